@@ -133,7 +133,8 @@ pub use secure_gate::SerializableSecret;
 // ── Bridge: secure-gate native types → ExposeSecret / ExposeSecretMut ────────
 //
 // These explicit impls allow code written against the secrecy `ExposeSecret` trait
-// to compile unchanged with native `Dynamic<T>` and `Fixed<[T; N]>` values.
+// to compile unchanged with native `Dynamic<T>` (including the unsized `Dynamic<str>`
+// / `Dynamic<[T]>` targets of the zero-copy v10 conversions) and `Fixed<[T; N]>` values.
 //
 // Explicit (rather than blanket) impls prevent the blanket from also catching
 // `SecretBox` / `Secret`, which carry their own direct impls.
@@ -166,6 +167,40 @@ impl<T: Zeroize> ExposeSecretMut<alloc::vec::Vec<T>> for Dynamic<alloc::vec::Vec
     }
 }
 
+impl ExposeSecret<str> for Dynamic<str> {
+    #[inline]
+    fn expose_secret(&self) -> &str {
+        RevealSecret::expose_secret(self)
+    }
+}
+
+impl ExposeSecretMut<str> for Dynamic<str> {
+    #[inline]
+    fn expose_secret_mut(&mut self) -> &mut str {
+        RevealSecretMut::expose_secret_mut(self)
+    }
+}
+
+impl<T> ExposeSecret<[T]> for Dynamic<[T]>
+where
+    [T]: Zeroize,
+{
+    #[inline]
+    fn expose_secret(&self) -> &[T] {
+        RevealSecret::expose_secret(self)
+    }
+}
+
+impl<T> ExposeSecretMut<[T]> for Dynamic<[T]>
+where
+    [T]: Zeroize,
+{
+    #[inline]
+    fn expose_secret_mut(&mut self) -> &mut [T] {
+        RevealSecretMut::expose_secret_mut(self)
+    }
+}
+
 impl<const N: usize, T: Zeroize> ExposeSecret<[T; N]> for Fixed<[T; N]> {
     #[inline]
     fn expose_secret(&self) -> &[T; N] {
@@ -21,8 +21,8 @@
 //! | secrecy 0.10 | secure-gate native |
 //! |---|---|
 //! | `SecretBox<T>` | [`Dynamic<T>`](secure_gate::Dynamic) |
-//! | `SecretString` | `Dynamic<String>` |
-//! | `SecretSlice<T>` | `Dynamic<Vec<T>>` |
+//! | `SecretString` | `Dynamic<String>`, or `Dynamic<str>` (zero-copy) |
+//! | `SecretSlice<T>` | `Dynamic<Vec<T>>`, or `Dynamic<[T]>` (zero-copy) |
 //! | `ExposeSecret<T>` | [`RevealSecret`](RevealSecret) |
 //! | `ExposeSecretMut<T>` | [`RevealSecretMut`](RevealSecretMut) |
 //! | `CloneableSecret` | [`CloneableSecret`](crate::CloneableSecret) (with `cloneable` feature) |
@@ -347,6 +347,33 @@ impl From<SecretString> for secure_gate::Dynamic<String> {
     }
 }
 
+/// Converts a `SecretSlice<S>` (= `SecretBox<[S]>`) into a [`Dynamic<[S]>`](secure_gate::Dynamic)
+/// without copying.
+///
+/// The boxed slice is moved out and an empty `Box<[S]>` (no allocation) is left
+/// behind for `SecretBox`'s `Drop` impl to zeroize as a no-op. No clone, no
+/// reallocation and no allocation failure window.
+impl<S> From<SecretSlice<S>> for secure_gate::Dynamic<[S]>
+where
+    S: Zeroize,
+    [S]: Zeroize,
+{
+    fn from(mut sb: SecretSlice<S>) -> Self {
+        secure_gate::Dynamic::from(core::mem::take(&mut sb.inner_secret))
+    }
+}
+
+/// Converts a `SecretString` (= `SecretBox<str>`) into a [`Dynamic<str>`](secure_gate::Dynamic)
+/// without copying.
+///
+/// Same move-out pattern as the `SecretSlice` conversion: the empty `Box<str>` left
+/// behind does not allocate.
+impl From<SecretString> for secure_gate::Dynamic<str> {
+    fn from(mut sb: SecretString) -> Self {
+        secure_gate::Dynamic::from(core::mem::take(&mut sb.inner_secret))
+    }
+}
+
 // ── Serde ─────────────────────────────────────────────────────────────────────
 
 #[cfg(feature = "serde-deserialize")]
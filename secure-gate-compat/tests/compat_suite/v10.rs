@@ -326,6 +326,26 @@ fn dynamic_vec_to_secret_box_vec() {
     assert_eq!(sb.expose_secret(), &[5u8, 6, 7]);
 }
 
+#[test]
+fn secret_slice_to_dynamic_slice_zero_copy() {
+    let ss: SecretSlice<u8> = vec![1u8, 2, 3].into();
+    let ptr = ss.expose_secret().as_ptr();
+    let d: Dynamic<[u8]> = ss.into();
+    assert_eq!(ExposeSecret::expose_secret(&d), &[1u8, 2, 3]);
+    // The boxed slice moved across — no copy was made.
+    assert_eq!(ExposeSecret::expose_secret(&d).as_ptr(), ptr);
+}
+
+#[test]
+fn secret_string_to_dynamic_str_zero_copy() {
+    let ss: SecretString = "zero_copy".into();
+    let ptr = ss.expose_secret().as_ptr();
+    let mut d: Dynamic<str> = ss.into();
+    ExposeSecretMut::expose_secret_mut(&mut d).make_ascii_uppercase();
+    assert_eq!(ExposeSecret::expose_secret(&d), "ZERO_COPY");
+    assert_eq!(ExposeSecret::expose_secret(&d).as_ptr(), ptr);
+}
+
 // ── 12. zeroize re-export ─────────────────────────────────────────────────────
 
 #[test]
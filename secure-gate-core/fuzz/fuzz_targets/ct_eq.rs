@@ -2,7 +2,7 @@
 //
 // Constant-time equality fuzz target for secure-gate.
 //
-// Tests `ct_eq` for `Fixed<[u8;32]>` and `Dynamic<Vec<u8>>`.
+// Tests `ct_eq` and `ct_cmp` for `Fixed<[u8;32]>` and `Dynamic<Vec<u8>>`.
 // Verifies reflexivity, symmetry, and correctness.
 //
 // Security invariants checked:
@@ -10,6 +10,8 @@
 //   - x.ct_eq(&y) == y.ct_eq(&x)   (symmetry)
 //   - ct_eq(&a, &b) matches byte equality
 //   - Empty/single-byte edge cases don't panic
+//   - ct_cmp(&a, &b) matches big-endian (lexicographic) and little-endian ordering
+//   - ct_lt(&a, &b) == ct_gt(&b, &a)                 (antisymmetry)
 //
 // Corpus seed hints (paste into fuzz/corpus/ct_eq/):
 //   \x00\x00\x00\x00 (32 zeros repeated)
@@ -19,7 +21,7 @@
 use arbitrary::{Arbitrary, Unstructured};
 use libfuzzer_sys::fuzz_target;
 
-use secure_gate::{ConstantTimeEq, Dynamic, RevealSecret};
+use secure_gate::{ByteOrder, ConstantTimeEq, ConstantTimeOrd, Dynamic, RevealSecret};
 use secure_gate_fuzz::arbitrary::{FuzzDynamicVec, FuzzFixed32};
 
 fuzz_target!(|data: &[u8]| {
@@ -90,6 +92,44 @@ fuzz_target!(|data: &[u8]| {
         assert!(a_vec.ct_eq(&b_vec), "Dynamic ct_eq incorrectly false for equal values");
     }
 
+    // === Fixed<[u8; 32]> — ct_cmp ===
+
+    let be: core::cmp::Ordering = a32.ct_cmp(&b32, ByteOrder::BigEndian).into();
+    assert_eq!(be, a_inner.cmp(b_inner), "Fixed big-endian ct_cmp mismatch");
+    let (mut ra, mut rb) = (*a_inner, *b_inner);
+    ra.reverse();
+    rb.reverse();
+    let le: core::cmp::Ordering = a32.ct_cmp(&b32, ByteOrder::LittleEndian).into();
+    assert_eq!(le, ra.cmp(&rb), "Fixed little-endian ct_cmp mismatch");
+    assert_eq!(
+        bool::from(a32.ct_lt(&b32, ByteOrder::BigEndian)),
+        bool::from(b32.ct_gt(&a32, ByteOrder::BigEndian)),
+        "Fixed ct_lt/ct_gt antisymmetry violated"
+    );
+
+    // === Dynamic<Vec<u8>> — ct_cmp (same length only) ===
+
+    let n = av.len().min(bv.len());
+    let a_cut = Dynamic::<Vec<u8>>::new(av[..n].to_vec());
+    let b_cut = Dynamic::<Vec<u8>>::new(bv[..n].to_vec());
+    let be: core::cmp::Ordering = a_cut
+        .ct_cmp(&b_cut, ByteOrder::BigEndian)
+        .expect("same length")
+        .into();
+    assert_eq!(be, av[..n].cmp(&bv[..n]), "Dynamic big-endian ct_cmp mismatch");
+    assert!(
+        a_cut
+            .ct_cmp(&a_cut, ByteOrder::LittleEndian)
+            .is_some_and(|o| bool::from(o.is_eq())),
+        "Dynamic ct_cmp reflexivity failed"
+    );
+    if av.len() != bv.len() {
+        assert!(
+            a_vec.ct_cmp(&b_vec, ByteOrder::BigEndian).is_none(),
+            "Dynamic ct_cmp accepted a length mismatch"
+        );
+    }
+
     // === Edge cases ===
 
     let empty_a = Dynamic::<Vec<u8>>::new(vec![]);
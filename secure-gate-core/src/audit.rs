@@ -0,0 +1,122 @@
+//! Runtime access auditing (`audit` feature).
+//!
+//! The grep-based audit story (see SECURITY.md § "Audit Surfaces") finds every
+//! access site in the source, but says nothing about what runs in production. With
+//! the `audit` feature enabled, every [`RevealSecret`](crate::RevealSecret) /
+//! [`RevealSecretMut`](crate::RevealSecretMut) method on [`Fixed`](crate::Fixed),
+//! [`Dynamic`](crate::Dynamic) and the borrowed views carries `#[track_caller]`
+//! and reports an [`AuditEvent`] to a process-wide hook:
+//!
+//! | Method | [`AccessTier`] | `mutable` |
+//! |---|---|---|
+//! | `with_secret` | `Scoped` | `false` |
+//! | `with_secret_mut` | `Scoped` | `true` |
+//! | `expose_secret` | `Direct` | `false` |
+//! | `expose_secret_mut` | `Direct` | `true` |
+//! | `into_inner` | `Owned` | `false` |
+//!
+//! Events carry the caller's source location and the wrapper's type name —
+//! **never the secret contents or length**. Crate methods that reveal on the
+//! caller's behalf (inherent encoders such as `to_hex`, `map_secret`, the
+//! [`Frozen`](crate::Frozen) / [`Budgeted`](crate::Budgeted) / `Expiring`
+//! forwarders) are `#[track_caller]` too, so the reported location is the
+//! application's call site, not a line inside this crate.
+//!
+//! Without the feature this module does not exist, no `#[track_caller]` is
+//! applied, and the access methods compile exactly as before.
+//!
+//! # Hook semantics
+//!
+//! - One hook per process, set with [`set_audit_hook`] and removed with
+//!   [`clear_audit_hook`]. Setting a hook replaces the previous one.
+//! - The hook is a plain `fn` pointer and runs synchronously on the accessing
+//!   thread, before the secret is handed out. Keep it cheap (increment a counter,
+//!   push to a channel) and never panic in it.
+//! - The hook must not reveal secrets itself — that would re-enter the hook.
+//!
+//! *Requires feature `audit` (implies `std`).*
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(feature = "audit")]
+//! # {
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use secure_gate::{AccessTier, AuditEvent, Fixed, RevealSecret};
+//!
+//! static DIRECT: AtomicUsize = AtomicUsize::new(0);
+//!
+//! fn count_escape_hatches(event: &AuditEvent) {
+//!     if event.tier == AccessTier::Direct {
+//!         DIRECT.fetch_add(1, Ordering::Relaxed);
+//!     }
+//! }
+//!
+//! secure_gate::set_audit_hook(count_escape_hatches);
+//! let key = Fixed::new([0u8; 32]);
+//! let _ = key.expose_secret();
+//! assert!(DIRECT.load(Ordering::Relaxed) >= 1);
+//! secure_gate::clear_audit_hook();
+//! # }
+//! ```
+
+use std::panic::Location;
+use std::sync::{PoisonError, RwLock};
+
+/// Which tier of the three-tier access model an audited call used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccessTier {
+    /// `with_secret` / `with_secret_mut` — borrow confined to a closure.
+    Scoped,
+    /// `expose_secret` / `expose_secret_mut` — escape hatch returning a reference.
+    Direct,
+    /// `into_inner` — ownership moved out of the wrapper.
+    Owned,
+}
+
+/// A single audited secret access. Never contains secret data.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct AuditEvent {
+    /// Access tier used.
+    pub tier: AccessTier,
+    /// `true` for `with_secret_mut` / `expose_secret_mut`.
+    pub mutable: bool,
+    /// Source location of the access in the calling code.
+    pub location: &'static Location<'static>,
+    /// [`core::any::type_name`] of the wrapper accessed, e.g.
+    /// `secure_gate::fixed::Fixed<[u8; 32]>`. Informational only — the format is
+    /// not stable across compiler versions.
+    pub type_name: &'static str,
+}
+
+static HOOK: RwLock<Option<fn(&AuditEvent)>> = RwLock::new(None);
+
+/// Installs `hook` as the process-wide audit hook, replacing any previous one.
+pub fn set_audit_hook(hook: fn(&AuditEvent)) {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = Some(hook);
+}
+
+/// Removes the audit hook. Subsequent accesses are not reported.
+pub fn clear_audit_hook() {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Reports an access by wrapper type `W` to the installed hook, if any.
+///
+/// The lock is released before the hook runs, so a hook may itself call
+/// [`set_audit_hook`] / [`clear_audit_hook`].
+#[track_caller]
+#[inline]
+pub(crate) fn record<W: ?Sized>(tier: AccessTier, mutable: bool) {
+    let hook = *HOOK.read().unwrap_or_else(PoisonError::into_inner);
+    if let Some(hook) = hook {
+        hook(&AuditEvent {
+            tier,
+            mutable,
+            location: Location::caller(),
+            type_name: core::any::type_name::<W>(),
+        });
+    }
+}
@@ -0,0 +1,137 @@
+//! Reveal-budget wrapper that self-destructs after N accesses.
+//!
+//! [`Budgeted<S>`] wraps a [`Fixed<T>`](crate::Fixed) or [`Dynamic<T>`](crate::Dynamic)
+//! and allows a fixed number of reveals through
+//! [`TryRevealSecret::try_with_secret`]. The reveal that spends the last unit of
+//! budget zeroizes the wrapped secret as soon as its closure returns or unwinds.
+//! Every later reveal fails with [`RevealError::BudgetExhausted`] without calling
+//! the closure.
+//!
+//! Intended for bootstrap tokens, enrolment codes and other secrets that should be
+//! read once (or a handful of times) and then disappear. Works in `no_std` and
+//! without `alloc` when wrapping a `Fixed`.
+//!
+//! # Security invariants
+//!
+//! - **No infallible access** — `Budgeted` implements neither `RevealSecret` nor
+//!   `RevealSecretMut`, has no `expose_secret` and no `into_inner`, so the budget
+//!   cannot be bypassed.
+//! - **A reveal is counted before the closure runs** — if the closure panics, the
+//!   access still counts. If it was the last reveal, the secret is zeroized during
+//!   unwind even when the `Budgeted` itself outlives the panic (e.g. under
+//!   `catch_unwind`).
+//! - **Zeroization reuses the wrapped type's `Zeroize` impl**; the wrapped type's
+//!   `Drop` impl still runs when the `Budgeted` is dropped.
+//! - **No `Clone`** — a clone would duplicate the remaining budget.
+//! - **`Debug` is redacted** — the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT)
+//!   marker, with the secret's length under `redact-length`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use secure_gate::{Budgeted, Fixed, RevealError, TryRevealSecret};
+//!
+//! let mut token = Budgeted::new(Fixed::new([0x5Au8; 32]), 2);
+//! assert_eq!(token.try_with_secret(|t| t[0]), Ok(0x5A));
+//! assert_eq!(token.remaining(), 1);
+//! assert_eq!(token.try_with_secret(|t| t[31]), Ok(0x5A)); // last reveal; zeroized afterwards
+//! assert!(token.is_exhausted());
+//! assert_eq!(token.try_with_secret(|t| t[0]), Err(RevealError::BudgetExhausted));
+//! ```
+
+use zeroize::Zeroize;
+
+use crate::{RevealError, RevealSecret, TryRevealSecret};
+
+/// Secret wrapper with a limited number of reveals.
+///
+/// Built with [`Budgeted::new`] or [`Budgeted::one_shot`]. See the
+/// [`TryRevealSecret`] impl for the access path.
+pub struct Budgeted<S: RevealSecret + Zeroize> {
+    inner: S,
+    remaining: usize,
+}
+
+impl<S: RevealSecret + Zeroize> Budgeted<S> {
+    /// Wraps `secret`, allowing `budget` reveals.
+    ///
+    /// A `budget` of `0` zeroizes `secret` immediately; every reveal then fails.
+    #[inline]
+    pub fn new(mut secret: S, budget: usize) -> Self {
+        if budget == 0 {
+            secret.zeroize();
+        }
+        Self {
+            inner: secret,
+            remaining: budget,
+        }
+    }
+
+    /// Wraps `secret`, allowing exactly one reveal.
+    #[inline]
+    pub fn one_shot(secret: S) -> Self {
+        Self::new(secret, 1)
+    }
+
+    /// Number of reveals still allowed.
+    ///
+    /// Always safe to call — does not expose secret contents.
+    #[inline(always)]
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Returns `true` once the budget is spent and the secret has been zeroized.
+    ///
+    /// Always safe to call — does not expose secret contents.
+    #[inline(always)]
+    pub const fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+/// Budget-limited access.
+///
+/// Decrements the budget, runs `f`, and zeroizes the wrapped secret when the
+/// budget reaches zero.
+impl<S: RevealSecret + Zeroize> TryRevealSecret for Budgeted<S> {
+    type Inner = S::Inner;
+
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn try_with_secret<F, R>(&mut self, f: F) -> Result<R, RevealError>
+    where
+        F: FnOnce(&S::Inner) -> R,
+    {
+        if self.remaining == 0 {
+            return Err(RevealError::BudgetExhausted);
+        }
+        // Count the access before running `f` so a panicking closure still spends it.
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            return Ok(self.inner.with_secret(f));
+        }
+        // Last reveal: the guard zeroizes whether `f` returns or unwinds.
+        let last = ZeroizeOnExit(&mut self.inner);
+        Ok(last.0.with_secret(f))
+    }
+}
+
+/// Zeroizes the borrowed secret when dropped.
+struct ZeroizeOnExit<'a, S: Zeroize>(&'a mut S);
+
+impl<S: Zeroize> Drop for ZeroizeOnExit<'_, S> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Prints the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT) marker
+/// for the wrapped secret's [`byte_len`](RevealSecret::byte_len).
+impl<S: RevealSecret + Zeroize> core::fmt::Debug for Budgeted<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let marker = crate::RedactionPolicy::DEFAULT.redact(self.inner.byte_len());
+        core::fmt::Display::fmt(&marker, f)
+    }
+}
+
+impl<S: RevealSecret + Zeroize + zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Budgeted<S> {}
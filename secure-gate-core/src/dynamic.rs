@@ -10,8 +10,10 @@
 //!
 //! - **No `Deref`, `AsRef`, or `Copy`** — the inner value cannot leak through
 //!   implicit conversions.
-//! - **`Debug` always prints `[REDACTED]`** — secrets never appear in logs or
-//!   panic messages.
+//! - **`Debug` is always redacted** — the
+//!   [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT) marker without a
+//!   length hint (see [`redacted`](Dynamic::redacted) for one); secrets never appear
+//!   in logs or panic messages.
 //! - **Unconditional zeroization on drop** — includes `Vec`/`String` spare capacity.
 //! - **Heap-only** — secret bytes never reside on the stack. Inner value stored in `Box<T>`.
 //! - **Opt-in `Clone`** — requires `T: CloneableSecret` and the `cloneable` feature.
@@ -27,14 +29,23 @@
 //! | Inner type | Realloc surface | Use case |
 //! |---|---|---|
 //! | `Dynamic<[u8; N]>` (boxed array) | **None** — fixed size | Long-lived known-size keys held on the heap |
+//! | [`FixedCapacity<Vec<u8>>`](crate::FixedCapacity) via `with_fixed_capacity` | **None** — only non-growing `try_*` writes and `DynamicWriter` exist | Variable-length secrets with a known upper bound (password entry, key loading) |
 //! | `Dynamic<Vec<u8>>` pre-sized | None *if* you avoid capacity-growing mutations | Variable-length secrets with a known upper bound |
-//! | `Dynamic<Vec<u8>>` growable | **Yes** — each realloc leaves the old buffer unzeroed | Convenient, but see realloc-residue warning below |
+//! | `Dynamic<Vec<u8>>` grown via `secure_*` methods | None — old buffer zeroized before free | Variable-length secrets built incrementally |
+//! | `Dynamic<Vec<u8>>` grown via `with_secret_mut` | **Yes** — each realloc leaves the old buffer unzeroed | Convenient, but see realloc-residue warning below |
 //! | `Dynamic<String>` | Same as `Dynamic<Vec<u8>>` | Passwords, API keys |
+//! | `Dynamic<[u8]>` / `Dynamic<str>` (boxed slice) | **None** — no spare capacity, cannot grow | Variable-length secrets that are complete at construction |
 //!
 //! For **long-lived, known-size key material**, prefer `Dynamic<[u8; N]>` —
 //! it combines `Dynamic`'s heap-only property with zero realloc surface.
 //! See `SECURITY.md` § "Inherent Rust Limitations" for the broader discussion.
 //!
+//! # Composite secrets
+//!
+//! Structs holding several keys are revealed through the opt-in
+//! [`OpaqueSecret`](crate::OpaqueSecret) marker: implement it on the inner type and
+//! `Dynamic<MyKeys>` gains `RevealSecret` / `RevealSecretMut`, reporting `len() == 1`.
+//!
 //! # Construction
 //!
 //! | Constructor | Notes |
@@ -42,6 +53,15 @@
 //! | [`Dynamic::new(value)`](Dynamic::new) | Ergonomic default; accepts `String`, `Vec<u8>`, `&str`, `Box<T>`, etc. |
 //! | [`Dynamic::<Vec<u8>>::new_with(f)`](Dynamic::new_with) | Scoped; for API symmetry with [`Fixed::new_with`](crate::Fixed::new_with) |
 //! | [`Dynamic::<String>::new_with(f)`](Dynamic::new_with) | Scoped; for API symmetry |
+//! | [`Dynamic::<Vec<u8>>::with_fixed_capacity(n)`](Dynamic::with_fixed_capacity) | Returns a [`FixedCapacity`](crate::FixedCapacity) that never reallocates; `try_*` writes past capacity return [`CapacityError`](crate::CapacityError) |
+//! | [`Dynamic::<String>::with_fixed_capacity(n)`](Dynamic::with_fixed_capacity) | Same, for strings |
+//!
+//! `Dynamic<[u8]>` and `Dynamic<str>` are built with `Dynamic::new(&bytes[..])` /
+//! `Dynamic::new("...")`, which copy into an exact-size allocation, or zero-copy
+//! from an existing `Box<[u8]>` / `Box<str>`. Avoid passing a `Vec`/`String` with
+//! spare capacity: `into_boxed_slice` / `into_boxed_str` shrink via `realloc` and
+//! can leave the original buffer unzeroed. Their `into_inner` returns the owned
+//! `Box` and does not allocate.
 //!
 //! Unlike [`Fixed::new_with`](crate::Fixed::new_with), `Dynamic` is already heap-only so
 //! `new_with` exists for consistent API idiom, not for stack-residue avoidance.
@@ -80,7 +100,9 @@
 //!
 //! For `Dynamic<Vec<_>>` and `Dynamic<String>`, capacity-changing mutations can
 //! cause the standard allocator to free the previous buffer without zeroizing it.
-//! Pre-allocate to the maximum size, use capacity-stable mutations, prefer
+//! Grow through [`secure_extend_from_slice`](Dynamic::secure_extend_from_slice),
+//! [`secure_push_str`](Dynamic::secure_push_str) and friends (zeroize-on-grow),
+//! pre-allocate to the maximum size, use capacity-stable mutations, prefer
 //! `Dynamic<[u8; N]>` (no realloc surface) for known-size heap secrets, or use
 //! [`Fixed<T>`](crate::Fixed) for known-size stack secrets. See `SECURITY.md` for
 //! the full threat-model discussion and deployment-level mitigations including
@@ -99,14 +121,6 @@ use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use zeroize::Zeroize;
 
-#[cfg(any(
-    feature = "encoding-hex",
-    feature = "encoding-base64",
-    feature = "encoding-bech32",
-    feature = "encoding-bech32m",
-    feature = "ct-eq",
-    feature = "std",
-))]
 use crate::RevealSecret;
 
 // Encoding traits
@@ -152,7 +166,7 @@ use crate::traits::decoding::hex::FromHexStr;
 ///
 /// let pw: Dynamic<String> = Dynamic::new(String::from("hunter2"));
 /// assert_eq!(pw.with_secret(|s: &String| s.len()), 7);
-/// assert_eq!(format!("{:?}", pw), "[REDACTED]");
+/// assert!(format!("{:?}", pw).starts_with("[REDACTED"));
 /// # }
 /// ```
 ///
@@ -162,6 +176,9 @@ use crate::traits::decoding::hex::FromHexStr;
 /// |---|---|---|
 /// | [`new(value)`](Self::new) | — | Accepts `Vec<u8>`, `&[u8]`, `Box<Vec<u8>>` |
 /// | [`new_with(f)`](Self::new_with) | — | Scoped closure construction |
+/// | [`with_fixed_capacity(n)`](Self::with_fixed_capacity) | — | Empty buffer that never reallocates |
+/// | [`try_new_with(n, f)`](Self::try_new_with) | — | Fallible: `AllocError` instead of OOM abort |
+/// | [`try_from_slice(bytes)`](Self::try_from_slice) | — | Fallible copy from a slice |
 /// | [`try_from_hex(s)`](Self::try_from_hex) | `encoding-hex` | Constant-time hex decoding |
 /// | [`try_from_base64url(s)`](Self::try_from_base64url) | `encoding-base64` | Constant-time Base64url decoding |
 /// | [`try_from_bech32(s, hrp)`](Self::try_from_bech32) | `encoding-bech32` | HRP-validated Bech32 |
@@ -170,6 +187,7 @@ use crate::traits::decoding::hex::FromHexStr;
 /// | [`try_from_bech32m_unchecked(s)`](Self::try_from_bech32m_unchecked) | `encoding-bech32m` | Bech32m without HRP check |
 /// | [`from_random(len)`](Self::from_random) | `rand` | System RNG |
 /// | [`from_rng(len, rng)`](Self::from_rng) | `rand` | Custom RNG |
+/// | [`try_from_random(len)`](Self::try_from_random) | `rand` | Fallible system RNG |
 ///
 /// # See also
 ///
@@ -241,12 +259,14 @@ impl<T: 'static + zeroize::Zeroize> From<T> for Dynamic<T> {
 impl Dynamic<Vec<u8>> {
     /// Encodes the secret bytes as a lowercase hex string.
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn to_hex(&self) -> alloc::string::String {
         self.with_secret(|s: &Vec<u8>| s.to_hex())
     }
 
     /// Encodes the secret bytes as an uppercase hex string.
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn to_hex_upper(&self) -> alloc::string::String {
         self.with_secret(|s: &Vec<u8>| s.to_hex_upper())
     }
@@ -254,6 +274,7 @@ impl Dynamic<Vec<u8>> {
     /// Encodes the secret bytes as a lowercase hex string, returning
     /// [`EncodedSecret`](crate::EncodedSecret) to preserve zeroization.
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn to_hex_zeroizing(&self) -> crate::EncodedSecret {
         self.with_secret(|s: &Vec<u8>| s.to_hex_zeroizing())
     }
@@ -261,6 +282,7 @@ impl Dynamic<Vec<u8>> {
     /// Encodes the secret bytes as an uppercase hex string, returning
     /// [`EncodedSecret`](crate::EncodedSecret) to preserve zeroization.
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn to_hex_upper_zeroizing(&self) -> crate::EncodedSecret {
         self.with_secret(|s: &Vec<u8>| s.to_hex_upper_zeroizing())
     }
@@ -281,6 +303,7 @@ impl Dynamic<Vec<u8>> {
 impl Dynamic<Vec<u8>> {
     /// Encodes the secret bytes as an unpadded Base64url string (RFC 4648, URL-safe alphabet).
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn to_base64url(&self) -> alloc::string::String {
         self.with_secret(|s: &Vec<u8>| s.to_base64url())
     }
@@ -288,6 +311,7 @@ impl Dynamic<Vec<u8>> {
     /// Encodes the secret bytes as an unpadded Base64url string, returning
     /// [`EncodedSecret`](crate::EncodedSecret) to preserve zeroization.
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn to_base64url_zeroizing(&self) -> crate::EncodedSecret {
         self.with_secret(|s: &Vec<u8>| s.to_base64url_zeroizing())
     }
@@ -308,6 +332,7 @@ impl Dynamic<Vec<u8>> {
 impl Dynamic<Vec<u8>> {
     /// Encodes the secret bytes as a Bech32 (BIP-173) string with the given HRP.
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn try_to_bech32(
         &self,
         hrp: &str,
@@ -318,6 +343,7 @@ impl Dynamic<Vec<u8>> {
     /// Encodes the secret bytes as a Bech32 string, returning
     /// [`EncodedSecret`](crate::EncodedSecret) to preserve zeroization.
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn try_to_bech32_zeroizing(
         &self,
         hrp: &str,
@@ -354,6 +380,7 @@ impl Dynamic<Vec<u8>> {
 impl Dynamic<Vec<u8>> {
     /// Encodes the secret bytes as a Bech32m (BIP-350) string with the given HRP.
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn try_to_bech32m(
         &self,
         hrp: &str,
@@ -364,6 +391,7 @@ impl Dynamic<Vec<u8>> {
     /// Encodes the secret bytes as a Bech32m string, returning
     /// [`EncodedSecret`](crate::EncodedSecret) to preserve zeroization.
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn try_to_bech32m_zeroizing(
         &self,
         hrp: &str,
@@ -467,170 +495,1386 @@ impl Dynamic<alloc::string::String> {
     }
 }
 
-// RevealSecret
-impl crate::RevealSecret for Dynamic<String> {
-    type Inner = String;
+// ---------------------------------------------------------------------------
+// Fallible allocation
+// ---------------------------------------------------------------------------
+//
+// Each `try_*` constructor reserves the secret's data buffer with
+// `try_reserve_exact` *before* any secret byte is written, and returns
+// `AllocError` instead of aborting when the allocator refuses.
+//
+// `Dynamic<Vec<u8>>` / `Dynamic<String>` box their constant-size header (three
+// words) first, into a `Dynamic` whose `Drop` already zeroizes, and only then
+// reserve the caller-sized buffer inside it. Stable Rust has no `Box::try_new`
+// and this crate forbids `unsafe`, so the header is boxed with the global
+// allocator's usual OOM behaviour — but it is the first allocation, its size
+// never depends on the input, and no secret byte exists yet. Every allocation
+// that scales with the secret is fallible. `Dynamic<[u8]>` and `Dynamic<str>`
+// have no header: their `try_new` is fallible end to end.
+
+/// Fallible constructors for `Dynamic<Vec<u8>>`.
+impl Dynamic<Vec<u8>> {
+    /// Fallible form of [`new_with`](Self::new_with): reserves `capacity` bytes up
+    /// front, then lets `f` fill the buffer.
+    ///
+    /// Keep `f` within `capacity` — growing past it goes through the ordinary,
+    /// infallible `Vec` growth path (and leaves realloc residue).
+    ///
+    /// The three-word `Vec` header is boxed before the reservation, with
+    /// ordinary allocation; it is constant-size and holds no secret. The
+    /// `capacity`-byte buffer is the only allocation that scales with the input,
+    /// and it is fallible. If `f` panics, the partly written buffer is zeroized.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllocError`](crate::AllocError) if `capacity` bytes cannot be
+    /// reserved. `f` is not called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secure_gate::{Dynamic, RevealSecret};
+    ///
+    /// let key = Dynamic::<Vec<u8>>::try_new_with(32, |v| v.resize(32, 0xAA))?;
+    /// assert_eq!(key.len(), 32);
+    /// # Ok::<(), secure_gate::AllocError>(())
+    /// ```
+    #[inline]
+    pub fn try_new_with<F>(capacity: usize, f: F) -> Result<Self, crate::AllocError>
+    where
+        F: FnOnce(&mut Vec<u8>),
+    {
+        // Header first: constant size, before any secret byte exists
+        let mut this = Self::from(Box::<Vec<u8>>::default());
+        this.inner
+            .try_reserve_exact(capacity)
+            .map_err(crate::AllocError::from_reserve(capacity))?;
+        // A panic in `f` drops `this`, whose `Drop` zeroizes the buffer
+        f(&mut this.inner);
+        Ok(this)
+    }
 
-    #[inline(always)]
-    fn with_secret<F, R>(&self, f: F) -> R
+    /// Fallible form of `Dynamic::from(&[u8])`: copies `bytes` into an
+    /// exactly-sized buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllocError`](crate::AllocError) if the buffer cannot be reserved.
+    #[inline]
+    pub fn try_from_slice(bytes: &[u8]) -> Result<Self, crate::AllocError> {
+        Self::try_new_with(bytes.len(), |v| v.extend_from_slice(bytes))
+    }
+}
+
+/// Fallible constructors for `Dynamic<String>`.
+impl Dynamic<String> {
+    /// Fallible form of [`new_with`](Self::new_with): reserves `capacity` bytes up
+    /// front, then lets `f` fill the string.
+    ///
+    /// Keep `f` within `capacity` — growing past it goes through the ordinary,
+    /// infallible `String` growth path (and leaves realloc residue).
+    ///
+    /// The three-word `String` header is boxed before the reservation, with
+    /// ordinary allocation; it is constant-size and holds no secret. The
+    /// `capacity`-byte buffer is the only allocation that scales with the input,
+    /// and it is fallible. If `f` panics, the partly written buffer is zeroized.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllocError`](crate::AllocError) if `capacity` bytes cannot be
+    /// reserved. `f` is not called.
+    #[inline]
+    pub fn try_new_with<F>(capacity: usize, f: F) -> Result<Self, crate::AllocError>
     where
-        F: FnOnce(&String) -> R,
+        F: FnOnce(&mut String),
     {
-        f(&self.inner)
+        // Header first: constant size, before any secret byte exists
+        let mut this = Self::from(Box::<String>::default());
+        this.inner
+            .try_reserve_exact(capacity)
+            .map_err(crate::AllocError::from_reserve(capacity))?;
+        // A panic in `f` drops `this`, whose `Drop` zeroizes the buffer
+        f(&mut this.inner);
+        Ok(this)
     }
+}
 
-    #[inline(always)]
-    fn expose_secret(&self) -> &String {
-        &self.inner
+/// Fallible constructors for `Dynamic<[u8]>`.
+impl Dynamic<[u8]> {
+    /// Fallible form of `Dynamic::<[u8]>::new(&[u8])`: copies `bytes` into an
+    /// exact-size `Box<[u8]>`. Every allocation on this path is fallible.
+    ///
+    /// The buffer is reserved with `try_reserve_exact` on an empty `Vec`. If the
+    /// allocator hands back more capacity than requested, `into_boxed_slice`
+    /// would shrink it with an infallible `realloc` that may leave a copy of the
+    /// secret behind, so that case is rejected before any byte is copied.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllocError`](crate::AllocError) if an exact-size buffer cannot
+    /// be reserved.
+    #[inline]
+    pub fn try_new(bytes: &[u8]) -> Result<Self, crate::AllocError> {
+        let mut v = Vec::new();
+        v.try_reserve_exact(bytes.len())
+            .map_err(crate::AllocError::from_reserve(bytes.len()))?;
+        if v.capacity() != bytes.len() {
+            return Err(crate::AllocError::AllocationFailed {
+                requested: bytes.len(),
+            });
+        }
+        v.extend_from_slice(bytes);
+        Ok(Self::from(v.into_boxed_slice()))
+    }
+}
+
+/// Fallible constructors for `Dynamic<str>`.
+impl Dynamic<str> {
+    /// Fallible form of `Dynamic::<str>::new(&str)`: copies `s` into an exact-size
+    /// `Box<str>`. Every allocation on this path is fallible.
+    ///
+    /// Over-sized reservations are rejected before any byte is copied, as in
+    /// [`Dynamic::<[u8]>::try_new`](Dynamic::try_new), so `into_boxed_str` never
+    /// reallocates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllocError`](crate::AllocError) if an exact-size buffer cannot
+    /// be reserved.
+    #[inline]
+    pub fn try_new(s: &str) -> Result<Self, crate::AllocError> {
+        let mut buf = String::new();
+        buf.try_reserve_exact(s.len())
+            .map_err(crate::AllocError::from_reserve(s.len()))?;
+        if buf.capacity() != s.len() {
+            return Err(crate::AllocError::AllocationFailed { requested: s.len() });
+        }
+        buf.push_str(s);
+        Ok(Self::from(buf.into_boxed_str()))
     }
+}
 
+// ---------------------------------------------------------------------------
+// Secret-to-secret transformation
+// ---------------------------------------------------------------------------
+
+impl<T: zeroize::Zeroize + crate::SentinelValue> Dynamic<T> {
+    /// Boxes a protected value. Generic form of `from_protected_bytes`: the
+    /// sentinel `Box` is the only allocation, and `protected` stays live across it
+    /// so an OOM panic zeroizes the value during unwind. After the swap,
+    /// `protected` holds the inert sentinel.
     #[inline(always)]
-    fn len(&self) -> usize {
-        self.inner.len()
+    fn from_protected_value(mut protected: zeroize::Zeroizing<T>) -> Self {
+        // Only fallible allocation; protected stays live across it for panic-safety
+        let mut boxed = Box::new(T::sentinel_value());
+        core::mem::swap(&mut *boxed, &mut *protected);
+        Self::from(boxed)
     }
+}
 
-    /// Consumes `self` and returns the inner `String` wrapped in [`crate::InnerSecret`].
+/// Secret-to-secret transformation.
+///
+/// Derive one secret from another without the result ever existing outside a
+/// wrapper — a subkey from a master key, a password `String` to bytes, a
+/// re-encoding. Each call is a single grep-able audit point (`map_secret`), and
+/// access goes through [`with_secret`](crate::RevealSecret::with_secret), so only
+/// inner types that are already revealable can be mapped.
+///
+/// # Panic safety
+///
+/// The closure's result is wrapped in `Zeroizing` immediately and stays there
+/// across the `Box` allocation for the new wrapper — the same pattern as
+/// [`new_with`](Dynamic::new_with). An OOM panic zeroizes the result during unwind.
+/// The output type needs [`SentinelValue`](crate::SentinelValue) for the swap.
+impl<T: ?Sized + zeroize::Zeroize> Dynamic<T>
+where
+    Self: crate::RevealSecret<Inner = T>,
+{
+    /// Builds a new `Dynamic<U>` from a scoped borrow of this secret.
     ///
-    /// **Allocation note:** allocates one small `Box<String>` sentinel (24 bytes on
-    /// 64-bit) before the swap. If that allocation panics (OOM), `self.inner` is
-    /// unchanged and `Dynamic::drop` zeroizes the real secret during unwind —
-    /// confidentiality is preserved. This is the same OOM-safety pattern used by
-    /// `from_protected_bytes` and `deserialize_with_limit`.
+    /// # Examples
     ///
-    /// After ownership transfer, capacity-changing mutations on the returned
-    /// `InnerSecret<String>` have the same realloc-residue caveats as mutating
-    /// `Dynamic<String>` in place; see `SECURITY.md`.
+    /// ```rust
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use secure_gate::{Dynamic, RevealSecret};
     ///
-    /// See [`RevealSecret::into_inner`] for full documentation including the
-    /// redacted `Debug` behavior.
-    #[inline(always)]
-    fn into_inner(mut self) -> crate::InnerSecret<String>
+    /// let pw: Dynamic<String> = Dynamic::new(String::from("hunter2"));
+    /// let bytes: Dynamic<Vec<u8>> = pw.map_secret(|s| s.as_bytes().to_vec());
+    /// assert_eq!(bytes.len(), 7);
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn map_secret<U, F>(&self, f: F) -> Dynamic<U>
     where
-        Self: Sized,
-        Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+        U: zeroize::Zeroize + crate::SentinelValue,
+        F: FnOnce(&T) -> U,
     {
-        // Swap in an empty-String sentinel. If Box::new panics (OOM) before the
-        // swap, self.inner still holds the real secret and Dynamic::drop zeroizes
-        // it on unwind. After the swap, self.inner is an empty sentinel — zeroized
-        // on Dynamic::drop as a no-op. `*boxed` deref-moves the String out of the Box.
-        let boxed = core::mem::replace(
-            &mut self.inner,
-            Box::new(crate::SentinelValue::sentinel_value()),
-        );
-        crate::InnerSecret::new(*boxed)
+        Dynamic::from_protected_value(zeroize::Zeroizing::new(self.with_secret(f)))
+    }
+
+    /// Fallible variant of [`map_secret`](Self::map_secret).
+    ///
+    /// # Errors
+    ///
+    /// Returns the closure's error unchanged; no wrapper is built.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn try_map_secret<U, E, F>(&self, f: F) -> Result<Dynamic<U>, E>
+    where
+        U: zeroize::Zeroize + crate::SentinelValue,
+        F: FnOnce(&T) -> Result<U, E>,
+    {
+        let out = zeroize::Zeroizing::new(self.with_secret(f)?);
+        Ok(Dynamic::from_protected_value(out))
     }
 }
 
-impl<T: zeroize::Zeroize> crate::RevealSecret for Dynamic<Vec<T>> {
-    type Inner = Vec<T>;
+/// Read-only freezing.
+impl<T: ?Sized + zeroize::Zeroize> Dynamic<T>
+where
+    Self: crate::RevealSecret,
+{
+    /// Consumes the wrapper and returns a [`Frozen`](crate::Frozen) that implements
+    /// [`RevealSecret`](crate::RevealSecret) but not
+    /// [`RevealSecretMut`](crate::RevealSecretMut).
+    ///
+    /// The heap allocation is moved, not copied. Zeroize-on-drop and the redacted
+    /// `Debug` carry over unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use secure_gate::{Dynamic, RevealSecret};
+    ///
+    /// let token = Dynamic::<String>::new("root-token".to_string()).freeze();
+    /// assert_eq!(token.with_secret(|t| t.len()), 10);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn freeze(self) -> crate::Frozen<Self> {
+        crate::Frozen::new(self)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Borrowed sub-secret views
+// ---------------------------------------------------------------------------
 
+/// Borrowed sub-secret views for `Dynamic<Vec<T>>`.
+///
+/// `slice` / `slice_mut` return a [`SecretRef`](crate::SecretRef) /
+/// [`SecretMut`](crate::SecretMut) over a sub-range of the secret. The view
+/// implements [`RevealSecret`](crate::RevealSecret) (and
+/// [`RevealSecretMut`](crate::RevealSecretMut)), so part of a secret can be passed
+/// on without handing over a raw slice. A live view borrows the wrapper, so the
+/// buffer cannot grow or be dropped while the view exists.
+///
+/// The same two methods exist on `Dynamic<[T]>`, `Dynamic<[T; N]>`,
+/// `Dynamic<String>` and `Dynamic<str>`; the string forms take byte ranges and
+/// yield `str` views.
+///
+/// # Panics
+///
+/// If the range is out of bounds (or, for strings, not on a `char` boundary),
+/// like slice indexing.
+impl<T: zeroize::Zeroize> Dynamic<Vec<T>> {
+    /// Returns a read-only view of `index` (any range type).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use secure_gate::{Dynamic, RevealSecret};
+    ///
+    /// let blob: Dynamic<Vec<u8>> = Dynamic::new((0u8..48).collect::<Vec<u8>>());
+    /// let mac = blob.slice(16..32);
+    /// assert_eq!(mac.len(), 16);
+    /// mac.with_secret(|m| assert_eq!(m[0], 16));
+    /// # }
+    /// ```
     #[inline(always)]
-    fn with_secret<F, R>(&self, f: F) -> R
+    pub fn slice<I>(&self, index: I) -> crate::SecretRef<'_, [T]>
     where
-        F: FnOnce(&Vec<T>) -> R,
+        I: core::slice::SliceIndex<[T], Output = [T]>,
     {
-        f(&self.inner)
+        crate::SecretRef::new(&self.inner[index])
     }
 
+    /// Returns a mutable view of `index` (any range type).
     #[inline(always)]
-    fn expose_secret(&self) -> &Vec<T> {
-        &self.inner
+    pub fn slice_mut<I>(&mut self, index: I) -> crate::SecretMut<'_, [T]>
+    where
+        I: core::slice::SliceIndex<[T], Output = [T]>,
+    {
+        crate::SecretMut::new(&mut self.inner[index])
     }
+}
 
+/// Borrowed sub-secret views for `Dynamic<[T]>` — see `Dynamic<Vec<T>>::slice`.
+impl<T> Dynamic<[T]>
+where
+    [T]: zeroize::Zeroize,
+{
+    /// Returns a read-only view of `index` (any range type).
     #[inline(always)]
-    fn len(&self) -> usize {
-        self.inner.len()
+    pub fn slice<I>(&self, index: I) -> crate::SecretRef<'_, [T]>
+    where
+        I: core::slice::SliceIndex<[T], Output = [T]>,
+    {
+        crate::SecretRef::new(&self.inner[index])
     }
 
+    /// Returns a mutable view of `index` (any range type).
     #[inline(always)]
-    fn byte_len(&self) -> usize {
-        self.inner.len() * core::mem::size_of::<T>()
+    pub fn slice_mut<I>(&mut self, index: I) -> crate::SecretMut<'_, [T]>
+    where
+        I: core::slice::SliceIndex<[T], Output = [T]>,
+    {
+        crate::SecretMut::new(&mut self.inner[index])
     }
+}
 
-    /// Consumes `self` and returns the inner `Vec<T>` wrapped in [`crate::InnerSecret`].
-    ///
-    /// **Allocation note:** allocates one small `Box<Vec<T>>` sentinel (24 bytes on
-    /// 64-bit) before the swap. If that allocation panics (OOM), `self.inner` is
-    /// unchanged and `Dynamic::drop` zeroizes the real secret during unwind —
-    /// confidentiality is preserved. This is the same OOM-safety pattern used by
-    /// `from_protected_bytes` and `deserialize_with_limit`.
-    ///
-    /// After ownership transfer, capacity-changing mutations on the returned
-    /// `InnerSecret<Vec<T>>` have the same realloc-residue caveats as mutating
-    /// `Dynamic<Vec<T>>` in place; see `SECURITY.md`.
-    ///
-    /// See [`RevealSecret::into_inner`] for full documentation including the
-    /// redacted `Debug` behavior.
+/// Borrowed sub-secret views for `Dynamic<[T; N]>` — see `Dynamic<Vec<T>>::slice`.
+impl<T: zeroize::Zeroize, const N: usize> Dynamic<[T; N]> {
+    /// Returns a read-only view of `index` (any range type).
     #[inline(always)]
-    fn into_inner(mut self) -> crate::InnerSecret<Vec<T>>
+    pub fn slice<I>(&self, index: I) -> crate::SecretRef<'_, [T]>
     where
-        Self: Sized,
-        Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+        I: core::slice::SliceIndex<[T], Output = [T]>,
     {
-        // Swap in an empty-Vec sentinel. If Box::new panics (OOM) before the swap,
-        // self.inner still holds the real secret and Dynamic::drop zeroizes it on
-        // unwind. After the swap, self.inner is an empty sentinel — zeroized on
-        // Dynamic::drop as a no-op. `*boxed` deref-moves the Vec out of the Box.
-        let boxed = core::mem::replace(
-            &mut self.inner,
-            Box::new(crate::SentinelValue::sentinel_value()),
-        );
-        crate::InnerSecret::new(*boxed)
+        crate::SecretRef::new(&self.inner[index])
+    }
+
+    /// Returns a mutable view of `index` (any range type).
+    #[inline(always)]
+    pub fn slice_mut<I>(&mut self, index: I) -> crate::SecretMut<'_, [T]>
+    where
+        I: core::slice::SliceIndex<[T], Output = [T]>,
+    {
+        crate::SecretMut::new(&mut self.inner[index])
     }
 }
 
-// RevealSecretMut
-impl crate::RevealSecretMut for Dynamic<String> {
+/// Borrowed sub-secret views for `Dynamic<String>` — see `Dynamic<Vec<T>>::slice`.
+impl Dynamic<String> {
+    /// Returns a read-only `str` view of the byte range `index`.
     #[inline(always)]
-    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    pub fn slice<I>(&self, index: I) -> crate::SecretRef<'_, str>
     where
-        F: FnOnce(&mut String) -> R,
+        I: core::slice::SliceIndex<str, Output = str>,
     {
-        f(&mut self.inner)
+        crate::SecretRef::new(&self.inner[index])
     }
 
+    /// Returns a mutable `str` view of the byte range `index`.
     #[inline(always)]
-    fn expose_secret_mut(&mut self) -> &mut String {
-        &mut self.inner
+    pub fn slice_mut<I>(&mut self, index: I) -> crate::SecretMut<'_, str>
+    where
+        I: core::slice::SliceIndex<str, Output = str>,
+    {
+        crate::SecretMut::new(&mut self.inner[index])
     }
 }
 
-impl<T: zeroize::Zeroize> crate::RevealSecretMut for Dynamic<Vec<T>> {
+/// Borrowed sub-secret views for `Dynamic<str>` — see `Dynamic<Vec<T>>::slice`.
+impl Dynamic<str> {
+    /// Returns a read-only view of the byte range `index`.
     #[inline(always)]
-    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    pub fn slice<I>(&self, index: I) -> crate::SecretRef<'_, str>
     where
-        F: FnOnce(&mut Vec<T>) -> R,
+        I: core::slice::SliceIndex<str, Output = str>,
     {
-        f(&mut self.inner)
+        crate::SecretRef::new(&self.inner[index])
     }
 
+    /// Returns a mutable view of the byte range `index`.
     #[inline(always)]
-    fn expose_secret_mut(&mut self) -> &mut Vec<T> {
-        &mut self.inner
+    pub fn slice_mut<I>(&mut self, index: I) -> crate::SecretMut<'_, str>
+    where
+        I: core::slice::SliceIndex<str, Output = str>,
+    {
+        crate::SecretMut::new(&mut self.inner[index])
     }
 }
 
-// Random generation
-#[cfg(feature = "rand")]
-impl Dynamic<alloc::vec::Vec<u8>> {
-    /// Fills a new `Vec<u8>` with `len` cryptographically secure random bytes and wraps it.
+// ---------------------------------------------------------------------------
+// Zeroize-on-grow mutation
+// ---------------------------------------------------------------------------
+
+/// Returns the capacity to allocate when `current` cannot hold `required` elements.
+///
+/// Mirrors `Vec`'s amortized doubling so repeated small appends stay O(1) amortized.
+#[inline(always)]
+fn grown_capacity(current: usize, required: usize) -> usize {
+    core::cmp::max(required, current.saturating_mul(2))
+}
+
+/// Zeroize-on-grow mutation for `Dynamic<Vec<u8>>`.
+///
+/// `Vec::push` / `Vec::extend_from_slice` inside
+/// [`with_secret_mut`](crate::RevealSecretMut::with_secret_mut) hand growth to the
+/// allocator's `realloc`, which may free the previous buffer without zeroizing it.
+/// The `secure_*` methods below never call `realloc`: when capacity runs out they
+/// allocate a fresh buffer, copy the live bytes, and zeroize the old buffer
+/// (including spare capacity) before it is freed.
+///
+/// # Panic safety
+///
+/// The only allocation is `Vec::with_capacity` for the replacement buffer. If it
+/// panics (OOM), `self` still owns the original buffer and `Dynamic::drop`
+/// zeroizes it during unwind.
+impl Dynamic<Vec<u8>> {
+    /// Ensures capacity for at least `additional` more bytes without leaving
+    /// realloc residue.
     ///
-    /// Uses the system RNG ([`SysRng`](rand::rngs::SysRng)). Requires the `rand` feature (and
-    /// `alloc`, which `Dynamic<Vec<u8>>` always needs).
+    /// No-op if the current capacity already suffices.
     ///
     /// # Panics
     ///
-    /// Panics if the system RNG fails to provide bytes ([`TryRng::try_fill_bytes`](rand::TryRng::try_fill_bytes)
-    /// returns `Err`). This is treated as a fatal environment error.
+    /// Panics if the new capacity overflows `usize`.
+    pub fn secure_reserve(&mut self, additional: usize) {
+        let required = self
+            .inner
+            .len()
+            .checked_add(additional)
+            .expect("capacity overflow");
+        if required <= self.inner.capacity() {
+            return;
+        }
+        let mut fresh = Vec::with_capacity(grown_capacity(self.inner.capacity(), required));
+        fresh.extend_from_slice(&self.inner);
+        let mut old = core::mem::replace(&mut *self.inner, fresh);
+        old.zeroize();
+    }
+
+    /// Appends `bytes`, growing via [`secure_reserve`](Self::secure_reserve).
+    #[inline]
+    pub fn secure_extend_from_slice(&mut self, bytes: &[u8]) {
+        self.secure_reserve(bytes.len());
+        self.inner.extend_from_slice(bytes);
+    }
+
+    /// Appends a single byte, growing via [`secure_reserve`](Self::secure_reserve).
+    #[inline]
+    pub fn secure_push(&mut self, byte: u8) {
+        self.secure_reserve(1);
+        self.inner.push(byte);
+    }
+}
+
+/// Zeroize-on-grow mutation for `Dynamic<String>`.
+///
+/// Same policy and panic-safety argument as the `Dynamic<Vec<u8>>` methods: growth
+/// allocates a fresh buffer, copies, and zeroizes the old one before it is freed.
+impl Dynamic<String> {
+    /// Ensures capacity for at least `additional` more bytes without leaving
+    /// realloc residue.
     ///
-    /// # Examples
+    /// No-op if the current capacity already suffices.
     ///
-    /// ```rust
-    /// # #[cfg(all(feature = "alloc", feature = "rand"))]
-    /// use secure_gate::{Dynamic, RevealSecret};
+    /// # Panics
     ///
-    /// # #[cfg(all(feature = "alloc", feature = "rand"))]
-    /// # {
+    /// Panics if the new capacity overflows `usize`.
+    pub fn secure_reserve(&mut self, additional: usize) {
+        let required = self
+            .inner
+            .len()
+            .checked_add(additional)
+            .expect("capacity overflow");
+        if required <= self.inner.capacity() {
+            return;
+        }
+        let mut fresh = String::with_capacity(grown_capacity(self.inner.capacity(), required));
+        fresh.push_str(&self.inner);
+        let mut old = core::mem::replace(&mut *self.inner, fresh);
+        old.zeroize();
+    }
+
+    /// Appends `s`, growing via [`secure_reserve`](Self::secure_reserve).
+    #[inline]
+    pub fn secure_push_str(&mut self, s: &str) {
+        self.secure_reserve(s.len());
+        self.inner.push_str(s);
+    }
+
+    /// Appends a single `char`, growing via [`secure_reserve`](Self::secure_reserve).
+    #[inline]
+    pub fn secure_push(&mut self, ch: char) {
+        self.secure_reserve(ch.len_utf8());
+        self.inner.push(ch);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Capacity-bounded mutation
+// ---------------------------------------------------------------------------
+
+/// Builds the error for a write of `additional` bytes onto a buffer of `len`/`capacity`.
+#[inline(always)]
+fn capacity_exceeded(len: usize, additional: usize, capacity: usize) -> crate::CapacityError {
+    crate::CapacityError::CapacityExceeded {
+        capacity,
+        required: len.saturating_add(additional),
+    }
+}
+
+/// Capacity-bounded buffers for `Dynamic<Vec<u8>>`.
+///
+/// The bounded buffer is a separate type, [`FixedCapacity`](crate::FixedCapacity),
+/// so that no growing mutator is reachable on it. The non-allocating writes below
+/// are its backend.
+impl Dynamic<Vec<u8>> {
+    /// Creates an empty [`FixedCapacity`](crate::FixedCapacity) buffer with a
+    /// fixed capacity of `capacity` bytes.
+    ///
+    /// The allocation happens here, before any secret bytes exist. The returned
+    /// buffer only offers non-growing writes (`try_extend_from_slice`, `try_push`,
+    /// `as_writer`); call `into_dynamic` once the secret is complete.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use secure_gate::{CapacityError, Dynamic, RevealSecret};
+    ///
+    /// let mut key = Dynamic::<Vec<u8>>::with_fixed_capacity(4);
+    /// key.try_extend_from_slice(&[1, 2, 3]).unwrap();
+    /// assert!(matches!(
+    ///     key.try_extend_from_slice(&[4, 5]),
+    ///     Err(CapacityError::CapacityExceeded { capacity: 4, required: 5, .. })
+    /// ));
+    /// assert_eq!(key.len(), 3);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn with_fixed_capacity(capacity: usize) -> crate::FixedCapacity<Vec<u8>> {
+        crate::FixedCapacity::<Vec<u8>>::new(capacity)
+    }
+
+    #[inline(always)]
+    pub(crate) fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    #[inline(always)]
+    pub(crate) fn remaining_capacity(&self) -> usize {
+        self.inner.capacity() - self.inner.len()
+    }
+
+    /// Appends `bytes` if they fit in the remaining capacity; never reallocates.
+    /// Leaves the buffer unchanged on error.
+    #[inline]
+    pub(crate) fn try_extend_from_slice(
+        &mut self,
+        bytes: &[u8],
+    ) -> Result<(), crate::CapacityError> {
+        if bytes.len() > self.remaining_capacity() {
+            return Err(capacity_exceeded(
+                self.inner.len(),
+                bytes.len(),
+                self.inner.capacity(),
+            ));
+        }
+        self.inner.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// Capacity-bounded buffers for `Dynamic<String>`.
+///
+/// Same arrangement as for `Dynamic<Vec<u8>>`: the public surface is
+/// [`FixedCapacity<String>`](crate::FixedCapacity).
+impl Dynamic<String> {
+    /// Creates an empty [`FixedCapacity`](crate::FixedCapacity) string with a
+    /// fixed capacity of `capacity` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use secure_gate::{Dynamic, RevealSecret};
+    ///
+    /// let mut pw = Dynamic::<String>::with_fixed_capacity(8);
+    /// pw.try_push_str("hunter2").unwrap();
+    /// assert!(pw.try_push_str("!!").is_err());
+    /// assert_eq!(pw.expose_secret(), "hunter2");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn with_fixed_capacity(capacity: usize) -> crate::FixedCapacity<String> {
+        crate::FixedCapacity::<String>::new(capacity)
+    }
+
+    #[inline(always)]
+    pub(crate) fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    #[inline(always)]
+    pub(crate) fn remaining_capacity(&self) -> usize {
+        self.inner.capacity() - self.inner.len()
+    }
+
+    /// Appends `s` if it fits in the remaining capacity; never reallocates.
+    /// Leaves the string unchanged on error.
+    #[inline]
+    pub(crate) fn try_push_str(&mut self, s: &str) -> Result<(), crate::CapacityError> {
+        if s.len() > self.remaining_capacity() {
+            return Err(capacity_exceeded(
+                self.inner.len(),
+                s.len(),
+                self.inner.capacity(),
+            ));
+        }
+        self.inner.push_str(s);
+        Ok(())
+    }
+}
+
+// RevealSecret
+impl crate::RevealSecret for Dynamic<String> {
+    type Inner = String;
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&String) -> R,
+    {
+        audit_access!(Scoped, false);
+        f(&self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &String {
+        audit_access!(Direct, false);
+        &self.inner
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Consumes `self` and returns the inner `String` wrapped in [`crate::InnerSecret`].
+    ///
+    /// **Allocation note:** allocates one small `Box<String>` sentinel (24 bytes on
+    /// 64-bit) before the swap. If that allocation panics (OOM), `self.inner` is
+    /// unchanged and `Dynamic::drop` zeroizes the real secret during unwind —
+    /// confidentiality is preserved. This is the same OOM-safety pattern used by
+    /// `from_protected_bytes` and `deserialize_with_limit`.
+    ///
+    /// After ownership transfer, capacity-changing mutations on the returned
+    /// `InnerSecret<String>` have the same realloc-residue caveats as mutating
+    /// `Dynamic<String>` in place; see `SECURITY.md`.
+    ///
+    /// See [`RevealSecret::into_inner`] for full documentation including the
+    /// redacted `Debug` behavior.
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn into_inner(mut self) -> crate::InnerSecret<String>
+    where
+        Self: Sized,
+        Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        audit_access!(Owned, false);
+        // Swap in an empty-String sentinel. If Box::new panics (OOM) before the
+        // swap, self.inner still holds the real secret and Dynamic::drop zeroizes
+        // it on unwind. After the swap, self.inner is an empty sentinel — zeroized
+        // on Dynamic::drop as a no-op. `*boxed` deref-moves the String out of the Box.
+        let boxed = core::mem::replace(
+            &mut self.inner,
+            Box::new(crate::SentinelValue::sentinel_value()),
+        );
+        crate::InnerSecret::new(*boxed)
+    }
+}
+
+impl<T: zeroize::Zeroize> crate::RevealSecret for Dynamic<Vec<T>> {
+    type Inner = Vec<T>;
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&Vec<T>) -> R,
+    {
+        audit_access!(Scoped, false);
+        f(&self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &Vec<T> {
+        audit_access!(Direct, false);
+        &self.inner
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        self.inner.len() * core::mem::size_of::<T>()
+    }
+
+    /// Consumes `self` and returns the inner `Vec<T>` wrapped in [`crate::InnerSecret`].
+    ///
+    /// **Allocation note:** allocates one small `Box<Vec<T>>` sentinel (24 bytes on
+    /// 64-bit) before the swap. If that allocation panics (OOM), `self.inner` is
+    /// unchanged and `Dynamic::drop` zeroizes the real secret during unwind —
+    /// confidentiality is preserved. This is the same OOM-safety pattern used by
+    /// `from_protected_bytes` and `deserialize_with_limit`.
+    ///
+    /// After ownership transfer, capacity-changing mutations on the returned
+    /// `InnerSecret<Vec<T>>` have the same realloc-residue caveats as mutating
+    /// `Dynamic<Vec<T>>` in place; see `SECURITY.md`.
+    ///
+    /// See [`RevealSecret::into_inner`] for full documentation including the
+    /// redacted `Debug` behavior.
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn into_inner(mut self) -> crate::InnerSecret<Vec<T>>
+    where
+        Self: Sized,
+        Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        audit_access!(Owned, false);
+        // Swap in an empty-Vec sentinel. If Box::new panics (OOM) before the swap,
+        // self.inner still holds the real secret and Dynamic::drop zeroizes it on
+        // unwind. After the swap, self.inner is an empty sentinel — zeroized on
+        // Dynamic::drop as a no-op. `*boxed` deref-moves the Vec out of the Box.
+        let boxed = core::mem::replace(
+            &mut self.inner,
+            Box::new(crate::SentinelValue::sentinel_value()),
+        );
+        crate::InnerSecret::new(*boxed)
+    }
+}
+
+// RevealSecretMut
+impl crate::RevealSecretMut for Dynamic<String> {
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut String) -> R,
+    {
+        audit_access!(Scoped, true);
+        f(&mut self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret_mut(&mut self) -> &mut String {
+        audit_access!(Direct, true);
+        &mut self.inner
+    }
+}
+
+impl<T: zeroize::Zeroize> crate::RevealSecretMut for Dynamic<Vec<T>> {
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut Vec<T>) -> R,
+    {
+        audit_access!(Scoped, true);
+        f(&mut self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret_mut(&mut self) -> &mut Vec<T> {
+        audit_access!(Direct, true);
+        &mut self.inner
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Boxed arrays and opaque composite secrets
+// ---------------------------------------------------------------------------
+
+/// Read-only access to a boxed fixed-size array.
+///
+/// Same element-count semantics as `Fixed<[T; N]>`: `len()` is `N`.
+impl<T, const N: usize> crate::RevealSecret for Dynamic<[T; N]>
+where
+    [T; N]: zeroize::Zeroize,
+{
+    type Inner = [T; N];
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&[T; N]) -> R,
+    {
+        audit_access!(Scoped, false);
+        f(&self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &[T; N] {
+        audit_access!(Direct, false);
+        &self.inner
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        N
+    }
+
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        N * core::mem::size_of::<T>()
+    }
+
+    /// Consumes `self` and returns the inner array wrapped in [`crate::InnerSecret`].
+    ///
+    /// **Allocation note:** the sentinel is a full `Box<[T; N]>` of default values, so
+    /// this allocates `N * size_of::<T>()` bytes before the swap. If that allocation
+    /// panics (OOM), `self.inner` is unchanged and `Dynamic::drop` zeroizes the real
+    /// secret during unwind.
+    ///
+    /// The returned array lives in `InnerSecret` by value — i.e. on the caller's
+    /// stack. Prefer [`with_secret`](crate::RevealSecret::with_secret) when the
+    /// heap-only property matters.
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn into_inner(mut self) -> crate::InnerSecret<[T; N]>
+    where
+        Self: Sized,
+        Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        audit_access!(Owned, false);
+        let boxed = core::mem::replace(
+            &mut self.inner,
+            Box::new(crate::SentinelValue::sentinel_value()),
+        );
+        crate::InnerSecret::new(*boxed)
+    }
+}
+
+impl<T, const N: usize> crate::RevealSecretMut for Dynamic<[T; N]>
+where
+    [T; N]: zeroize::Zeroize,
+{
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut [T; N]) -> R,
+    {
+        audit_access!(Scoped, true);
+        f(&mut self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret_mut(&mut self) -> &mut [T; N] {
+        audit_access!(Direct, true);
+        &mut self.inner
+    }
+}
+
+/// Read-only access to an opaque composite secret (see [`OpaqueSecret`](crate::OpaqueSecret)).
+///
+/// The value is treated as one unit: `len()` is `1` and `byte_len()` is
+/// `size_of::<T>()`.
+impl<T: crate::OpaqueSecret> crate::RevealSecret for Dynamic<T> {
+    type Inner = T;
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        audit_access!(Scoped, false);
+        f(&self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &T {
+        audit_access!(Direct, false);
+        &self.inner
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        1
+    }
+
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        core::mem::size_of::<T>()
+    }
+
+    /// Consumes `self` and returns the inner value wrapped in [`crate::InnerSecret`].
+    ///
+    /// Requires `T: SentinelValue`. **Allocation note:** allocates one
+    /// `Box<T>` sentinel (`size_of::<T>()` bytes) before the swap. If that allocation
+    /// panics (OOM), `self.inner` is unchanged and `Dynamic::drop` zeroizes the real
+    /// secret during unwind.
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn into_inner(mut self) -> crate::InnerSecret<T>
+    where
+        Self: Sized,
+        Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        audit_access!(Owned, false);
+        let boxed = core::mem::replace(
+            &mut self.inner,
+            Box::new(crate::SentinelValue::sentinel_value()),
+        );
+        crate::InnerSecret::new(*boxed)
+    }
+}
+
+impl<T: crate::OpaqueSecret> crate::RevealSecretMut for Dynamic<T> {
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        audit_access!(Scoped, true);
+        f(&mut self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret_mut(&mut self) -> &mut T {
+        audit_access!(Direct, true);
+        &mut self.inner
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Unsized inner types: Dynamic<[T]> and Dynamic<str>
+// ---------------------------------------------------------------------------
+
+/// Construction helpers for `Dynamic<[u8]>`.
+impl Dynamic<[u8]> {
+    /// Copies `protected` bytes into an exact-size `Box<[u8]>`, keeping
+    /// [`zeroize::Zeroizing`] alive across the allocation.
+    ///
+    /// `Vec::into_boxed_slice` is deliberately avoided: when the decoded `Vec` has
+    /// spare capacity it shrinks via `realloc`, which may free the original buffer
+    /// unzeroed. Copying into a fresh exact-size allocation and letting
+    /// `Zeroizing` wipe the source has no such window.
+    ///
+    /// # Panic safety
+    ///
+    /// `Box::<[u8]>::from` is the sole allocation point. If it panics (OOM),
+    /// `protected` is still in scope and `Zeroizing::drop` zeroes the secret bytes
+    /// during unwind.
+    #[cfg(any(
+        feature = "encoding-hex",
+        feature = "encoding-base64",
+        feature = "encoding-bech32",
+        feature = "encoding-bech32m",
+        feature = "serde-deserialize",
+    ))]
+    #[inline(always)]
+    fn from_protected_bytes(protected: zeroize::Zeroizing<alloc::vec::Vec<u8>>) -> Self {
+        // Only fallible allocation; protected stays live across it for panic-safety
+        Self::from(Box::<[u8]>::from(&protected[..]))
+    }
+}
+
+/// Construction helpers for `Dynamic<str>`.
+impl Dynamic<str> {
+    /// Copies a protected `String` into an exact-size `Box<str>`. Same rationale
+    /// and panic-safety argument as the `Dynamic<[u8]>` helper: no shrink-`realloc`,
+    /// and `protected` stays live across the only allocation.
+    #[cfg(feature = "serde-deserialize")]
+    #[inline(always)]
+    fn from_protected_bytes(protected: zeroize::Zeroizing<alloc::string::String>) -> Self {
+        // Only fallible allocation; protected stays live across it for panic-safety
+        Self::from(Box::<str>::from(protected.as_str()))
+    }
+}
+
+/// Read-only access to a boxed slice.
+///
+/// A `Box<[T]>` has no spare capacity and cannot grow, so `Dynamic<[T]>` has no
+/// realloc-residue surface at all.
+impl<T> crate::RevealSecret for Dynamic<[T]>
+where
+    [T]: zeroize::Zeroize,
+{
+    type Inner = [T];
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&[T]) -> R,
+    {
+        audit_access!(Scoped, false);
+        f(&self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &[T] {
+        audit_access!(Direct, false);
+        &self.inner
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        self.inner.len() * core::mem::size_of::<T>()
+    }
+
+    /// Never callable — `[T]` is unsized, so the trait bounds cannot hold.
+    ///
+    /// Use the inherent [`Dynamic::<[T]>::into_inner`](Dynamic::into_inner), which
+    /// returns the owned `Box<[T]>` and takes precedence at call sites.
+    fn into_inner(self) -> crate::InnerSecret<[T]>
+    where
+        Self: Sized,
+        Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        crate::__private::unsized_into_inner()
+    }
+}
+
+/// Read-only access to a boxed string slice.
+impl crate::RevealSecret for Dynamic<str> {
+    type Inner = str;
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&str) -> R,
+    {
+        audit_access!(Scoped, false);
+        f(&self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &str {
+        audit_access!(Direct, false);
+        &self.inner
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Never callable — `str` is unsized, so the trait bounds cannot hold.
+    ///
+    /// Use the inherent [`Dynamic::<str>::into_inner`](Dynamic::into_inner), which
+    /// returns the owned `Box<str>` and takes precedence at call sites.
+    fn into_inner(self) -> crate::InnerSecret<str>
+    where
+        Self: Sized,
+        // `for<'a>` keeps the concrete (always-false) `str: Sized` bound from being
+        // rejected as trivially unsatisfiable; it is still never met.
+        for<'a> Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        crate::__private::unsized_into_inner()
+    }
+}
+
+impl<T> crate::RevealSecretMut for Dynamic<[T]>
+where
+    [T]: zeroize::Zeroize,
+{
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut [T]) -> R,
+    {
+        audit_access!(Scoped, true);
+        f(&mut self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret_mut(&mut self) -> &mut [T] {
+        audit_access!(Direct, true);
+        &mut self.inner
+    }
+}
+
+impl crate::RevealSecretMut for Dynamic<str> {
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut str) -> R,
+    {
+        audit_access!(Scoped, true);
+        f(&mut self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret_mut(&mut self) -> &mut str {
+        audit_access!(Direct, true);
+        &mut self.inner
+    }
+}
+
+impl<T: zeroize::Zeroize> Dynamic<[T]>
+where
+    [T]: zeroize::Zeroize,
+{
+    /// Consumes `self` and returns the inner `Box<[T]>` wrapped in [`crate::InnerSecret`].
+    ///
+    /// Unlike the sized `Dynamic` impls, this **does not allocate**: the empty
+    /// `Box<[T]>` swapped in as a sentinel is a dangling, zero-length pointer. The
+    /// returned box zeroizes every element when it drops.
+    ///
+    /// This inherent method shadows [`RevealSecret::into_inner`](crate::RevealSecret::into_inner),
+    /// which cannot be called for unsized inner types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    ///
+    /// let key: Dynamic<[u8]> = Dynamic::new(&[1u8, 2, 3][..]);
+    /// let owned: secure_gate::InnerSecret<Box<[u8]>> = key.into_inner();
+    /// assert_eq!(&**owned, &[1, 2, 3]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn into_inner(mut self) -> crate::InnerSecret<Box<[T]>> {
+        audit_access!(Owned, false);
+        let boxed = core::mem::take(&mut self.inner);
+        crate::InnerSecret::new(boxed)
+    }
+}
+
+impl Dynamic<str> {
+    /// Consumes `self` and returns the inner `Box<str>` wrapped in [`crate::InnerSecret`].
+    ///
+    /// Allocation-free, like [`Dynamic::<[T]>::into_inner`](Dynamic::into_inner): the
+    /// empty `Box<str>` sentinel does not touch the heap.
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn into_inner(mut self) -> crate::InnerSecret<Box<str>> {
+        audit_access!(Owned, false);
+        let boxed = core::mem::take(&mut self.inner);
+        crate::InnerSecret::new(boxed)
+    }
+}
+
+// Hex encoding and decoding for Dynamic<[u8]>.
+#[cfg(feature = "encoding-hex")]
+impl Dynamic<[u8]> {
+    /// Encodes the secret bytes as a lowercase hex string.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn to_hex(&self) -> alloc::string::String {
+        self.with_secret(|s: &[u8]| s.to_hex())
+    }
+
+    /// Encodes the secret bytes as an uppercase hex string.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn to_hex_upper(&self) -> alloc::string::String {
+        self.with_secret(|s: &[u8]| s.to_hex_upper())
+    }
+
+    /// Encodes the secret bytes as a lowercase hex string, returning
+    /// [`EncodedSecret`](crate::EncodedSecret) to preserve zeroization.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn to_hex_zeroizing(&self) -> crate::EncodedSecret {
+        self.with_secret(|s: &[u8]| s.to_hex_zeroizing())
+    }
+
+    /// Encodes the secret bytes as an uppercase hex string, returning
+    /// [`EncodedSecret`](crate::EncodedSecret) to preserve zeroization.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn to_hex_upper_zeroizing(&self) -> crate::EncodedSecret {
+        self.with_secret(|s: &[u8]| s.to_hex_upper_zeroizing())
+    }
+
+    /// Decodes a hex string (lowercase, uppercase, or mixed) into an exact-size
+    /// `Dynamic<[u8]>`.
+    ///
+    /// The decoded buffer stays inside a `Zeroizing` wrapper until the boxed copy
+    /// exists, guaranteeing zeroization even on OOM panic.
+    pub fn try_from_hex(s: &str) -> Result<Self, crate::error::HexError> {
+        Ok(Self::from_protected_bytes(zeroize::Zeroizing::new(
+            s.try_from_hex()?,
+        )))
+    }
+}
+
+// Base64url encoding and decoding for Dynamic<[u8]>.
+#[cfg(feature = "encoding-base64")]
+impl Dynamic<[u8]> {
+    /// Encodes the secret bytes as an unpadded Base64url string (RFC 4648, URL-safe alphabet).
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn to_base64url(&self) -> alloc::string::String {
+        self.with_secret(|s: &[u8]| s.to_base64url())
+    }
+
+    /// Encodes the secret bytes as an unpadded Base64url string, returning
+    /// [`EncodedSecret`](crate::EncodedSecret) to preserve zeroization.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn to_base64url_zeroizing(&self) -> crate::EncodedSecret {
+        self.with_secret(|s: &[u8]| s.to_base64url_zeroizing())
+    }
+
+    /// Decodes a Base64url (unpadded) string into an exact-size `Dynamic<[u8]>`.
+    ///
+    /// The decoded buffer stays inside a `Zeroizing` wrapper until the boxed copy
+    /// exists, guaranteeing zeroization even on OOM panic.
+    pub fn try_from_base64url(s: &str) -> Result<Self, crate::error::Base64Error> {
+        Ok(Self::from_protected_bytes(zeroize::Zeroizing::new(
+            s.try_from_base64url()?,
+        )))
+    }
+}
+
+// Bech32 (BIP-173) encoding and decoding for Dynamic<[u8]>.
+#[cfg(feature = "encoding-bech32")]
+impl Dynamic<[u8]> {
+    /// Encodes the secret bytes as a Bech32 (BIP-173) string with the given HRP.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn try_to_bech32(
+        &self,
+        hrp: &str,
+    ) -> Result<alloc::string::String, crate::error::Bech32Error> {
+        self.with_secret(|s: &[u8]| s.try_to_bech32(hrp))
+    }
+
+    /// Encodes the secret bytes as a Bech32 string, returning
+    /// [`EncodedSecret`](crate::EncodedSecret) to preserve zeroization.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn try_to_bech32_zeroizing(
+        &self,
+        hrp: &str,
+    ) -> Result<crate::EncodedSecret, crate::error::Bech32Error> {
+        self.with_secret(|s: &[u8]| s.try_to_bech32_zeroizing(hrp))
+    }
+
+    /// Decodes a Bech32 (BIP-173) string into an exact-size `Dynamic<[u8]>`,
+    /// validating the HRP (case-insensitive).
+    ///
+    /// HRP comparison is non-constant-time — the HRP is public metadata.
+    pub fn try_from_bech32(s: &str, expected_hrp: &str) -> Result<Self, crate::error::Bech32Error> {
+        Ok(Self::from_protected_bytes(zeroize::Zeroizing::new(
+            s.try_from_bech32(expected_hrp)?,
+        )))
+    }
+
+    /// Decodes a Bech32 (BIP-173) string into an exact-size `Dynamic<[u8]>` without
+    /// validating the HRP.
+    ///
+    /// Use [`try_from_bech32`](Self::try_from_bech32) in security-critical code to prevent
+    /// cross-protocol confusion attacks.
+    pub fn try_from_bech32_unchecked(s: &str) -> Result<Self, crate::error::Bech32Error> {
+        let (_hrp, bytes) = s.try_from_bech32_unchecked()?;
+        Ok(Self::from_protected_bytes(zeroize::Zeroizing::new(bytes)))
+    }
+}
+
+// Bech32m (BIP-350) encoding and decoding for Dynamic<[u8]>.
+#[cfg(feature = "encoding-bech32m")]
+impl Dynamic<[u8]> {
+    /// Encodes the secret bytes as a Bech32m (BIP-350) string with the given HRP.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn try_to_bech32m(
+        &self,
+        hrp: &str,
+    ) -> Result<alloc::string::String, crate::error::Bech32Error> {
+        self.with_secret(|s: &[u8]| s.try_to_bech32m(hrp))
+    }
+
+    /// Encodes the secret bytes as a Bech32m string, returning
+    /// [`EncodedSecret`](crate::EncodedSecret) to preserve zeroization.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn try_to_bech32m_zeroizing(
+        &self,
+        hrp: &str,
+    ) -> Result<crate::EncodedSecret, crate::error::Bech32Error> {
+        self.with_secret(|s: &[u8]| s.try_to_bech32m_zeroizing(hrp))
+    }
+
+    /// Decodes a Bech32m (BIP-350) string into an exact-size `Dynamic<[u8]>`,
+    /// validating the HRP (case-insensitive).
+    pub fn try_from_bech32m(
+        s: &str,
+        expected_hrp: &str,
+    ) -> Result<Self, crate::error::Bech32Error> {
+        Ok(Self::from_protected_bytes(zeroize::Zeroizing::new(
+            s.try_from_bech32m(expected_hrp)?,
+        )))
+    }
+
+    /// Decodes a Bech32m (BIP-350) string into an exact-size `Dynamic<[u8]>` without
+    /// validating the HRP.
+    ///
+    /// Use [`try_from_bech32m`](Self::try_from_bech32m) in security-critical code.
+    pub fn try_from_bech32m_unchecked(s: &str) -> Result<Self, crate::error::Bech32Error> {
+        let (_hrp, bytes) = s.try_from_bech32m_unchecked()?;
+        Ok(Self::from_protected_bytes(zeroize::Zeroizing::new(bytes)))
+    }
+}
+
+// Random generation
+#[cfg(feature = "rand")]
+impl Dynamic<alloc::vec::Vec<u8>> {
+    /// Fills a new `Vec<u8>` with `len` cryptographically secure random bytes and wraps it.
+    ///
+    /// Uses the system RNG ([`SysRng`](rand::rngs::SysRng)). Requires the `rand` feature (and
+    /// `alloc`, which `Dynamic<Vec<u8>>` always needs).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system RNG fails to provide bytes ([`TryRng::try_fill_bytes`](rand::TryRng::try_fill_bytes)
+    /// returns `Err`). This is treated as a fatal environment error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "alloc", feature = "rand"))]
+    /// use secure_gate::{Dynamic, RevealSecret};
+    ///
+    /// # #[cfg(all(feature = "alloc", feature = "rand"))]
+    /// # {
     /// let nonce: Dynamic<Vec<u8>> = Dynamic::from_random(24);
     /// assert_eq!(nonce.len(), 24);
     /// # }
@@ -677,6 +1921,38 @@ impl Dynamic<alloc::vec::Vec<u8>> {
         });
         result.map(|_| this)
     }
+
+    /// Fallible form of [`from_random`](Self::from_random): reserves `len` bytes
+    /// with `try_reserve_exact` before filling them from the system RNG.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllocError`](crate::AllocError) if the buffer cannot be reserved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system RNG fails, like [`from_random`](Self::from_random).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::{Dynamic, RevealSecret};
+    ///
+    /// let nonce = Dynamic::<Vec<u8>>::try_from_random(24).expect("allocation");
+    /// assert_eq!(nonce.len(), 24);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_from_random(len: usize) -> Result<Self, crate::AllocError> {
+        Self::try_new_with(len, |v| {
+            v.resize(len, 0u8);
+            SysRng
+                .try_fill_bytes(v)
+                .expect("SysRng failure is a program error");
+        })
+    }
 }
 
 /// Constant-time equality for `Dynamic<T>` — routes through [`expose_secret()`](crate::RevealSecret::expose_secret).
@@ -689,14 +1965,192 @@ where
     Self: crate::RevealSecret<Inner = T>,
 {
     fn ct_eq(&self, other: &Self) -> bool {
-        self.expose_secret().ct_eq(other.expose_secret())
+        (*self.inner).ct_eq(&*other.inner)
+    }
+
+    fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+        (*self.inner).ct_eq_choice(&*other.inner)
+    }
+}
+
+/// `Choice`-returning equality for composing with other `subtle` code.
+///
+/// As with the `bool` form, lengths are compared first and are not hidden.
+#[cfg(feature = "ct-eq")]
+impl subtle::ConstantTimeEq for Dynamic<Vec<u8>> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(self.inner.as_slice(), other.inner.as_slice())
+    }
+}
+
+/// Length-hiding comparison for variable-length secrets (passwords, tokens).
+///
+/// See [`ct_eq_padded`](crate::ct_eq_padded) for the exact guarantee: the
+/// iteration count depends only on `max_len`, and values longer than `max_len`
+/// never match.
+#[cfg(feature = "ct-eq")]
+impl Dynamic<String> {
+    /// Compares two strings' UTF-8 bytes in `max_len` iterations, whatever their lengths.
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "ct-eq", feature = "alloc"))]
+    /// # {
+    /// use secure_gate::Dynamic;
+    ///
+    /// let stored = Dynamic::<String>::new("correct horse".to_string());
+    /// let attempt = Dynamic::<String>::new("correct".to_string());
+    /// assert!(!bool::from(stored.ct_eq_padded(&attempt, 128)));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn ct_eq_padded(&self, other: &Self, max_len: usize) -> subtle::Choice {
+        crate::ct_eq_padded(self.inner.as_bytes(), other.inner.as_bytes(), max_len)
+    }
+}
+
+/// Length-hiding comparison for variable-length byte secrets.
+/// See [`ct_eq_padded`](crate::ct_eq_padded).
+#[cfg(feature = "ct-eq")]
+impl Dynamic<Vec<u8>> {
+    /// Compares two byte vectors in `max_len` iterations, whatever their lengths.
+    #[inline]
+    pub fn ct_eq_padded(&self, other: &Self, max_len: usize) -> subtle::Choice {
+        crate::ct_eq_padded(&self.inner, &other.inner, max_len)
+    }
+}
+
+/// Constant-time ordering for `Dynamic<Vec<u8>>`.
+///
+/// Length is public, only the contents are compared in constant time. The
+/// operands must have the same length; a mismatch returns `None` instead of
+/// panicking, matching [`ct_select`](Dynamic::ct_select). This is an inherent
+/// method rather than [`ConstantTimeOrd`](crate::ConstantTimeOrd), whose
+/// signature has no room for the failure.
+#[cfg(feature = "ct-eq")]
+impl Dynamic<Vec<u8>> {
+    /// Compares `self` with `other` as unsigned integers in the given
+    /// [`ByteOrder`](crate::ByteOrder), or returns `None` if they have different
+    /// lengths.
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "ct-eq", feature = "alloc"))]
+    /// # {
+    /// use secure_gate::{ByteOrder, Dynamic};
+    ///
+    /// let a = Dynamic::<Vec<u8>>::new(vec![0x00, 0x10]);
+    /// let b = Dynamic::<Vec<u8>>::new(vec![0x01, 0x00]);
+    /// let ord = a.ct_cmp(&b, ByteOrder::BigEndian).expect("same length");
+    /// assert!(bool::from(ord.is_lt()));
+    ///
+    /// let short = Dynamic::<Vec<u8>>::new(vec![0x01]);
+    /// assert!(a.ct_cmp(&short, ByteOrder::BigEndian).is_none());
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ct_cmp(&self, other: &Self, order: crate::ByteOrder) -> Option<crate::CtOrdering> {
+        if self.inner.len() != other.inner.len() {
+            return None;
+        }
+        Some(crate::traits::constant_time_ord::ct_cmp_bytes(
+            &self.inner,
+            &other.inner,
+            order,
+        ))
+    }
+}
+
+/// Branch-free selection for `Dynamic<Vec<u8>>`.
+///
+/// Length is public, only the contents are selected in constant time. The
+/// operands must have the same length; a mismatch is reported (checked before
+/// any byte is touched) instead of panicking. See
+/// [`Fixed::ct_select`](crate::Fixed::ct_select) for why these are inherent
+/// methods rather than `subtle::ConditionallySelectable`.
+#[cfg(feature = "ct-eq")]
+impl Dynamic<Vec<u8>> {
+    /// Returns a copy of `a` when `choice` is 0 and of `b` when `choice` is 1, or
+    /// `None` if `a` and `b` have different lengths.
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "ct-eq", feature = "alloc"))]
+    /// # {
+    /// use secure_gate::{Choice, Dynamic, RevealSecret};
+    ///
+    /// let a = Dynamic::<Vec<u8>>::new(vec![1u8; 4]);
+    /// let b = Dynamic::<Vec<u8>>::new(vec![2u8; 4]);
+    /// let picked = Dynamic::ct_select(&a, &b, Choice::from(0)).expect("same length");
+    /// assert_eq!(picked.expose_secret(), &[1u8; 4]);
+    ///
+    /// let short = Dynamic::<Vec<u8>>::new(vec![2u8; 3]);
+    /// assert!(Dynamic::ct_select(&a, &short, Choice::from(0)).is_none());
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ct_select(a: &Self, b: &Self, choice: subtle::Choice) -> Option<Self> {
+        use subtle::ConditionallySelectable;
+        if a.inner.len() != b.inner.len() {
+            return None;
+        }
+        Some(Self::new_with(|out| {
+            // reserve up front so no reallocation leaves a partial copy behind
+            out.reserve_exact(a.inner.len());
+            out.extend(
+                a.inner
+                    .iter()
+                    .zip(b.inner.iter())
+                    .map(|(x, y)| u8::conditional_select(x, y, choice)),
+            );
+        }))
+    }
+
+    /// Overwrites `self` with `other` when `choice` is 1; leaves it unchanged when 0.
+    ///
+    /// # Errors
+    ///
+    /// [`FromSliceError::InvalidLength`](crate::FromSliceError::InvalidLength),
+    /// leaving `self` unchanged, if `self` and `other` have different lengths.
+    #[inline]
+    pub fn conditional_assign(
+        &mut self,
+        other: &Self,
+        choice: subtle::Choice,
+    ) -> Result<(), crate::FromSliceError> {
+        use subtle::ConditionallySelectable;
+        if self.inner.len() != other.inner.len() {
+            return Err(crate::FromSliceError::InvalidLength {
+                expected: self.inner.len(),
+                got: other.inner.len(),
+            });
+        }
+        for (s, o) in self.inner.iter_mut().zip(other.inner.iter()) {
+            s.conditional_assign(o, choice);
+        }
+        Ok(())
     }
 }
 
-/// Always prints `[REDACTED]` — secrets never appear in debug output.
+/// Prints the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT) marker
+/// without a length hint. Secrets never appear in debug output.
+///
+/// Available for every `T`. A generic `T` may hold its bytes behind a pointer, so
+/// no length is shown even with `redact-length`; use
+/// [`redacted`](Dynamic::redacted) for a length-aware marker.
+///
+/// ```rust
+/// use secure_gate::{Dynamic, RedactionPolicy};
+///
+/// let pw = Dynamic::<String>::new("hunter2".to_string());
+/// assert_eq!(format!("{:?}", pw), "[REDACTED]");
+/// let expected = RedactionPolicy::DEFAULT.redact(7).to_string();
+/// assert_eq!(pw.redacted(RedactionPolicy::DEFAULT).to_string(), expected);
+/// ```
 impl<T: ?Sized + zeroize::Zeroize> core::fmt::Debug for Dynamic<T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("[REDACTED]")
+        let marker = crate::RedactionPolicy::DEFAULT.redact_unknown_len();
+        core::fmt::Display::fmt(&marker, f)
     }
 }
 
@@ -713,10 +2167,13 @@ impl<T: zeroize::Zeroize + crate::CloneableSecret> Clone for Dynamic<T> {
 // Streaming I/O (std only)
 // ---------------------------------------------------------------------------
 
-/// Streams bytes directly into the protected buffer via [`RevealSecretMut`](crate::RevealSecretMut).
+/// Streams bytes directly into the protected buffer via
+/// [`secure_extend_from_slice`](Dynamic::secure_extend_from_slice).
 ///
 /// Data flows **into** the wrapper — this is a pure security improvement over
-/// accumulating plaintext in a bare `Vec<u8>` before wrapping.
+/// accumulating plaintext in a bare `Vec<u8>` before wrapping. Growth follows the
+/// zeroize-on-grow policy, so writes past the current capacity never leave the
+/// previous buffer unzeroed.
 ///
 /// # Example
 ///
@@ -736,8 +2193,8 @@ impl<T: zeroize::Zeroize + crate::CloneableSecret> Clone for Dynamic<T> {
 impl std::io::Write for Dynamic<alloc::vec::Vec<u8>> {
     #[inline]
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        use crate::RevealSecretMut;
-        self.with_secret_mut(|v| std::io::Write::write(v, buf))
+        self.secure_extend_from_slice(buf);
+        Ok(buf.len())
     }
 
     #[inline]
@@ -824,7 +2281,7 @@ impl Dynamic<alloc::vec::Vec<u8>> {
 /// [`SerializableSecret`](crate::SerializableSecret) marker. Serialization exposes the
 /// full secret — audit every impl.
 #[cfg(feature = "serde-serialize")]
-impl<T: zeroize::Zeroize + crate::SerializableSecret> serde::Serialize for Dynamic<T> {
+impl<T: ?Sized + zeroize::Zeroize + crate::SerializableSecret> serde::Serialize for Dynamic<T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -835,8 +2292,9 @@ impl<T: zeroize::Zeroize + crate::SerializableSecret> serde::Serialize for Dynam
 
 // Deserialize
 
-/// Default maximum byte length accepted when deserializing `Dynamic<Vec<u8>>` or
-/// `Dynamic<String>` via the standard `serde::Deserialize` impl (1 MiB).
+/// Default maximum byte length accepted when deserializing `Dynamic<Vec<u8>>`,
+/// `Dynamic<String>`, `Dynamic<[u8]>` or `Dynamic<str>` via the standard
+/// `serde::Deserialize` impl (1 MiB).
 ///
 /// Pass a custom value to [`Dynamic::deserialize_with_limit`] when a different
 /// ceiling is required.
@@ -891,6 +2349,34 @@ impl Dynamic<alloc::vec::Vec<u8>> {
         core::mem::swap(&mut *boxed, &mut *buf);
         Ok(Self::from(boxed))
     }
+
+    /// Like [`deserialize_with_limit`](Self::deserialize_with_limit), but copies
+    /// into the final buffer with a fallible reservation (see
+    /// [`try_from_slice`](Self::try_from_slice)). An allocation failure is
+    /// reported as a deserializer error whose message is
+    /// [`AllocError`](crate::AllocError)'s `Display`.
+    ///
+    /// Only the copy made by this crate is fallible: the intermediate `Vec<u8>`
+    /// is built by the upstream deserializer with ordinary allocation. Bound the
+    /// payload at the transport layer when the input size is untrusted.
+    pub fn try_deserialize_with_limit<'de, D>(
+        deserializer: D,
+        limit: usize,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let buf: zeroize::Zeroizing<alloc::vec::Vec<u8>> =
+            zeroize::Zeroizing::new(serde::Deserialize::deserialize(deserializer)?);
+        if buf.len() > limit {
+            // buf drops here → Zeroizing zeros the oversized buffer before deallocation
+            return Err(serde::de::Error::custom(
+                "deserialized secret exceeds maximum size",
+            ));
+        }
+        // buf stays live (and is zeroized on drop) whether or not the copy succeeds
+        Self::try_from_slice(&buf).map_err(serde::de::Error::custom)
+    }
 }
 
 #[cfg(feature = "serde-deserialize")]
@@ -936,6 +2422,130 @@ impl Dynamic<String> {
         core::mem::swap(&mut *boxed, &mut *buf);
         Ok(Self::from(boxed))
     }
+
+    /// Like [`deserialize_with_limit`](Self::deserialize_with_limit), but copies
+    /// into the final buffer with a fallible reservation. Same scope as
+    /// [`Dynamic::<Vec<u8>>::try_deserialize_with_limit`](Dynamic::try_deserialize_with_limit):
+    /// the upstream deserializer's own `String` is allocated normally.
+    pub fn try_deserialize_with_limit<'de, D>(
+        deserializer: D,
+        limit: usize,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let buf: zeroize::Zeroizing<alloc::string::String> =
+            zeroize::Zeroizing::new(serde::Deserialize::deserialize(deserializer)?);
+        if buf.len() > limit {
+            // buf drops here → Zeroizing zeros the oversized buffer before deallocation
+            return Err(serde::de::Error::custom(
+                "deserialized secret exceeds maximum size",
+            ));
+        }
+        // buf stays live (and is zeroized on drop) whether or not the copy succeeds
+        Self::try_new_with(buf.len(), |s| s.push_str(&buf)).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde-deserialize")]
+impl Dynamic<[u8]> {
+    /// Deserializes into an exact-size `Dynamic<[u8]>`, rejecting payloads larger
+    /// than `limit` bytes.
+    ///
+    /// Deserializes through `Vec<u8>` exactly like
+    /// [`Dynamic::<Vec<u8>>::deserialize_with_limit`](Dynamic::deserialize_with_limit)
+    /// — same zeroization scope, same post-materialization limit — then copies the
+    /// bytes into a fresh `Box<[u8]>` while the `Vec` is still held in `Zeroizing`.
+    /// No shrink-`realloc` ever touches the secret.
+    pub fn deserialize_with_limit<'de, D>(deserializer: D, limit: usize) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let buf: zeroize::Zeroizing<alloc::vec::Vec<u8>> =
+            zeroize::Zeroizing::new(serde::Deserialize::deserialize(deserializer)?);
+        if buf.len() > limit {
+            // buf drops here → Zeroizing zeros the oversized buffer before deallocation
+            return Err(serde::de::Error::custom(
+                "deserialized secret exceeds maximum size",
+            ));
+        }
+        Ok(Self::from_protected_bytes(buf))
+    }
+
+    /// Like [`deserialize_with_limit`](Self::deserialize_with_limit), but copies
+    /// into the final `Box<[u8]>` with a fallible reservation. An allocation
+    /// failure is reported as a deserializer error whose message is
+    /// [`AllocError`](crate::AllocError)'s `Display`.
+    ///
+    /// Only the copy made by this crate is fallible: the intermediate `Vec<u8>`
+    /// is built by the upstream deserializer with ordinary allocation. Bound the
+    /// payload at the transport layer when the input size is untrusted.
+    pub fn try_deserialize_with_limit<'de, D>(
+        deserializer: D,
+        limit: usize,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let buf: zeroize::Zeroizing<alloc::vec::Vec<u8>> =
+            zeroize::Zeroizing::new(serde::Deserialize::deserialize(deserializer)?);
+        if buf.len() > limit {
+            // buf drops here → Zeroizing zeros the oversized buffer before deallocation
+            return Err(serde::de::Error::custom(
+                "deserialized secret exceeds maximum size",
+            ));
+        }
+        // buf stays live (and is zeroized on drop) whether or not the copy succeeds
+        Self::try_new(&buf).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde-deserialize")]
+impl Dynamic<str> {
+    /// Deserializes into an exact-size `Dynamic<str>`, rejecting payloads larger
+    /// than `limit` bytes.
+    ///
+    /// Deserializes through `String` exactly like
+    /// [`Dynamic::<String>::deserialize_with_limit`](Dynamic::deserialize_with_limit),
+    /// then copies the string into a fresh `Box<str>` while the `String` is still
+    /// held in `Zeroizing`.
+    pub fn deserialize_with_limit<'de, D>(deserializer: D, limit: usize) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let buf: zeroize::Zeroizing<alloc::string::String> =
+            zeroize::Zeroizing::new(serde::Deserialize::deserialize(deserializer)?);
+        if buf.len() > limit {
+            // buf drops here → Zeroizing zeros the oversized buffer before deallocation
+            return Err(serde::de::Error::custom(
+                "deserialized secret exceeds maximum size",
+            ));
+        }
+        Ok(Self::from_protected_bytes(buf))
+    }
+
+    /// Like [`deserialize_with_limit`](Self::deserialize_with_limit), but copies
+    /// into the final `Box<str>` with a fallible reservation. Same scope as
+    /// [`Dynamic::<[u8]>::try_deserialize_with_limit`](Dynamic::try_deserialize_with_limit):
+    /// the upstream deserializer's own `String` is allocated normally.
+    pub fn try_deserialize_with_limit<'de, D>(
+        deserializer: D,
+        limit: usize,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let buf: zeroize::Zeroizing<alloc::string::String> =
+            zeroize::Zeroizing::new(serde::Deserialize::deserialize(deserializer)?);
+        if buf.len() > limit {
+            // buf drops here → Zeroizing zeros the oversized buffer before deallocation
+            return Err(serde::de::Error::custom(
+                "deserialized secret exceeds maximum size",
+            ));
+        }
+        // buf stays live (and is zeroized on drop) whether or not the copy succeeds
+        Self::try_new(&buf).map_err(serde::de::Error::custom)
+    }
 }
 
 #[cfg(feature = "serde-deserialize")]
@@ -958,6 +2568,26 @@ impl<'de> serde::Deserialize<'de> for Dynamic<String> {
     }
 }
 
+#[cfg(feature = "serde-deserialize")]
+impl<'de> serde::Deserialize<'de> for Dynamic<[u8]> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::deserialize_with_limit(deserializer, MAX_DESERIALIZE_BYTES)
+    }
+}
+
+#[cfg(feature = "serde-deserialize")]
+impl<'de> serde::Deserialize<'de> for Dynamic<str> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::deserialize_with_limit(deserializer, MAX_DESERIALIZE_BYTES)
+    }
+}
+
 /// Zeroizes the inner value (including `Vec`/`String` spare capacity).
 ///
 /// **Warning:** does not run under `panic = "abort"`.
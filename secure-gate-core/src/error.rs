@@ -9,6 +9,9 @@
 //! | [`Base64Error`] | [`Fixed::try_from_base64url`](crate::Fixed::try_from_base64url), [`FromBase64UrlStr`](crate::FromBase64UrlStr) | `encoding-base64` |
 //! | [`Bech32Error`] | `try_from_bech32*`, [`FromBech32Str`](crate::FromBech32Str), [`FromBech32mStr`](crate::FromBech32mStr) | `encoding-bech32` / `encoding-bech32m` |
 //! | [`DecodingError`] | Unified wrapper for all above | Always |
+//! | [`RevealError`] | [`TryRevealSecret::try_with_secret`](crate::TryRevealSecret::try_with_secret) on [`Budgeted`](crate::Budgeted) / `Expiring` | Always |
+//! | [`CapacityError`] | Capacity-bounded `Dynamic` writes ([`FixedCapacity`](crate::FixedCapacity), [`DynamicWriter`](crate::DynamicWriter)) | `alloc` |
+//! | [`AllocError`] | Fallible `Dynamic` constructors ([`try_new_with`](crate::Dynamic::try_new_with), [`try_from_slice`](crate::Dynamic::try_from_slice), `try_from_random`, `try_new`, `try_deserialize_with_limit`) | `alloc` |
 //!
 //! # Design: build-invariant, heap-free, forward-compatible
 //!
@@ -34,7 +37,8 @@
 
 use thiserror::Error;
 
-/// Error returned when a byte slice cannot be converted to a fixed-size array.
+/// Error returned when a byte slice cannot be converted to a fixed-size array, or
+/// assigned into a secret of a different length.
 ///
 /// Carries the expected and actual lengths in all build profiles. Lengths are
 /// public protocol parameters, not secret material.
@@ -170,3 +174,73 @@ pub enum DecodingError {
     #[error("invalid encoding")]
     InvalidEncoding,
 }
+
+/// Error returned when a policy-limited secret can no longer be revealed.
+///
+/// Produced by [`TryRevealSecret::try_with_secret`](crate::TryRevealSecret::try_with_secret).
+/// The closure is not called and the secret is (or has already been) zeroized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum RevealError {
+    /// The wrapper's reveal budget is spent.
+    #[error("reveal budget exhausted")]
+    BudgetExhausted,
+    /// The wrapper's deadline has passed.
+    #[error("secret expired")]
+    Expired,
+}
+
+/// Error returned when a capacity-bounded write would exceed the buffer's capacity.
+///
+/// Produced by the non-allocating `try_*` mutators on
+/// [`FixedCapacity`](crate::FixedCapacity) and by [`DynamicWriter`](crate::DynamicWriter). The buffer
+/// is left unchanged — no partial write is performed.
+///
+/// *Requires feature `alloc`.*
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum CapacityError {
+    /// The write needs more bytes than the fixed capacity provides.
+    #[error("write exceeds fixed capacity: capacity {capacity}, required {required}")]
+    #[non_exhaustive]
+    CapacityExceeded {
+        /// The buffer's fixed capacity in bytes.
+        capacity: usize,
+        /// Total length in bytes the write would have produced.
+        required: usize,
+    },
+}
+
+/// Error returned when a fallible constructor cannot allocate the secret's buffer.
+///
+/// Produced by the `try_*` constructors on `Dynamic` (`try_new`, `try_new_with`,
+/// `try_from_slice`, `try_from_random`), which reserve memory with
+/// `try_reserve_exact` instead of aborting on out-of-memory. Nothing is
+/// allocated and no secret bytes are copied when this error is returned.
+///
+/// *Requires feature `alloc`.*
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum AllocError {
+    /// The allocator refused the request, or the size overflowed `isize::MAX`.
+    #[error("secret allocation failed: {requested} bytes requested")]
+    #[non_exhaustive]
+    AllocationFailed {
+        /// Number of bytes the constructor tried to reserve.
+        requested: usize,
+    },
+}
+
+#[cfg(feature = "alloc")]
+impl AllocError {
+    /// Maps a [`TryReserveError`](alloc::collections::TryReserveError) for a
+    /// `requested`-byte reservation.
+    #[inline]
+    pub(crate) fn from_reserve(
+        requested: usize,
+    ) -> impl FnOnce(alloc::collections::TryReserveError) -> Self {
+        move |_| Self::AllocationFailed { requested }
+    }
+}
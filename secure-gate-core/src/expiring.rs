@@ -0,0 +1,206 @@
+//! Time-limited wrapper that zeroizes its secret after a TTL.
+//!
+//! [`Expiring<S, C>`] wraps a [`Fixed<T>`](crate::Fixed) or [`Dynamic<T>`](crate::Dynamic)
+//! together with a deadline. Reveals go through
+//! [`TryRevealSecret::try_with_secret`]: before the deadline they behave like
+//! `with_secret`; at or after it the wrapped secret is zeroized and the call fails
+//! with [`RevealError::Expired`]. [`purge_if_expired`](Expiring::purge_if_expired)
+//! lets a housekeeping task wipe expired secrets that nobody is reading.
+//!
+//! Time comes from a [`Clock`]. The default [`SystemClock`] reads
+//! [`std::time::Instant`]; tests can plug in a clock they control.
+//!
+//! *Requires feature `std`.*
+//!
+//! # Security invariants
+//!
+//! - **No infallible access** — `Expiring` implements neither `RevealSecret` nor
+//!   `RevealSecretMut`, and has no `expose_secret` or `into_inner`.
+//! - **Expiry is checked on every reveal**, before the closure runs. A closure that
+//!   starts before the deadline runs to completion.
+//! - **Zeroization is lazy** — nothing runs in the background. An expired secret is
+//!   wiped on the next reveal, on `purge_if_expired`, or on drop, whichever comes
+//!   first. Call `purge_if_expired` periodically when secrets may sit unread.
+//! - **`Debug` is redacted** — the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT)
+//!   marker, with the secret's length under `redact-length`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::cell::Cell;
+//! use std::time::{Duration, Instant};
+//! use secure_gate::{Clock, Expiring, Fixed, RevealError, TryRevealSecret};
+//!
+//! struct FakeClock {
+//!     base: Instant,
+//!     elapsed: Cell<Duration>,
+//! }
+//!
+//! impl Clock for FakeClock {
+//!     fn now(&self) -> Instant {
+//!         self.base + self.elapsed.get()
+//!     }
+//! }
+//!
+//! let clock = FakeClock { base: Instant::now(), elapsed: Cell::new(Duration::ZERO) };
+//! let mut key = Expiring::with_clock(Fixed::new([1u8; 32]), Duration::from_secs(60), &clock);
+//! assert_eq!(key.try_with_secret(|k| k[0]), Ok(1));
+//!
+//! clock.elapsed.set(Duration::from_secs(61));
+//! assert_eq!(key.try_with_secret(|k| k[0]), Err(RevealError::Expired));
+//! ```
+
+use std::time::{Duration, Instant};
+
+use zeroize::Zeroize;
+
+use crate::{RevealError, RevealSecret, TryRevealSecret};
+
+/// Source of the current time for [`Expiring`].
+///
+/// Implemented by [`SystemClock`] and by `&C` for any clock `C`, so a test can keep
+/// ownership of its fake clock and lend it to the wrapper.
+pub trait Clock {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+}
+
+/// The default [`Clock`]: [`Instant::now`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline(always)]
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    #[inline(always)]
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
+
+/// Secret wrapper that stops being revealable — and zeroizes itself — once its
+/// deadline passes.
+///
+/// Built with [`Expiring::new`] (system clock) or [`Expiring::with_clock`]. See the
+/// [`TryRevealSecret`] impl for the access path.
+pub struct Expiring<S: RevealSecret + Zeroize, C: Clock = SystemClock> {
+    inner: S,
+    /// `None` when `now + ttl` does not fit in an [`Instant`]: never expires.
+    deadline: Option<Instant>,
+    purged: bool,
+    clock: C,
+}
+
+impl<S: RevealSecret + Zeroize> Expiring<S> {
+    /// Wraps `secret`, expiring `ttl` from now on the system clock.
+    ///
+    /// A `ttl` too large to add to the current [`Instant`] (e.g.
+    /// [`Duration::MAX`]) means the secret never expires.
+    #[inline]
+    pub fn new(secret: S, ttl: Duration) -> Self {
+        Self::with_clock(secret, ttl, SystemClock)
+    }
+}
+
+impl<S: RevealSecret + Zeroize, C: Clock> Expiring<S, C> {
+    /// Wraps `secret`, expiring `ttl` from `clock.now()`.
+    ///
+    /// If `clock.now() + ttl` overflows [`Instant`], the secret never expires.
+    #[inline]
+    pub fn with_clock(secret: S, ttl: Duration, clock: C) -> Self {
+        let deadline = clock.now().checked_add(ttl);
+        Self {
+            inner: secret,
+            deadline,
+            purged: false,
+            clock,
+        }
+    }
+
+    /// The instant at (and after) which the secret is no longer revealable, or
+    /// `None` if the TTL overflowed [`Instant`] and the secret never expires.
+    #[inline(always)]
+    pub const fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Time left before expiry; [`Duration::ZERO`] once expired, [`Duration::MAX`]
+    /// if the secret never expires.
+    ///
+    /// Always safe to call — does not expose secret contents.
+    #[inline]
+    pub fn remaining_ttl(&self) -> Duration {
+        if self.purged {
+            return Duration::ZERO;
+        }
+        match self.deadline {
+            Some(deadline) => deadline.saturating_duration_since(self.clock.now()),
+            None => Duration::MAX,
+        }
+    }
+
+    /// Returns `true` if the deadline has passed (or the secret was already purged).
+    ///
+    /// Always safe to call — does not expose secret contents.
+    #[inline]
+    pub fn is_expired(&self) -> bool {
+        self.purged || self.deadline_passed()
+    }
+
+    /// Zeroizes the secret if the deadline has passed.
+    ///
+    /// Returns `true` if the secret is now purged (including when it already was).
+    /// Use from a periodic housekeeping task so expired secrets that nobody reads
+    /// do not linger in memory until drop.
+    #[inline]
+    pub fn purge_if_expired(&mut self) -> bool {
+        if !self.purged && self.deadline_passed() {
+            self.inner.zeroize();
+            self.purged = true;
+        }
+        self.purged
+    }
+
+    #[inline]
+    fn deadline_passed(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| self.clock.now() >= deadline)
+    }
+}
+
+/// Deadline-limited access.
+///
+/// Purges and fails if the deadline has passed; otherwise runs `f`.
+impl<S: RevealSecret + Zeroize, C: Clock> TryRevealSecret for Expiring<S, C> {
+    type Inner = S::Inner;
+
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn try_with_secret<F, R>(&mut self, f: F) -> Result<R, RevealError>
+    where
+        F: FnOnce(&S::Inner) -> R,
+    {
+        if self.purge_if_expired() {
+            return Err(RevealError::Expired);
+        }
+        Ok(self.inner.with_secret(f))
+    }
+}
+
+/// Prints the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT) marker
+/// for the wrapped secret's [`byte_len`](RevealSecret::byte_len).
+impl<S: RevealSecret + Zeroize, C: Clock> core::fmt::Debug for Expiring<S, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let marker = crate::RedactionPolicy::DEFAULT.redact(self.inner.byte_len());
+        core::fmt::Display::fmt(&marker, f)
+    }
+}
+
+impl<S: RevealSecret + Zeroize + zeroize::ZeroizeOnDrop, C: Clock> zeroize::ZeroizeOnDrop
+    for Expiring<S, C>
+{
+}
@@ -0,0 +1,364 @@
+//! Keyed fingerprints: loggable, hashable identifiers for secrets.
+//!
+//! Secret wrappers deliberately implement neither `Hash` nor `PartialEq` (see
+//! `docs/security_hash_eq.md`). Yet applications still need to deduplicate keys,
+//! index them, and say *which* key was used in a log line. A [`Fingerprint`] is a
+//! 128-bit identifier derived from the secret with a keyed hash:
+//!
+//! ```text
+//! fingerprint = BLAKE2s-256-MAC(fingerprint_key, secret)[..16]
+//! ```
+//!
+//! Because the hash is **keyed**, a fingerprint is useless for offline guessing
+//! without the [`FingerprintKey`]: an attacker who sees `3f9a…` in a log cannot
+//! test candidate passwords against it. Fingerprints are only comparable when
+//! computed under the same key — use [`FingerprintKey::process`] for one random
+//! key shared by the whole process, or [`FingerprintKey::new`] when fingerprints
+//! must be stable across restarts.
+//!
+//! [`Fingerprint`] is **not** secret: it implements `Hash`, `Eq`, `Ord` and
+//! `Display` (32 lowercase hex characters) and is safe to log or store.
+//! [`SecretKeyedMap`] uses it to key a map by secrets without keeping copies of
+//! them.
+//!
+//! *Requires feature `fingerprint`* (pulls in `rand` and `blake2`).
+//!
+//! # Security notes
+//!
+//! - **Equality of fingerprints reveals equality of secrets** (under one key).
+//!   That is the purpose; do not publish fingerprints where that is a leak.
+//! - **Low-entropy secrets** (passwords, PINs) are only protected while the key
+//!   stays secret. A leaked key turns every logged fingerprint into an offline
+//!   guessing oracle.
+//! - **128 bits** keeps accidental collisions negligible (~2⁻⁶⁴ after 2³² secrets);
+//!   it is an identifier, not a MAC tag.
+//! - Computing a fingerprint is a Tier 1 reveal and is reported to the `audit`
+//!   hook like any other `with_secret` call.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(feature = "std")]
+//! # {
+//! use secure_gate::{Fixed, FingerprintKey};
+//!
+//! let key = Fixed::new([0x42u8; 32]);
+//! let fp = key.fingerprint(FingerprintKey::process());
+//!
+//! assert_eq!(fp.to_string().len(), 32);
+//! assert_eq!(fp, Fixed::new([0x42u8; 32]).fingerprint(FingerprintKey::process()));
+//! # }
+//! ```
+
+use alloc::collections::BTreeMap;
+use core::fmt;
+
+use blake2::Blake2sMac256;
+use blake2::digest::{FixedOutput, KeyInit, Update};
+use rand::{TryRng, rngs::SysRng};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{Dynamic, Fixed, RevealSecret};
+
+/// Length of a [`FingerprintKey`] in bytes (the BLAKE2s maximum key size).
+const KEY_LEN: usize = 32;
+
+/// Length of a [`Fingerprint`] in bytes.
+const FINGERPRINT_LEN: usize = 16;
+
+/// Secret key for computing [`Fingerprint`]s. Zeroized on drop; `Debug` is redacted.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct FingerprintKey([u8; KEY_LEN]);
+
+impl FingerprintKey {
+    /// Wraps caller-supplied key bytes, for fingerprints that must be stable
+    /// across processes (e.g. stored in a database).
+    #[inline]
+    pub const fn new(bytes: [u8; KEY_LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Draws a fresh key from the system RNG.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system RNG fails.
+    pub fn random() -> Self {
+        let mut key = Self([0u8; KEY_LEN]);
+        SysRng
+            .try_fill_bytes(&mut key.0)
+            .expect("SysRng failure is a program error");
+        key
+    }
+
+    /// The process-wide key, drawn from the system RNG on first use.
+    ///
+    /// Fingerprints computed under it are comparable for the lifetime of the
+    /// process and meaningless afterwards. Requires `std`.
+    ///
+    /// # Panics
+    ///
+    /// Panics on first use if the system RNG fails.
+    #[cfg(feature = "std")]
+    pub fn process() -> &'static Self {
+        static KEY: std::sync::OnceLock<FingerprintKey> = std::sync::OnceLock::new();
+        KEY.get_or_init(Self::random)
+    }
+
+    /// Computes the fingerprint of raw bytes under this key.
+    fn compute(&self, bytes: &[u8]) -> Fingerprint {
+        let mut mac = <Blake2sMac256 as KeyInit>::new_from_slice(&self.0)
+            .expect("32-byte key is within the BLAKE2s key limit");
+        mac.update(bytes);
+        let mut tag = mac.finalize_fixed();
+        let mut out = [0u8; FINGERPRINT_LEN];
+        out.copy_from_slice(&tag[..FINGERPRINT_LEN]);
+        tag.as_mut_slice().zeroize();
+        Fingerprint(out)
+    }
+
+    /// Fingerprints any revealable secret whose inner value is byte-like.
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn of<S>(&self, secret: &S) -> Fingerprint
+    where
+        S: RevealSecret + ?Sized,
+        S::Inner: AsRef<[u8]>,
+    {
+        secret.with_secret(|inner| self.compute(inner.as_ref()))
+    }
+}
+
+impl fmt::Debug for FingerprintKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+/// 128-bit keyed identifier of a secret. Not secret itself: safe to log, hash,
+/// compare and store.
+///
+/// `Display` prints 32 lowercase hex characters; `Debug` prints
+/// `Fingerprint(<hex>)`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Fingerprint([u8; FINGERPRINT_LEN]);
+
+impl Fingerprint {
+    /// The raw fingerprint bytes.
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; FINGERPRINT_LEN] {
+        &self.0
+    }
+
+    /// Lowercase hex digits, as ASCII.
+    pub(crate) fn hex(&self) -> [u8; 2 * FINGERPRINT_LEN] {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let mut out = [0u8; 2 * FINGERPRINT_LEN];
+        for (pair, b) in out.chunks_exact_mut(2).zip(self.0) {
+            pair[0] = DIGITS[usize::from(b >> 4)];
+            pair[1] = DIGITS[usize::from(b & 0x0f)];
+        }
+        out
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = self.hex();
+        f.write_str(core::str::from_utf8(&hex).expect("hex digits are ASCII"))
+    }
+}
+
+impl fmt::Debug for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fingerprint({self})")
+    }
+}
+
+impl<T: Zeroize> Fixed<T>
+where
+    Self: RevealSecret,
+    <Self as RevealSecret>::Inner: AsRef<[u8]>,
+{
+    /// Computes a keyed [`Fingerprint`] of the secret bytes.
+    ///
+    /// Equal secrets give equal fingerprints under the same `key`. Requires
+    /// `fingerprint`.
+    ///
+    /// ```rust
+    /// use secure_gate::{Fixed, FingerprintKey};
+    ///
+    /// let fp_key = FingerprintKey::new([7u8; 32]);
+    /// let a = Fixed::new([1u8; 16]).fingerprint(&fp_key);
+    /// let b = Fixed::new([2u8; 16]).fingerprint(&fp_key);
+    /// assert_ne!(a, b);
+    /// ```
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn fingerprint(&self, key: &FingerprintKey) -> Fingerprint {
+        key.of(self)
+    }
+}
+
+impl<T: ?Sized + Zeroize> Dynamic<T>
+where
+    Self: RevealSecret,
+    <Self as RevealSecret>::Inner: AsRef<[u8]>,
+{
+    /// Computes a keyed [`Fingerprint`] of the secret bytes.
+    ///
+    /// `Dynamic<String>` fingerprints its UTF-8 bytes, so it matches a
+    /// `Dynamic<Vec<u8>>` holding the same bytes. Requires `fingerprint`.
+    ///
+    /// ```rust
+    /// use secure_gate::{Dynamic, FingerprintKey};
+    ///
+    /// let fp_key = FingerprintKey::new([7u8; 32]);
+    /// let pw = Dynamic::<String>::new(String::from("hunter2"));
+    /// let raw = Dynamic::<Vec<u8>>::new(b"hunter2".to_vec());
+    /// assert_eq!(pw.fingerprint(&fp_key), raw.fingerprint(&fp_key));
+    /// ```
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn fingerprint(&self, key: &FingerprintKey) -> Fingerprint {
+        key.of(self)
+    }
+}
+
+/// Map keyed by secrets, storing only their [`Fingerprint`]s.
+///
+/// The secrets themselves are never copied into the map: each lookup reveals the
+/// secret once (Tier 1) to fingerprint it under the map's own key. Works with any
+/// [`RevealSecret`] whose inner value is byte-like — `Fixed<[u8; N]>`,
+/// `Dynamic<Vec<u8>>`, `Dynamic<String>`, [`Frozen`](crate::Frozen), and so on.
+///
+/// Backed by a `BTreeMap`, so it is available without `std` and is not exposed to
+/// hash flooding. `Debug` prints only the entry count. Requires `fingerprint`.
+///
+/// ```rust
+/// use secure_gate::{Dynamic, SecretKeyedMap};
+///
+/// let mut sessions = SecretKeyedMap::new();
+/// let token = Dynamic::<String>::new(String::from("tok_3f9a"));
+/// sessions.insert(&token, "alice");
+///
+/// let presented = Dynamic::<String>::new(String::from("tok_3f9a"));
+/// assert_eq!(sessions.get(&presented), Some(&"alice"));
+/// ```
+pub struct SecretKeyedMap<V> {
+    key: FingerprintKey,
+    entries: BTreeMap<Fingerprint, V>,
+}
+
+impl<V> SecretKeyedMap<V> {
+    /// Creates an empty map with a fresh random [`FingerprintKey`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system RNG fails.
+    pub fn new() -> Self {
+        Self::with_key(FingerprintKey::random())
+    }
+
+    /// Creates an empty map that fingerprints under `key`.
+    pub fn with_key(key: FingerprintKey) -> Self {
+        Self {
+            key,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// The fingerprint this map would store `secret` under — useful for logging
+    /// which entry a lookup hit.
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn fingerprint<S>(&self, secret: &S) -> Fingerprint
+    where
+        S: RevealSecret + ?Sized,
+        S::Inner: AsRef<[u8]>,
+    {
+        self.key.of(secret)
+    }
+
+    /// Inserts `value` under `secret`, returning the previous value if any.
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn insert<S>(&mut self, secret: &S, value: V) -> Option<V>
+    where
+        S: RevealSecret + ?Sized,
+        S::Inner: AsRef<[u8]>,
+    {
+        let fp = self.fingerprint(secret);
+        self.entries.insert(fp, value)
+    }
+
+    /// Returns the value stored under `secret`.
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn get<S>(&self, secret: &S) -> Option<&V>
+    where
+        S: RevealSecret + ?Sized,
+        S::Inner: AsRef<[u8]>,
+    {
+        self.entries.get(&self.fingerprint(secret))
+    }
+
+    /// Returns a mutable reference to the value stored under `secret`.
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn get_mut<S>(&mut self, secret: &S) -> Option<&mut V>
+    where
+        S: RevealSecret + ?Sized,
+        S::Inner: AsRef<[u8]>,
+    {
+        let fp = self.fingerprint(secret);
+        self.entries.get_mut(&fp)
+    }
+
+    /// Removes and returns the value stored under `secret`.
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn remove<S>(&mut self, secret: &S) -> Option<V>
+    where
+        S: RevealSecret + ?Sized,
+        S::Inner: AsRef<[u8]>,
+    {
+        let fp = self.fingerprint(secret);
+        self.entries.remove(&fp)
+    }
+
+    /// `true` if a value is stored under `secret`.
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn contains_key<S>(&self, secret: &S) -> bool
+    where
+        S: RevealSecret + ?Sized,
+        S::Inner: AsRef<[u8]>,
+    {
+        self.entries.contains_key(&self.fingerprint(secret))
+    }
+
+    /// Iterates over the stored fingerprints and values, in fingerprint order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Fingerprint, &V)> {
+        self.entries.iter()
+    }
+
+    /// Number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// `true` if the map holds no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<V> Default for SecretKeyedMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> fmt::Debug for SecretKeyedMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretKeyedMap")
+            .field("len", &self.entries.len())
+            .finish_non_exhaustive()
+    }
+}
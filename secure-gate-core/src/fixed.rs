@@ -8,8 +8,9 @@
 //!
 //! - **No `Deref`, `AsRef`, or `Copy`** — the inner value cannot leak through
 //!   implicit conversions.
-//! - **`Debug` always prints `[REDACTED]`** — secrets never appear in logs or
-//!   panic messages.
+//! - **`Debug` is always redacted** — `[REDACTED]`, or `[REDACTED; N bytes]` under
+//!   `redact-length` ([`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT));
+//!   secrets never appear in logs or panic messages.
 //! - **Unconditional zeroization on drop** — the inner `T` is overwritten with
 //!   zeroes when the wrapper is dropped, even on error paths.
 //! - **Opt-in `Clone`** — requires `T: CloneableSecret` and the `cloneable` feature.
@@ -55,6 +56,13 @@
 //! let owned = secret.into_inner();
 //! ```
 //!
+//! # Scalar and composite secrets
+//!
+//! Beyond byte arrays, `Fixed<T>` reveals any inner type implementing
+//! [`OpaqueSecret`](crate::OpaqueSecret) — the primitive integers out of the box
+//! (`Fixed<u64>` counters, seeds, PINs) and user structs that opt in. These report
+//! `len() == 1` and `byte_len() == size_of::<T>()`.
+//!
 //! # Warning: no `static` secrets
 //!
 //! `Drop` does not run on `static` items. Placing a `Fixed` in a `static` or
@@ -151,8 +159,8 @@ fn drain_bech32_payload<const N: usize>(
 /// let first = key.with_secret(|k| k[0]);
 /// assert_eq!(first, 0xAB);
 ///
-/// // Debug is always redacted.
-/// assert_eq!(format!("{:?}", key), "[REDACTED]");
+/// // Debug is always redacted: `[REDACTED]` (plus a length with `redact-length`).
+/// assert!(format!("{:?}", key).starts_with("[REDACTED"));
 /// ```
 ///
 /// # Constructors
@@ -317,6 +325,149 @@ impl<const N: usize> Fixed<[u8; N]> {
     }
 }
 
+/// Splitting and concatenation for `Fixed<[u8; N]>`.
+///
+/// Key schedules routinely cut a master key into sub-keys or join a nonce and a
+/// key. All sizes are const generics and the size relation is checked at compile
+/// time — a mismatch is a build error, never a runtime panic. Every output part is
+/// filled in place with [`new_with`](Self::new_with), so the bytes are copied from
+/// the source wrapper directly into the destination wrapper's storage with no
+/// intermediate plaintext buffer.
+///
+/// The sources are borrowed, not consumed: drop them (or let them fall out of
+/// scope) once the parts are built so they are zeroized.
+impl<const N: usize> Fixed<[u8; N]> {
+    /// Splits the secret into a leading `A`-byte part and a trailing `B`-byte part.
+    ///
+    /// `A + B == N` is enforced at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secure_gate::{Fixed, RevealSecret};
+    ///
+    /// let master = Fixed::new([7u8; 64]);
+    /// let (enc, mac) = master.split::<32, 32>();
+    /// assert_eq!(enc.len() + mac.len(), 64);
+    /// ```
+    ///
+    /// A size mismatch does not compile:
+    ///
+    /// ```compile_fail
+    /// use secure_gate::Fixed;
+    ///
+    /// let master = Fixed::new([7u8; 64]);
+    /// let (_a, _b) = master.split::<32, 16>();
+    /// ```
+    #[inline]
+    pub fn split<const A: usize, const B: usize>(&self) -> (Fixed<[u8; A]>, Fixed<[u8; B]>) {
+        const { assert!(A + B == N, "split: A + B must equal N") };
+        let head = Fixed::new_with(|out| out.copy_from_slice(&self.inner[..A]));
+        let tail = Fixed::new_with(|out| out.copy_from_slice(&self.inner[A..]));
+        (head, tail)
+    }
+
+    /// Concatenates `a` followed by `b` into a new `Fixed<[u8; N]>`.
+    ///
+    /// `A + B == N` is enforced at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secure_gate::{Fixed, RevealSecret};
+    ///
+    /// let nonce = Fixed::new([1u8; 12]);
+    /// let key = Fixed::new([2u8; 32]);
+    /// let joined: Fixed<[u8; 44]> = Fixed::concat(&nonce, &key);
+    /// joined.with_secret(|b| assert_eq!((b[11], b[12]), (1, 2)));
+    /// ```
+    #[inline]
+    pub fn concat<const A: usize, const B: usize>(a: &Fixed<[u8; A]>, b: &Fixed<[u8; B]>) -> Self {
+        const { assert!(A + B == N, "concat: A + B must equal N") };
+        Self::new_with(|out| {
+            out[..A].copy_from_slice(&a.inner);
+            out[A..].copy_from_slice(&b.inner);
+        })
+    }
+
+    /// Splits the secret into `K` consecutive parts of `M` bytes each.
+    ///
+    /// `M * K == N` is enforced at compile time. Use [`split`](Self::split) for
+    /// two parts of different sizes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secure_gate::{Fixed, RevealSecret};
+    ///
+    /// let schedule = Fixed::new([0xA5u8; 96]);
+    /// let [enc, mac, iv] = schedule.split_array::<32, 3>();
+    /// assert_eq!(enc.len() + mac.len() + iv.len(), 96);
+    /// ```
+    #[inline]
+    pub fn split_array<const M: usize, const K: usize>(&self) -> [Fixed<[u8; M]>; K] {
+        const { assert!(M * K == N, "split_array: M * K must equal N") };
+        core::array::from_fn(|i| {
+            Fixed::new_with(|out| out.copy_from_slice(&self.inner[i * M..(i + 1) * M]))
+        })
+    }
+}
+
+/// Borrowed sub-secret views.
+///
+/// `slice` / `slice_mut` return a [`SecretRef`](crate::SecretRef) /
+/// [`SecretMut`](crate::SecretMut) over a sub-range of the array. The view
+/// implements [`RevealSecret`] (and [`RevealSecretMut`]), so a slice of a key can be
+/// passed to another component without ever handing over a raw `&[u8]`.
+impl<const N: usize, T: zeroize::Zeroize> Fixed<[T; N]> {
+    /// Returns a read-only view of `index` (any range type).
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds, like slice indexing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secure_gate::{Fixed, RevealSecret};
+    ///
+    /// let schedule = Fixed::new(core::array::from_fn::<u8, 48, _>(|i| i as u8));
+    /// let mac = schedule.slice(16..32);
+    /// assert_eq!(mac.len(), 16);
+    /// mac.with_secret(|m| assert_eq!(m[0], 16));
+    /// ```
+    #[inline(always)]
+    pub fn slice<I>(&self, index: I) -> crate::SecretRef<'_, [T]>
+    where
+        I: core::slice::SliceIndex<[T], Output = [T]>,
+    {
+        crate::SecretRef::new(&self.inner[index])
+    }
+
+    /// Returns a mutable view of `index` (any range type).
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds, like slice indexing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secure_gate::{Fixed, RevealSecret, RevealSecretMut};
+    ///
+    /// let mut key = Fixed::new([0u8; 8]);
+    /// key.slice_mut(4..).with_secret_mut(|tail| tail.fill(0xFF));
+    /// key.with_secret(|k| assert_eq!(k, &[0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF]));
+    /// ```
+    #[inline(always)]
+    pub fn slice_mut<I>(&mut self, index: I) -> crate::SecretMut<'_, [T]>
+    where
+        I: core::slice::SliceIndex<[T], Output = [T]>,
+    {
+        crate::SecretMut::new(&mut self.inner[index])
+    }
+}
+
 /// Hex encoding and decoding for `Fixed<[u8; N]>`.
 ///
 /// Encoding uses a constant-time backend (`base16ct`). Decoding works with or without
@@ -341,6 +492,7 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// ```
     #[cfg(feature = "alloc")]
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn to_hex(&self) -> alloc::string::String {
         self.with_secret(|s: &[u8; N]| s.to_hex())
     }
@@ -362,6 +514,7 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// ```
     #[cfg(feature = "alloc")]
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn to_hex_upper(&self) -> alloc::string::String {
         self.with_secret(|s: &[u8; N]| s.to_hex_upper())
     }
@@ -390,6 +543,7 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// ```
     #[cfg(feature = "alloc")]
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn to_hex_zeroizing(&self) -> crate::EncodedSecret {
         self.with_secret(|s: &[u8; N]| s.to_hex_zeroizing())
     }
@@ -413,6 +567,7 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// ```
     #[cfg(feature = "alloc")]
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn to_hex_upper_zeroizing(&self) -> crate::EncodedSecret {
         self.with_secret(|s: &[u8; N]| s.to_hex_upper_zeroizing())
     }
@@ -512,6 +667,7 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// ```
     #[cfg(feature = "alloc")]
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn to_base64url(&self) -> alloc::string::String {
         self.with_secret(|s: &[u8; N]| s.to_base64url())
     }
@@ -540,6 +696,7 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// ```
     #[cfg(feature = "alloc")]
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn to_base64url_zeroizing(&self) -> crate::EncodedSecret {
         self.with_secret(|s: &[u8; N]| s.to_base64url_zeroizing())
     }
@@ -621,6 +778,7 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// Requires the `encoding-bech32` and `alloc` features.
     #[cfg(feature = "alloc")]
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn try_to_bech32(
         &self,
         hrp: &str,
@@ -634,6 +792,7 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// Requires the `encoding-bech32` and `alloc` features.
     #[cfg(feature = "alloc")]
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn try_to_bech32_zeroizing(
         &self,
         hrp: &str,
@@ -695,6 +854,7 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// Requires the `encoding-bech32m` and `alloc` features.
     #[cfg(feature = "alloc")]
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn try_to_bech32m(
         &self,
         hrp: &str,
@@ -708,6 +868,7 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// Requires the `encoding-bech32m` and `alloc` features.
     #[cfg(feature = "alloc")]
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn try_to_bech32m_zeroizing(
         &self,
         hrp: &str,
@@ -764,15 +925,19 @@ impl<const N: usize, T: zeroize::Zeroize> RevealSecret for Fixed<[T; N]> {
     type Inner = [T; N];
 
     #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
     fn with_secret<F, R>(&self, f: F) -> R
     where
         F: FnOnce(&[T; N]) -> R,
     {
+        audit_access!(Scoped, false);
         f(&self.inner)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
     fn expose_secret(&self) -> &[T; N] {
+        audit_access!(Direct, false);
         &self.inner
     }
 
@@ -796,11 +961,13 @@ impl<const N: usize, T: zeroize::Zeroize> RevealSecret for Fixed<[T; N]> {
     /// See [`RevealSecret::into_inner`] for full documentation including the
     /// `SentinelValue` bound rationale and redacted `Debug` behavior.
     #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
     fn into_inner(mut self) -> crate::InnerSecret<[T; N]>
     where
         Self: Sized,
         Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
     {
+        audit_access!(Owned, false);
         // Replace inner with the sentinel so Fixed::drop zeroizes a harmless
         // placeholder while the caller receives the real secret.
         let inner = core::mem::replace(&mut self.inner, crate::SentinelValue::sentinel_value());
@@ -811,19 +978,196 @@ impl<const N: usize, T: zeroize::Zeroize> RevealSecret for Fixed<[T; N]> {
 /// Explicit access to mutable [`Fixed<[T; N]>`] contents.
 impl<const N: usize, T: zeroize::Zeroize> RevealSecretMut for Fixed<[T; N]> {
     #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
     fn with_secret_mut<F, R>(&mut self, f: F) -> R
     where
         F: FnOnce(&mut [T; N]) -> R,
     {
+        audit_access!(Scoped, true);
         f(&mut self.inner)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
     fn expose_secret_mut(&mut self) -> &mut [T; N] {
+        audit_access!(Direct, true);
         &mut self.inner
     }
 }
 
+/// Explicit access to an opaque scalar or composite secret (see
+/// [`OpaqueSecret`](crate::OpaqueSecret)).
+///
+/// The value is treated as one unit: `len()` is `1` and `byte_len()` is
+/// `size_of::<T>()`. Covers primitive integers (`Fixed<u64>` counters, seeds,
+/// PINs) and user structs that opt in.
+impl<T: crate::OpaqueSecret> RevealSecret for Fixed<T> {
+    type Inner = T;
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        audit_access!(Scoped, false);
+        f(&self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &T {
+        audit_access!(Direct, false);
+        &self.inner
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        1
+    }
+
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        core::mem::size_of::<T>()
+    }
+
+    /// Consumes `self` and returns the inner value wrapped in [`crate::InnerSecret`].
+    ///
+    /// Zero cost — no allocation. Requires `T: SentinelValue`; the integer
+    /// sentinels are `0`.
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn into_inner(mut self) -> crate::InnerSecret<T>
+    where
+        Self: Sized,
+        Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        audit_access!(Owned, false);
+        let inner = core::mem::replace(&mut self.inner, crate::SentinelValue::sentinel_value());
+        crate::InnerSecret::new(inner)
+    }
+}
+
+/// Explicit mutable access to an opaque scalar or composite secret.
+impl<T: crate::OpaqueSecret> RevealSecretMut for Fixed<T> {
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        audit_access!(Scoped, true);
+        f(&mut self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret_mut(&mut self) -> &mut T {
+        audit_access!(Direct, true);
+        &mut self.inner
+    }
+}
+
+/// Secret-to-secret transformation.
+///
+/// Derive one secret from another without the result ever existing outside a
+/// wrapper — a subkey from a master key, a checksum-stripped payload, a re-encoding.
+/// Each call is a single grep-able audit point (`map_secret`), and access goes
+/// through [`with_secret`](RevealSecret::with_secret), so only inner types that are
+/// already revealable can be mapped.
+///
+/// # Panic safety
+///
+/// The closure's result is wrapped in `Zeroizing` immediately and swapped into
+/// the new `Fixed<U>` in place of a [`SentinelValue`](crate::SentinelValue) —
+/// the same pattern as [`Dynamic::map_secret`](crate::Dynamic::map_secret). A
+/// panic after the closure returns (for example in `U::sentinel_value`) zeroizes
+/// the result during unwind. The usual stack-move caveat applies to the
+/// closure's return slot.
+impl<T: zeroize::Zeroize> Fixed<T>
+where
+    Self: RevealSecret<Inner = T>,
+{
+    /// Builds a new `Fixed<U>` from a scoped borrow of this secret.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secure_gate::{Fixed, RevealSecret};
+    ///
+    /// let master = Fixed::new([0x42u8; 32]);
+    /// let subkey: Fixed<[u8; 16]> = master.map_secret(|k| {
+    ///     let mut out = [0u8; 16];
+    ///     out.copy_from_slice(&k[..16]);
+    ///     out
+    /// });
+    /// assert_eq!(subkey.len(), 16);
+    /// ```
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn map_secret<U, F>(&self, f: F) -> Fixed<U>
+    where
+        U: zeroize::Zeroize + crate::SentinelValue,
+        F: FnOnce(&T) -> U,
+    {
+        Fixed::from_protected_value(zeroize::Zeroizing::new(self.with_secret(f)))
+    }
+
+    /// Fallible variant of [`map_secret`](Self::map_secret).
+    ///
+    /// # Errors
+    ///
+    /// Returns the closure's error unchanged; no wrapper is built.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn try_map_secret<U, E, F>(&self, f: F) -> Result<Fixed<U>, E>
+    where
+        U: zeroize::Zeroize + crate::SentinelValue,
+        F: FnOnce(&T) -> Result<U, E>,
+    {
+        let out = zeroize::Zeroizing::new(self.with_secret(f)?);
+        Ok(Fixed::from_protected_value(out))
+    }
+}
+
+impl<T: zeroize::Zeroize + crate::SentinelValue> Fixed<T> {
+    /// Moves a protected value into a new wrapper. The wrapper is built around
+    /// the sentinel first, with `protected` still live, so a panic in
+    /// `sentinel_value` zeroizes the value during unwind. After the swap,
+    /// `protected` holds the inert sentinel.
+    #[inline(always)]
+    fn from_protected_value(mut protected: zeroize::Zeroizing<T>) -> Self {
+        let mut this = Self::new(T::sentinel_value());
+        core::mem::swap(&mut this.inner, &mut *protected);
+        this
+    }
+}
+
+/// Read-only freezing.
+impl<T: zeroize::Zeroize> Fixed<T>
+where
+    Self: RevealSecret,
+{
+    /// Consumes the wrapper and returns a [`Frozen`](crate::Frozen) that implements
+    /// [`RevealSecret`] but not [`RevealSecretMut`].
+    ///
+    /// Use it to hand a long-lived root key to subsystems that must never edit it
+    /// in place. Zeroize-on-drop and the redacted `Debug` carry over unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secure_gate::{Fixed, RevealSecret};
+    ///
+    /// let root = Fixed::new([7u8; 32]).freeze();
+    /// assert_eq!(root.len(), 32);
+    /// ```
+    #[inline(always)]
+    pub fn freeze(self) -> crate::Frozen<Self> {
+        crate::Frozen::new(self)
+    }
+}
+
 #[cfg(feature = "rand")]
 impl<const N: usize> Fixed<[u8; N]> {
     /// Fills a new `[u8; N]` with cryptographically secure random bytes and wraps it.
@@ -913,21 +1257,153 @@ where
     Self: crate::RevealSecret<Inner = T>,
 {
     fn ct_eq(&self, other: &Self) -> bool {
-        self.expose_secret().ct_eq(other.expose_secret())
+        self.inner.ct_eq(&other.inner)
+    }
+
+    fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+        self.inner.ct_eq_choice(&other.inner)
+    }
+}
+
+/// `Choice`-returning equality for composing with other `subtle` code.
+///
+/// Call through the trait path (`subtle::ConstantTimeEq::ct_eq(&a, &b)`) when
+/// [`crate::ConstantTimeEq`] is also in scope.
+#[cfg(feature = "ct-eq")]
+impl<const N: usize> subtle::ConstantTimeEq for Fixed<[u8; N]> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&self.inner[..], &other.inner[..])
     }
 }
 
-/// Always prints `[REDACTED]` — secrets never appear in debug output.
+/// Constant-time ordering for `Fixed<[u8; N]>`, reading the bytes as an unsigned
+/// integer in the given [`ByteOrder`](crate::ByteOrder).
+#[cfg(feature = "ct-eq")]
+impl<const N: usize> crate::ConstantTimeOrd for Fixed<[u8; N]> {
+    #[inline]
+    fn ct_cmp(&self, other: &Self, order: crate::ByteOrder) -> crate::CtOrdering {
+        crate::traits::constant_time_ord::ct_cmp_bytes(&self.inner, &other.inner, order)
+    }
+}
+
+/// Branch-free selection for `Fixed<[u8; N]>`.
+///
+/// `subtle::ConditionallySelectable` requires `Copy`, which a zeroizing wrapper
+/// cannot be, so the same operations are provided as inherent methods. Each one
+/// touches every byte of both operands regardless of `choice`.
+///
+/// There is no `subtle::ConditionallyNegatable` counterpart: `subtle` only
+/// implements it for `ConditionallySelectable` types with an arithmetic `Neg`,
+/// and a byte string has no negation. Negate in the group or field type that
+/// owns the arithmetic, then wrap the result.
 ///
 /// ```rust
-/// use secure_gate::Fixed;
+/// # #[cfg(feature = "ct-eq")]
+/// # {
+/// use secure_gate::{Choice, Fixed, RevealSecret};
+///
+/// let a = Fixed::new([1u8; 4]);
+/// let b = Fixed::new([2u8; 4]);
+/// let picked = Fixed::ct_select(&a, &b, Choice::from(1));
+/// assert_eq!(picked.expose_secret(), &[2u8; 4]);
+/// # }
+/// ```
+#[cfg(feature = "ct-eq")]
+impl<const N: usize> Fixed<[u8; N]> {
+    /// Returns a copy of `a` when `choice` is 0 and of `b` when `choice` is 1.
+    #[inline]
+    pub fn ct_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        use subtle::ConditionallySelectable;
+        Self::new_with(|out| {
+            for ((o, x), y) in out.iter_mut().zip(&a.inner).zip(&b.inner) {
+                *o = u8::conditional_select(x, y, choice);
+            }
+        })
+    }
+
+    /// Overwrites `self` with `other` when `choice` is 1; leaves it unchanged when 0.
+    #[inline]
+    pub fn conditional_assign(&mut self, other: &Self, choice: subtle::Choice) {
+        use subtle::ConditionallySelectable;
+        for (s, o) in self.inner.iter_mut().zip(&other.inner) {
+            s.conditional_assign(o, choice);
+        }
+    }
+
+    /// Swaps `a` and `b` when `choice` is 1; leaves both unchanged when 0.
+    #[inline]
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: subtle::Choice) {
+        use subtle::ConditionallySelectable;
+        for (x, y) in a.inner.iter_mut().zip(b.inner.iter_mut()) {
+            u8::conditional_swap(x, y, choice);
+        }
+    }
+
+    /// Finds the secret stored under `key` without revealing, through timing,
+    /// which entry matched or whether any did.
+    ///
+    /// Every entry is compared and every value is read, so the scan costs the same
+    /// for a hit at any position and for a miss. If several keys match, the last
+    /// one wins. Key *lengths* are compared first for variable-length key types —
+    /// use fixed-size keys if their length is sensitive.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "ct-eq")]
+    /// # {
+    /// use secure_gate::Fixed;
+    ///
+    /// let k1 = Fixed::new([1u8; 16]);
+    /// let k2 = Fixed::new([2u8; 16]);
+    /// let table = [(b"id-1", &k1), (b"id-2", &k2)];
+    ///
+    /// let hit = Fixed::ct_lookup(&table, b"id-2");
+    /// assert!(bool::from(hit.is_some()));
+    /// let key = hit.into_option().expect("present");
+    /// assert_eq!(key[0], 2);
+    ///
+    /// assert!(bool::from(Fixed::ct_lookup(&table, b"id-9").is_none()));
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn ct_lookup<K: crate::ConstantTimeEq + ?Sized>(
+        entries: &[(&K, &Self)],
+        key: &K,
+    ) -> subtle::CtOption<crate::InnerSecret<[u8; N]>> {
+        use subtle::ConditionallySelectable;
+        audit_access!(Owned, false);
+        let mut found = subtle::Choice::from(0);
+        let mut acc = zeroize::Zeroizing::new([0u8; N]);
+        for (k, v) in entries {
+            let hit = k.ct_eq_choice(key);
+            for (a, b) in acc.iter_mut().zip(&v.inner) {
+                a.conditional_assign(b, hit);
+            }
+            found |= hit;
+        }
+        let value = core::mem::replace(&mut *acc, [0u8; N]);
+        subtle::CtOption::new(crate::InnerSecret::new(value), found)
+    }
+}
+
+/// Prints a marker under [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT)
+/// — `[REDACTED]`, or `[REDACTED; N bytes]` with the `redact-length` feature.
+/// Secrets never appear in debug output.
+///
+/// The length is `size_of::<T>()`, which is what
+/// [`byte_len`](RevealSecret::byte_len) reports for every revealable `Fixed<T>`.
+///
+/// ```rust
+/// use secure_gate::{Fixed, RedactionPolicy};
 ///
 /// let key = Fixed::new([0xABu8; 32]);
-/// assert_eq!(format!("{:?}", key), "[REDACTED]");
+/// let expected = RedactionPolicy::DEFAULT.redact(32).to_string();
+/// assert_eq!(format!("{:?}", key), expected);
 /// ```
 impl<T: zeroize::Zeroize> core::fmt::Debug for Fixed<T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("[REDACTED]")
+        let marker = crate::RedactionPolicy::DEFAULT.redact(core::mem::size_of::<T>());
+        core::fmt::Display::fmt(&marker, f)
     }
 }
 
@@ -0,0 +1,287 @@
+//! Capacity-bounded secret buffers that can never reallocate.
+//!
+//! [`FixedCapacity<T>`] wraps a [`Dynamic<Vec<u8>>`](crate::Dynamic) or
+//! [`Dynamic<String>`](crate::Dynamic) whose capacity is fixed at construction.
+//! It is built with `Dynamic::<Vec<u8>>::with_fixed_capacity(n)` /
+//! `Dynamic::<String>::with_fixed_capacity(n)` (or [`FixedCapacity::new`]).
+//!
+//! Secret bytes written to a growable buffer get copied whenever it reallocates,
+//! and the old buffer is freed unzeroed. Pre-sizing a `Dynamic<Vec<u8>>` avoids
+//! that only as long as every later write stays within capacity, which reviewers
+//! have to check by hand. `FixedCapacity` makes the compiler check it:
+//!
+//! - **Only non-growing writes** — `try_extend_from_slice` / `try_push`
+//!   (`Vec<u8>`) and `try_push_str` / `try_push` (`String`). A write that does not
+//!   fit returns [`CapacityError`](crate::CapacityError) and leaves the buffer
+//!   unchanged.
+//! - **No growing access** — no `secure_*` mutators, no `impl Write`, no
+//!   [`RevealSecretMut`](crate::RevealSecretMut) (which would hand out a
+//!   `&mut Vec`). `std::io::Write` goes through [`DynamicWriter`] (`std`), which
+//!   refuses to grow.
+//! - **Read access is unchanged** — [`RevealSecret`] is forwarded to the wrapped
+//!   `Dynamic`.
+//! - **Zeroize on drop** — delegated to the wrapped `Dynamic`, spare capacity
+//!   included.
+//!
+//! [`into_dynamic`](FixedCapacity::into_dynamic) returns the plain, growable
+//! `Dynamic` once the secret is complete.
+//!
+//! # Example
+//!
+//! ```rust
+//! use secure_gate::{CapacityError, Dynamic, RevealSecret};
+//!
+//! let mut key = Dynamic::<Vec<u8>>::with_fixed_capacity(4);
+//! key.try_extend_from_slice(&[1, 2, 3]).unwrap();
+//! assert!(matches!(
+//!     key.try_extend_from_slice(&[4, 5]),
+//!     Err(CapacityError::CapacityExceeded { capacity: 4, required: 5, .. })
+//! ));
+//! assert_eq!(key.len(), 3);
+//! ```
+//!
+//! Growing writes do not compile:
+//!
+//! ```compile_fail
+//! use secure_gate::Dynamic;
+//!
+//! let mut key = Dynamic::<Vec<u8>>::with_fixed_capacity(4);
+//! key.secure_push(1);
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{CapacityError, Dynamic, RevealSecret};
+
+/// Secret buffer whose capacity is fixed at construction: every write either
+/// fits or fails, and none reallocates.
+///
+/// Implemented for `FixedCapacity<Vec<u8>>` and `FixedCapacity<String>`. See the
+/// [module docs](crate::FixedCapacity) for what is and is not exposed.
+/// Zeroization is delegated to the wrapped `Dynamic`.
+pub struct FixedCapacity<T: zeroize::Zeroize> {
+    inner: Dynamic<T>,
+}
+
+impl<T: zeroize::Zeroize> FixedCapacity<T> {
+    /// Unwraps into the plain, growable [`Dynamic<T>`](crate::Dynamic).
+    ///
+    /// The returned value has the usual `Dynamic` API again, including the
+    /// zeroize-on-grow `secure_*` mutators.
+    #[inline(always)]
+    pub fn into_dynamic(self) -> Dynamic<T> {
+        self.inner
+    }
+}
+
+impl FixedCapacity<Vec<u8>> {
+    /// Creates an empty buffer with a fixed capacity of `capacity` bytes.
+    ///
+    /// The allocation happens here, before any secret bytes exist. Same as
+    /// [`Dynamic::<Vec<u8>>::with_fixed_capacity`](crate::Dynamic::with_fixed_capacity).
+    #[inline]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Dynamic::new(Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Total capacity in bytes. Never changes.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Number of bytes that can still be written without exceeding capacity.
+    #[inline(always)]
+    pub fn remaining_capacity(&self) -> usize {
+        self.inner.remaining_capacity()
+    }
+
+    /// Appends `bytes` if they fit in the remaining capacity; never reallocates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError::CapacityExceeded`] without modifying the buffer
+    /// if `bytes` does not fit.
+    #[inline]
+    pub fn try_extend_from_slice(&mut self, bytes: &[u8]) -> Result<(), CapacityError> {
+        self.inner.try_extend_from_slice(bytes)
+    }
+
+    /// Appends a single byte if it fits in the remaining capacity; never reallocates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError::CapacityExceeded`] if the buffer is full.
+    #[inline]
+    pub fn try_push(&mut self, byte: u8) -> Result<(), CapacityError> {
+        self.inner.try_extend_from_slice(&[byte])
+    }
+}
+
+impl FixedCapacity<String> {
+    /// Creates an empty string with a fixed capacity of `capacity` bytes.
+    ///
+    /// Same as [`Dynamic::<String>::with_fixed_capacity`](crate::Dynamic::with_fixed_capacity).
+    ///
+    /// ```rust
+    /// use secure_gate::{FixedCapacity, RevealSecret};
+    ///
+    /// let mut pw = FixedCapacity::<String>::new(8);
+    /// pw.try_push_str("hunter2").unwrap();
+    /// assert!(pw.try_push_str("!!").is_err());
+    /// assert_eq!(pw.expose_secret(), "hunter2");
+    /// ```
+    #[inline]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Dynamic::new(String::with_capacity(capacity)),
+        }
+    }
+
+    /// Total capacity in bytes. Never changes.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Number of bytes that can still be written without exceeding capacity.
+    #[inline(always)]
+    pub fn remaining_capacity(&self) -> usize {
+        self.inner.remaining_capacity()
+    }
+
+    /// Appends `s` if it fits in the remaining capacity; never reallocates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError::CapacityExceeded`] without modifying the string
+    /// if `s` does not fit.
+    #[inline]
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        self.inner.try_push_str(s)
+    }
+
+    /// Appends a single `char` if its UTF-8 encoding fits; never reallocates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError::CapacityExceeded`] if the encoded `char` does not
+    /// fit.
+    #[inline]
+    pub fn try_push(&mut self, ch: char) -> Result<(), CapacityError> {
+        self.inner.try_push_str(ch.encode_utf8(&mut [0u8; 4]))
+    }
+}
+
+/// Read-only access, forwarded to the wrapped `Dynamic`.
+impl<T: zeroize::Zeroize> RevealSecret for FixedCapacity<T>
+where
+    Dynamic<T>: RevealSecret<Inner = T>,
+{
+    type Inner = T;
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.inner.with_secret(f)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &T {
+        self.inner.expose_secret()
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        self.inner.byte_len()
+    }
+
+    /// Consumes the buffer and returns the inner value; same as the wrapped
+    /// `Dynamic`'s `into_inner`.
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn into_inner(self) -> crate::InnerSecret<T>
+    where
+        Self: Sized,
+        Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        self.inner.into_inner()
+    }
+}
+
+/// Same output as the wrapped `Dynamic`'s `Debug`.
+impl<T: zeroize::Zeroize> core::fmt::Debug for FixedCapacity<T>
+where
+    Dynamic<T>: RevealSecret,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<T: zeroize::Zeroize> zeroize::ZeroizeOnDrop for FixedCapacity<T> {}
+
+/// Capacity-bounded writer over a [`FixedCapacity<Vec<u8>>`].
+///
+/// Created by [`FixedCapacity::<Vec<u8>>::as_writer`]. Borrows the buffer
+/// mutably and never reallocates. Each [`Write::write`](std::io::Write::write)
+/// either appends the whole input or fails with
+/// [`ErrorKind::StorageFull`](std::io::ErrorKind::StorageFull) (carrying a
+/// [`CapacityError`]) and writes nothing, so a secret is never silently
+/// truncated. The `std::io::Write` sibling of [`DynamicReader`](crate::DynamicReader).
+#[cfg(feature = "std")]
+pub struct DynamicWriter<'a> {
+    secret: &'a mut FixedCapacity<Vec<u8>>,
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for DynamicWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.secret
+            .try_extend_from_slice(buf)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::StorageFull, e))?;
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl FixedCapacity<Vec<u8>> {
+    /// Returns a [`DynamicWriter`] that implements [`std::io::Write`] without ever
+    /// growing the buffer.
+    ///
+    /// Unlike `impl Write for Dynamic<Vec<u8>>` (which grows with zeroize-on-grow),
+    /// writes past the capacity fail instead of allocating.
+    ///
+    /// ```rust
+    /// use std::io::{self, Write};
+    /// use secure_gate::{Dynamic, RevealSecret};
+    ///
+    /// let mut key = Dynamic::<Vec<u8>>::with_fixed_capacity(32);
+    /// io::copy(&mut &[0x42u8; 32][..], &mut key.as_writer()).unwrap();
+    /// assert_eq!(key.len(), 32);
+    ///
+    /// let err = key.as_writer().write_all(b"x").unwrap_err();
+    /// assert_eq!(err.kind(), io::ErrorKind::StorageFull);
+    /// ```
+    #[inline]
+    pub fn as_writer(&mut self) -> DynamicWriter<'_> {
+        DynamicWriter { secret: self }
+    }
+}
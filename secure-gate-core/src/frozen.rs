@@ -0,0 +1,142 @@
+//! Read-only wrapper that forbids in-place mutation.
+//!
+//! [`Frozen<S>`] wraps a secret wrapper `S` (a [`Fixed<T>`](crate::Fixed) or
+//! [`Dynamic<T>`](crate::Dynamic)) and forwards [`RevealSecret`] only. It never
+//! implements [`RevealSecretMut`](crate::RevealSecretMut), so code that receives a
+//! `Frozen` root key — or a `&Frozen` — cannot edit it in place. The guarantee is
+//! checked at compile time.
+//!
+//! # Security invariants
+//!
+//! - **No mutable access** — no `with_secret_mut`, no `expose_secret_mut`, no
+//!   `slice_mut`, no `DerefMut`. The wrapped `S` is private.
+//! - **Zeroize on drop** — delegated to `S`, whose own `Drop` impl runs unchanged.
+//! - **`Debug` is redacted** — the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT)
+//!   marker, with the secret's length under `redact-length`.
+//! - **Ownership still works** — [`into_inner`](RevealSecret::into_inner) consumes
+//!   the `Frozen` exactly as it would the wrapped `S`. Freezing restricts *shared*
+//!   holders; whoever owns the value can still take it apart, and that call is
+//!   grep-able like any other `into_inner`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use secure_gate::{Fixed, Frozen, RedactionPolicy, RevealSecret};
+//!
+//! let root: Frozen<Fixed<[u8; 32]>> = Fixed::new([0x42u8; 32]).freeze();
+//! assert_eq!(root.with_secret(|k| k[0]), 0x42);
+//! assert_eq!(format!("{:?}", root), RedactionPolicy::DEFAULT.redact(32).to_string());
+//! ```
+//!
+//! Mutation does not compile:
+//!
+//! ```compile_fail
+//! use secure_gate::{Fixed, RevealSecretMut};
+//!
+//! let mut root = Fixed::new([0x42u8; 32]).freeze();
+//! root.with_secret_mut(|k| k[0] = 0);
+//! ```
+
+use crate::RevealSecret;
+
+/// Read-only secret wrapper: implements [`RevealSecret`] but never
+/// [`RevealSecretMut`](crate::RevealSecretMut).
+///
+/// Built with `Fixed::freeze`, `Dynamic::freeze`, or `Frozen::from(wrapper)`.
+/// Zeroization is delegated to the wrapped `S`.
+pub struct Frozen<S: RevealSecret> {
+    inner: S,
+}
+
+impl<S: RevealSecret> Frozen<S> {
+    /// Freezes `secret`, removing all mutable access.
+    #[inline(always)]
+    pub fn new(secret: S) -> Self {
+        Self { inner: secret }
+    }
+}
+
+impl<S: RevealSecret> From<S> for Frozen<S> {
+    #[inline(always)]
+    fn from(secret: S) -> Self {
+        Self::new(secret)
+    }
+}
+
+/// Read-only access, forwarded to the wrapped secret.
+impl<S: RevealSecret> RevealSecret for Frozen<S> {
+    type Inner = S::Inner;
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&S::Inner) -> R,
+    {
+        self.inner.with_secret(f)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &S::Inner {
+        self.inner.expose_secret()
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        self.inner.byte_len()
+    }
+
+    /// Consumes the `Frozen` and returns the inner value of the wrapped secret.
+    ///
+    /// Same cost and guarantees as the wrapped type's `into_inner`.
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn into_inner(self) -> crate::InnerSecret<S::Inner>
+    where
+        Self: Sized,
+        Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        self.inner.into_inner()
+    }
+}
+
+/// Constant-time equality, forwarded to the wrapped secret.
+#[cfg(feature = "ct-eq")]
+impl<S: RevealSecret + crate::ConstantTimeEq> crate::ConstantTimeEq for Frozen<S> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        self.inner.ct_eq(&other.inner)
+    }
+
+    #[inline]
+    fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+        self.inner.ct_eq_choice(&other.inner)
+    }
+}
+
+/// Opt-in cloning, available when the wrapped secret is itself `Clone`
+/// (requires the `cloneable` feature and a [`CloneableSecret`](crate::CloneableSecret) inner type).
+#[cfg(feature = "cloneable")]
+impl<S: RevealSecret + Clone> Clone for Frozen<S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.inner.clone())
+    }
+}
+
+/// Prints the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT) marker
+/// for the wrapped secret's [`byte_len`](RevealSecret::byte_len).
+impl<S: RevealSecret> core::fmt::Debug for Frozen<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let marker = crate::RedactionPolicy::DEFAULT.redact(self.inner.byte_len());
+        core::fmt::Display::fmt(&marker, f)
+    }
+}
+
+impl<S: RevealSecret + zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Frozen<S> {}
@@ -40,8 +40,8 @@
 //! // Tier 2 — direct reference (escape hatch for FFI / third-party APIs)
 //! assert_eq!(key.expose_secret().len(), 32);
 //!
-//! // Debug is always redacted
-//! assert_eq!(format!("{:?}", key), "[REDACTED]");
+//! // Debug is always redacted: `[REDACTED]` (plus a length with `redact-length`)
+//! assert!(format!("{:?}", key).starts_with("[REDACTED"));
 //! // key is zeroized when dropped
 //! ```
 //!
@@ -71,19 +71,33 @@
 //! secure_gate (lib.rs)
 //! ├── Fixed<T>              ← always available, stack-allocated
 //! ├── Dynamic<T>            ← requires `alloc`, heap-allocated
+//! ├── FixedCapacity<T>      ← never-reallocating Vec<u8> / String buffer (alloc)
+//! ├── SecretRef / SecretMut ← borrowed sub-secret views (`slice` / `slice_mut`)
+//! ├── Frozen<S>             ← read-only wrapper (`freeze`), no RevealSecretMut
+//! ├── Budgeted<S>           ← reveal-budget wrapper, self-zeroizes when spent
+//! ├── Expiring<S, C>        ← TTL wrapper, self-zeroizes after deadline (std)
+//! ├── Shielded<T>           ← encrypted at rest in memory (shielded)
+//! ├── Fingerprint           ← keyed loggable identifier + SecretKeyedMap (fingerprint)
+//! ├── RedactionPolicy       ← labels / length hints for redacted markers (Redacted)
+//! ├── SecretRecord          ← structured redacted tracing / log value (tracing)
 //! ├── traits/
 //! │   ├── RevealSecret      ← immutable access (always available)
 //! │   ├── RevealSecretMut   ← mutable access (always available)
+//! │   ├── TryRevealSecret   ← fallible access for policy wrappers
+//! │   ├── OpaqueSecret      ← opt-in reveal for composite inner types
 //! │   ├── revealed_secrets/
 //! │   │   ├── InnerSecret<T>    ← owned extraction wrapper
 //! │   │   └── EncodedSecret     ← zeroizing encoded string wrapper (alloc)
-//! │   ├── ConstantTimeEq    ← ct-eq feature
+//! │   ├── ConstantTimeEq    ← ct-eq feature (+ SubtleConstantTimeEq, ct_eq_all, ct_eq_padded, Choice / CtOption)
+//! │   ├── ConstantTimeOrd   ← ct-eq feature (ct_lt / ct_gt / ct_cmp, ByteOrder)
 //! │   ├── CloneableSecret   ← cloneable feature
 //! │   ├── SerializableSecret← serde-serialize feature
 //! │   ├── encoding/         ← ToHex, ToBase64Url, ToBech32, ToBech32m
 //! │   └── decoding/         ← FromHexStr, FromBase64UrlStr, FromBech32Str, FromBech32mStr
+//! ├── SecretStruct          ← #[derive] for composite secret structs (derive feature)
+//! ├── audit                 ← AuditEvent, set_audit_hook (audit feature)
 //! ├── macros/               ← fixed_alias!, dynamic_alias!, etc.
-//! └── error                 ← FromSliceError, HexError, Base64Error, Bech32Error, DecodingError
+//! └── error                 ← FromSliceError, HexError, Base64Error, Bech32Error, DecodingError, RevealError, CapacityError, AllocError
 //! ```
 //!
 //! All public items are re-exported at the crate root. Use `secure_gate::Fixed`,
@@ -93,11 +107,19 @@
 //!
 //! | Category | Types | `Deref` to secret? | Purpose |
 //! |----------|-------|-------------------|---------|
-//! | **Secret wrappers** | [`Fixed<T>`], [`Dynamic<T>`] | No — use [`RevealSecret`] | Hold live secrets; `Debug` → `[REDACTED]` |
+//! | **Secret wrappers** | [`Fixed<T>`], [`Dynamic<T>`] | No — use [`RevealSecret`] | Hold live secrets; `Debug` → [`RedactionPolicy::DEFAULT`] marker |
+//! | **Read-only wrapper** | [`Frozen<S>`] | No — use [`RevealSecret`] | Forbids in-place mutation; `Debug` → policy marker |
+//! | **Bounded buffer** | `FixedCapacity<T>` (alloc) | No — use [`RevealSecret`] | Only non-growing `try_*` writes; never reallocates |
+//! | **Policy wrappers** | [`Budgeted<S>`], `Expiring<S, C>` (std) | No — use [`TryRevealSecret`] | Limited reveals or TTL, then self-zeroize; `Debug` → policy marker |
+//! | **Encrypted wrapper** | `Shielded<T>` (shielded) | No — use [`RevealSecret`] | Ciphertext at rest, plaintext only inside closures; `Debug` → policy marker |
+//! | **Borrowed views** | [`SecretRef`], [`SecretMut`] | No — use [`RevealSecret`] | Sub-range of a live secret; `Debug` → policy marker |
+//! | **Redaction markers** | [`RedactionPolicy`], [`Redacted`], `SecretRecord` (tracing) | — (not secret) | Labeled / length-hinted `[REDACTED ...]` text for logs |
+//! | **Identifiers** | `Fingerprint`, `SecretKeyedMap<V>` (fingerprint) | — (not secret) | Keyed hash of a secret for logs, dedup and map lookups |
 //! | **Output wrappers** | [`InnerSecret<T>`], [`EncodedSecret`] | Yes — caller owns the data | Hold extracted or encoded results |
-//! | **Opt-in markers** | [`CloneableSecret`], [`SerializableSecret`] | — (no methods) | Implement on inner type `T` to unlock gated impls |
+//! | **Opt-in markers** | [`OpaqueSecret`], [`CloneableSecret`], [`SerializableSecret`] | — (no methods) | Implement on inner type `T` to unlock gated impls |
+//! | **Derive** | `#[derive(SecretStruct)]` (derive) | — | Generates redacted `Debug`, zeroize-on-drop and the markers for composite `T` |
 //!
-//! `CloneableSecret` and `SerializableSecret` are implemented on the **inner type `T`**,
+//! `OpaqueSecret`, `CloneableSecret` and `SerializableSecret` are implemented on the **inner type `T`**,
 //! not on `Fixed<T>` or `Dynamic<T>` directly. Output wrappers ([`InnerSecret`],
 //! [`EncodedSecret`]) are not secret wrappers and do not interact with these markers.
 //!
@@ -130,10 +152,12 @@
 //! | Feature | Default | Description |
 //! |---------|---------|-------------|
 //! | `alloc` | **yes** | Heap types ([`Dynamic<T>`]), `Vec`/`String` zeroization |
-//! | `std` | no | Full `std` support (implies `alloc`) |
+//! | `std` | no | Full `std` support (implies `alloc`); `Expiring`, `DynamicReader` / `DynamicWriter` |
 //! | | | **Cryptographic** |
-//! | `ct-eq` | no | [`ConstantTimeEq`] via `subtle` — timing-safe comparison |
+//! | `ct-eq` | no | [`ConstantTimeEq`] via `subtle` — timing-safe comparison, branch-free `ct_select` / `conditional_assign` |
 //! | `rand` | no | `from_random()` / `from_rng()` — `no_std` for [`Fixed`] |
+//! | `shielded` | no | `Shielded<T>` in-memory encryption (ChaCha20 + BLAKE2b; implies `alloc`, `rand`) |
+//! | `fingerprint` | no | Keyed BLAKE2s `Fingerprint` (`Hash + Eq + Display`) and `SecretKeyedMap` (implies `alloc`, `rand`) |
 //! | | | **Serialization** |
 //! | `serde-serialize` | no | Serde `Serialize` (requires [`SerializableSecret`] marker) |
 //! | `serde-deserialize` | no | Serde `Deserialize` with 1 MiB default limit |
@@ -146,6 +170,10 @@
 //! | `encoding` | no | All encoding features |
 //! | | | **Meta** |
 //! | `cloneable` | no | [`CloneableSecret`] opt-in cloning |
+//! | `derive` | no | `#[derive(SecretStruct)]` for composite secret structs |
+//! | `tracing` | no | `trace_record()` → `SecretRecord` (type, length, optional fingerprint) with `Display` and `valuable::Valuable` |
+//! | `redact-length` | no | [`RedactionPolicy::DEFAULT`] — and so wrapper `Debug` wherever the length is known (not plain `Dynamic` / `InnerSecret`) — includes a `; N bytes` length hint |
+//! | `audit` | no | Runtime hook for every reveal: tier, caller location, wrapper type (implies `std`) |
 //! | `full` | no | Everything |
 //!
 //! # What's available without `alloc`?
@@ -196,7 +224,8 @@
 //!   [`Fixed::new_with`](Fixed::new_with), pass by reference, or use
 //!   [`Dynamic<T>`] for long-lived secrets.
 //! - **Heap-reallocation residue**: `Vec` / `String` realloc frees the old buffer
-//!   unzeroed. Pre-size, use `Dynamic<[u8; N]>` (boxed array — no realloc surface),
+//!   unzeroed. Pre-size, use `Dynamic<[u8; N]>` or `Dynamic<[u8]>` (boxed array /
+//!   slice — no realloc surface),
 //!   or install a zero-on-dealloc global allocator such as `zeroizing-alloc` at
 //!   the binary level.
 //! - **Swap / core dumps**: process memory paged to disk or written on crash is
@@ -212,9 +241,26 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+/// Reports a secret access to the `audit` hook from inside a `RevealSecret` /
+/// `RevealSecretMut` method. Expands to nothing without the `audit` feature.
+macro_rules! audit_access {
+    ($tier:ident, $mutable:expr) => {
+        #[cfg(feature = "audit")]
+        $crate::audit::record::<Self>($crate::AccessTier::$tier, $mutable);
+    };
+}
+
+/// Runtime access auditing - requires `audit`.
+#[cfg(feature = "audit")]
+mod audit;
+
 #[cfg(feature = "alloc")]
 mod dynamic;
 
+/// Capacity-bounded buffers - requires `alloc`.
+#[cfg(feature = "alloc")]
+mod fixed_capacity;
+
 /// Fixed-size secret wrapper types - always available with zero dependencies.
 /// These provide fundamental secure storage abstractions for fixed-size data.
 mod fixed;
@@ -222,6 +268,34 @@ mod fixed;
 /// Centralized error types - always available.
 mod error;
 
+/// Borrowed sub-secret views - always available.
+mod secret_ref;
+
+/// Read-only wrapper - always available.
+mod frozen;
+
+/// Reveal-budget wrapper - always available.
+mod budgeted;
+
+/// Time-limited wrapper - requires `std`.
+#[cfg(feature = "std")]
+mod expiring;
+
+/// In-memory encrypted wrapper - requires `shielded`.
+#[cfg(feature = "shielded")]
+mod shielded;
+
+/// Keyed secret fingerprints - requires `fingerprint`.
+#[cfg(feature = "fingerprint")]
+mod fingerprint;
+
+/// Redaction policies for log output - always available.
+mod redaction;
+
+/// Structured redacted records for tracing / log fields - requires `tracing`.
+#[cfg(feature = "tracing")]
+mod trace;
+
 /// Core traits for wrapper polymorphism - always available.
 pub mod traits;
 
@@ -239,7 +313,7 @@ pub mod traits;
 ///
 /// let pw: Dynamic<String> = Dynamic::new(String::from("hunter2"));
 /// assert_eq!(pw.with_secret(|s: &String| s.len()), 7);
-/// assert_eq!(format!("{:?}", pw), "[REDACTED]");
+/// assert!(format!("{:?}", pw).starts_with("[REDACTED"));
 /// # }
 /// ```
 #[cfg(feature = "alloc")]
@@ -249,8 +323,32 @@ pub use dynamic::Dynamic;
 #[cfg(feature = "std")]
 pub use dynamic::DynamicReader;
 
+/// Secret buffer with a fixed capacity that can never reallocate: only
+/// non-growing `try_*` writes are available. Built with
+/// [`Dynamic::with_fixed_capacity`]. Requires `alloc`.
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// use secure_gate::{Dynamic, FixedCapacity, RevealSecret};
+///
+/// let mut pin: FixedCapacity<String> = Dynamic::<String>::with_fixed_capacity(6);
+/// pin.try_push_str("123456").unwrap();
+/// assert!(pin.try_push('7').is_err());
+/// assert_eq!(pin.len(), 6);
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub use fixed_capacity::FixedCapacity;
+
+/// Capacity-bounded writer over [`FixedCapacity<Vec<u8>>`] that never
+/// reallocates — see [`FixedCapacity::as_writer`].
+#[cfg(feature = "std")]
+pub use fixed_capacity::DynamicWriter;
+
 #[cfg(all(feature = "alloc", feature = "serde-deserialize"))]
-/// Default maximum byte length for `Dynamic<Vec<u8>>` / `Dynamic<String>` deserialization (1 MiB).
+/// Default maximum byte length for `Dynamic<Vec<u8>>` / `Dynamic<String>` / `Dynamic<[u8]>` /
+/// `Dynamic<str>` deserialization (1 MiB).
 ///
 /// The standard `serde::Deserialize` impl for both types rejects payloads exceeding this value.
 /// Pass a custom ceiling to [`Dynamic::deserialize_with_limit`] when a different limit is needed.
@@ -274,10 +372,198 @@ pub use dynamic::MAX_DESERIALIZE_BYTES;
 ///
 /// let key = Fixed::new([0xABu8; 32]);
 /// key.with_secret(|b| assert_eq!(b[0], 0xAB));
-/// assert_eq!(format!("{:?}", key), "[REDACTED]");
+/// assert!(format!("{:?}", key).starts_with("[REDACTED"));
 /// ```
 pub use fixed::Fixed;
 
+/// Read-only borrowed view into part of a secret, returned by `Fixed::slice` /
+/// `Dynamic::slice`. Implements [`RevealSecret`]; `Debug` prints the redaction marker. Lets a
+/// sub-range of a key be passed on without handing over a raw `&[u8]`.
+///
+/// ```rust
+/// use secure_gate::{Fixed, RevealSecret};
+///
+/// let schedule = Fixed::new([7u8; 48]);
+/// let mac = schedule.slice(16..32);
+/// assert_eq!(mac.len(), 16);
+/// assert!(format!("{:?}", mac).starts_with("[REDACTED"));
+/// ```
+pub use secret_ref::SecretRef;
+
+/// Mutable borrowed view into part of a secret, returned by `Fixed::slice_mut` /
+/// `Dynamic::slice_mut`. Implements [`RevealSecret`] and [`RevealSecretMut`];
+/// `Debug` prints the redaction marker.
+pub use secret_ref::SecretMut;
+
+/// Read-only secret wrapper: implements [`RevealSecret`] but never [`RevealSecretMut`].
+///
+/// Built with `Fixed::freeze` / `Dynamic::freeze` or `Frozen::from(wrapper)`. Keeps
+/// the wrapped secret's zeroize-on-drop and the redacted `Debug`, and gives a
+/// compile-time guarantee that holders cannot edit the secret in place.
+///
+/// ```rust
+/// use secure_gate::{Fixed, Frozen, RevealSecret};
+///
+/// let root: Frozen<Fixed<[u8; 32]>> = Fixed::new([1u8; 32]).freeze();
+/// root.with_secret(|k| assert_eq!(k[0], 1));
+/// assert!(format!("{:?}", root).starts_with("[REDACTED"));
+/// ```
+pub use frozen::Frozen;
+
+/// Secret wrapper that allows a limited number of reveals, then zeroizes itself.
+///
+/// Access goes through [`TryRevealSecret::try_with_secret`]; once the budget is spent
+/// every reveal returns [`RevealError::BudgetExhausted`]. For bootstrap tokens and
+/// enrolment codes. Works in `no_std`.
+///
+/// ```rust
+/// use secure_gate::{Budgeted, Fixed, RevealError, TryRevealSecret};
+///
+/// let mut code = Budgeted::one_shot(Fixed::new([7u8; 8]));
+/// assert_eq!(code.try_with_secret(|c| c[0]), Ok(7));
+/// assert_eq!(code.try_with_secret(|c| c[0]), Err(RevealError::BudgetExhausted));
+/// ```
+pub use budgeted::Budgeted;
+
+/// Secret wrapper that zeroizes itself once revealed after its deadline.
+///
+/// Access goes through [`TryRevealSecret::try_with_secret`], which returns
+/// [`RevealError::Expired`] after the TTL; [`Expiring::purge_if_expired`] wipes
+/// expired secrets proactively. The [`Clock`] is pluggable for tests. Requires `std`.
+///
+/// ```rust
+/// # #[cfg(feature = "std")]
+/// # {
+/// use std::time::Duration;
+/// use secure_gate::{Expiring, Fixed, TryRevealSecret};
+///
+/// let mut session = Expiring::new(Fixed::new([3u8; 32]), Duration::from_secs(300));
+/// assert_eq!(session.try_with_secret(|k| k[0]), Ok(3));
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub use expiring::Expiring;
+
+/// Time source for [`Expiring`]. Implement it on a fake clock in tests.
+#[cfg(feature = "std")]
+pub use expiring::Clock;
+
+/// Default [`Clock`] backed by [`std::time::Instant::now`].
+#[cfg(feature = "std")]
+pub use expiring::SystemClock;
+
+/// Secret kept encrypted in memory under a key derived from a 16 KiB random
+/// prekey (OpenSSH-style shielding); decrypted only inside `with_secret`, and
+/// re-encrypted under a fresh salt on every access. Requires `shielded`.
+///
+/// ```rust
+/// # #[cfg(feature = "shielded")]
+/// # {
+/// use secure_gate::{Dynamic, RevealSecret, Shielded};
+///
+/// let pw = Shielded::new(Dynamic::<String>::new(String::from("hunter2")));
+/// assert_eq!(pw.with_secret(|s| s.len()), 7);
+/// # }
+/// ```
+#[cfg(feature = "shielded")]
+pub use shielded::Shielded;
+
+/// 128-bit keyed identifier of a secret — `Hash + Eq + Ord + Display`, safe to log.
+/// Produced by `Fixed::fingerprint` / `Dynamic::fingerprint`. Requires `fingerprint`.
+///
+/// ```rust
+/// # #[cfg(feature = "fingerprint")]
+/// # {
+/// use secure_gate::{Dynamic, FingerprintKey};
+///
+/// let fp_key = FingerprintKey::random();
+/// let api_key = Dynamic::<String>::new(String::from("sk_live_123"));
+/// let fp = api_key.fingerprint(&fp_key);
+/// assert_eq!(format!("{fp}").len(), 32);
+/// # }
+/// ```
+#[cfg(feature = "fingerprint")]
+pub use fingerprint::Fingerprint;
+
+/// Secret key for [`Fingerprint`]s: `random()`, `new(bytes)`, or the shared
+/// `process()` key (std). Zeroized on drop. Requires `fingerprint`.
+#[cfg(feature = "fingerprint")]
+pub use fingerprint::FingerprintKey;
+
+/// Map keyed by secrets that stores only their [`Fingerprint`]s. Requires `fingerprint`.
+#[cfg(feature = "fingerprint")]
+pub use fingerprint::SecretKeyedMap;
+
+/// What a redacted marker may print about a secret: an optional label
+/// (`[REDACTED api_key]`) and an optional length hint (`[REDACTED; 32 bytes]`).
+/// `RedactionPolicy::DEFAULT` drives the wrappers' plain `Debug` and adds the
+/// length hint under `redact-length`.
+///
+/// ```rust
+/// use secure_gate::{Fixed, RedactionPolicy};
+///
+/// let key = Fixed::new([0u8; 32]);
+/// let policy = RedactionPolicy::BARE.label("api_key").length_hint(true);
+/// assert_eq!(key.redacted(policy).to_string(), "[REDACTED api_key; 32 bytes]");
+/// ```
+pub use redaction::RedactionPolicy;
+
+/// Printable redacted marker returned by `redacted(policy)` on [`Fixed`],
+/// [`Dynamic`], [`InnerSecret`] and [`EncodedSecret`]. Holds no secret data.
+pub use redaction::Redacted;
+
+/// Redacted record of a secret — type name, byte length and an optional
+/// fingerprint — for `tracing` / `log` fields. `Display` for the `%` sigil,
+/// `valuable::Valuable` for structured consumers. Requires `tracing`.
+///
+/// ```rust
+/// # #[cfg(feature = "tracing")]
+/// # {
+/// use secure_gate::Dynamic;
+///
+/// let token = Dynamic::<String>::new(String::from("tok_3f9a"));
+/// let record = token.trace_record();
+/// assert_eq!(record.byte_len(), 8);
+/// assert!(!record.to_string().contains("tok_3f9a"));
+/// # }
+/// ```
+#[cfg(feature = "tracing")]
+pub use trace::SecretRecord;
+
+/// Access tier reported in an [`AuditEvent`]: `Scoped`, `Direct` or `Owned`.
+/// Requires `audit`.
+#[cfg(feature = "audit")]
+pub use audit::AccessTier;
+
+/// One audited `RevealSecret` / `RevealSecretMut` call: tier, mutability, caller
+/// location and wrapper type name — never the secret. Requires `audit`.
+#[cfg(feature = "audit")]
+pub use audit::AuditEvent;
+
+/// Installs the process-wide audit hook called on every secret access.
+/// Requires `audit`.
+///
+/// ```rust
+/// # #[cfg(feature = "audit")]
+/// # {
+/// use secure_gate::{AuditEvent, Fixed, RevealSecret};
+///
+/// fn log_access(event: &AuditEvent) {
+///     eprintln!("{:?} access to {} at {}", event.tier, event.type_name, event.location);
+/// }
+///
+/// secure_gate::set_audit_hook(log_access);
+/// Fixed::new([0u8; 16]).with_secret(|_| ());
+/// secure_gate::clear_audit_hook();
+/// # }
+/// ```
+#[cfg(feature = "audit")]
+pub use audit::set_audit_hook;
+
+/// Removes the audit hook installed by [`set_audit_hook`]. Requires `audit`.
+#[cfg(feature = "audit")]
+pub use audit::clear_audit_hook;
+
 /// Marker trait that opts a secret type into cloning. No methods — gates the `Clone`
 /// impl on [`Fixed`] and [`Dynamic`]. Each clone is independently zeroized on drop,
 /// but increases the in-memory exposure surface. Requires `cloneable` feature.
@@ -318,6 +604,59 @@ pub use traits::CloneableSecret;
 #[cfg(feature = "ct-eq")]
 pub use traits::ConstantTimeEq;
 
+/// Opt-in marker that gives any [`subtle::ConstantTimeEq`] type this crate's
+/// [`ConstantTimeEq`], so `Fixed` / `Dynamic` wrappers around it can be compared.
+/// Requires `ct-eq` feature.
+#[cfg(feature = "ct-eq")]
+pub use traits::SubtleConstantTimeEq;
+
+/// Folds several constant-time comparisons into one [`Choice`] without
+/// short-circuiting. Requires `ct-eq` feature.
+#[cfg(feature = "ct-eq")]
+pub use traits::ct_eq_all;
+
+/// Constant-time unsigned-integer ordering (`ct_lt`, `ct_gt`, `ct_cmp`) for
+/// byte-array secrets, big- or little-endian. Requires `ct-eq` feature.
+///
+/// ```rust
+/// # #[cfg(feature = "ct-eq")]
+/// # {
+/// use secure_gate::{ByteOrder, ConstantTimeOrd, Fixed};
+///
+/// let a = Fixed::new([0u8, 1]);
+/// let b = Fixed::new([0u8, 2]);
+/// assert!(bool::from(a.ct_lt(&b, ByteOrder::BigEndian)));
+/// # }
+/// ```
+#[cfg(feature = "ct-eq")]
+pub use traits::ConstantTimeOrd;
+
+/// Byte order for [`ConstantTimeOrd`]. Requires `ct-eq` feature.
+#[cfg(feature = "ct-eq")]
+pub use traits::ByteOrder;
+
+/// `Choice`-valued ordering returned by [`ConstantTimeOrd::ct_cmp`].
+/// Requires `ct-eq` feature.
+#[cfg(feature = "ct-eq")]
+pub use traits::CtOrdering;
+
+/// Length-hiding constant-time equality for variable-length byte strings — work
+/// depends only on `max_len`. Requires `ct-eq` feature.
+#[cfg(feature = "ct-eq")]
+pub use traits::ct_eq_padded;
+
+/// `subtle`'s constant-time boolean, returned by
+/// [`ct_eq_choice`](ConstantTimeEq::ct_eq_choice) and taken by
+/// [`Fixed::ct_select`], [`Fixed::conditional_assign`] and the matching
+/// `Dynamic<Vec<u8>>` methods. Requires `ct-eq` feature.
+#[cfg(feature = "ct-eq")]
+pub use subtle::Choice;
+
+/// `subtle`'s constant-time `Option`, returned by [`Fixed::ct_lookup`].
+/// Requires `ct-eq` feature.
+#[cfg(feature = "ct-eq")]
+pub use subtle::CtOption;
+
 /// Explicit immutable access to secret contents (3-tier access model).
 ///
 /// - **Tier 1** (preferred): [`with_secret()`](RevealSecret::with_secret) — scoped closure,
@@ -339,6 +678,10 @@ pub use traits::RevealSecret;
 /// Only [`Fixed`] and [`Dynamic`] implement this — read-only wrappers deliberately do not.
 pub use traits::RevealSecretMut;
 
+/// Fallible scoped access for policy-limited wrappers such as [`Budgeted`].
+/// `try_with_secret` returns [`RevealError`] once the secret is no longer readable.
+pub use traits::TryRevealSecret;
+
 /// Owned extraction **output wrapper** returned by [`RevealSecret::into_inner`] (Tier 3 access).
 ///
 /// Wraps [`Zeroizing<T>`](zeroize::Zeroizing) with `Debug` → `[REDACTED]`. Implements
@@ -356,13 +699,25 @@ pub use traits::InnerSecret;
 ///
 /// When `into_inner` moves the real secret out of a wrapper, it must leave *something*
 /// behind for the wrapper's `Drop` impl to zeroize. `SentinelValue::sentinel_value()`
-/// produces that inert placeholder (an all-default array, empty `String`, or empty
-/// `Vec`). Implemented for `[T; N]` (any `N`, `T: Default`), `String`, and `Vec<T>`.
+/// produces that inert placeholder (an all-default array, empty `String`, empty
+/// `Vec`, or zero integer). Implemented for `[T; N]` (any `N`, `T: Default`), `String`,
+/// `Vec<T>`, and the primitive integer types.
 ///
 /// Implement this for your own inner types to make `into_inner` available on wrappers
 /// around them. A sentinel must never contain secret material.
 pub use traits::SentinelValue;
 
+/// Marker trait that opts a scalar or composite inner type into [`RevealSecret`] /
+/// [`RevealSecretMut`] on [`Fixed`] and [`Dynamic`]. No methods.
+///
+/// The crate's container impls cover `String`, `Vec<T>`, arrays and slices; the
+/// primitive integers implement this marker out of the box (`Fixed<u64>`). Implement
+/// `OpaqueSecret` on a struct deriving `Zeroize` to read `Fixed<MyKeys>` /
+/// `Dynamic<MyKeys>` as one unit: `len()` is `1` and `byte_len()` is `size_of::<T>()`.
+/// Pair with [`SentinelValue`] for `into_inner`; cloning and serialization still need
+/// their own markers.
+pub use traits::OpaqueSecret;
+
 /// Encoded string **output wrapper** for zeroizing encoded output.
 ///
 /// This is an **output wrapper** — it exists *only* to keep encoded data zeroized until
@@ -500,3 +855,71 @@ pub use error::DecodingError;
 /// Error returned when a byte slice cannot be converted to `Fixed<[u8; N]>` due to
 /// length mismatch. Produced by `Fixed::try_from(&[u8])`.
 pub use error::FromSliceError;
+
+/// Error returned by [`TryRevealSecret::try_with_secret`] when a policy-limited
+/// secret ([`Budgeted`], `Expiring`) can no longer be revealed.
+pub use error::RevealError;
+
+/// Error returned when a capacity-bounded write on [`Dynamic`] would exceed the
+/// buffer's fixed capacity. Produced by the `try_*` mutators and [`DynamicWriter`].
+#[cfg(feature = "alloc")]
+pub use error::CapacityError;
+
+/// Error returned when a fallible [`Dynamic`] constructor (`try_new`,
+/// `try_new_with`, `try_from_slice`, `try_from_random`) cannot reserve its buffer.
+#[cfg(feature = "alloc")]
+pub use error::AllocError;
+
+/// Derives redacted `Debug`, `Zeroize`, zeroize-on-drop, [`SentinelValue`] and
+/// [`OpaqueSecret`] for a composite secret struct, plus opt-in
+/// [`ConstantTimeEq`] (`#[secret(ct_eq)]`), [`CloneableSecret`]
+/// (`#[secret(cloneable)]`) and [`SerializableSecret`] (`#[secret(serializable)]`).
+/// Mark public metadata fields with `#[secret(skip)]`; add `ct_eq` to also compare
+/// them. Requires `derive` feature.
+///
+/// ```rust
+/// # #[cfg(all(feature = "derive", feature = "ct-eq", feature = "alloc"))]
+/// # {
+/// use secure_gate::{ConstantTimeEq, Dynamic, RevealSecret, SecretStruct};
+///
+/// #[derive(SecretStruct)]
+/// #[secret(ct_eq)]
+/// struct SessionKeys {
+///     enc: [u8; 32],
+///     mac: [u8; 32],
+///     #[secret(skip, ct_eq)]
+///     key_id: [u8; 4],
+/// }
+///
+/// let keys = SessionKeys { enc: [1; 32], mac: [2; 32], key_id: *b"k001" };
+/// assert_eq!(
+///     format!("{keys:?}"),
+///     "SessionKeys { enc: [REDACTED], mac: [REDACTED], key_id: [107, 48, 48, 49] }"
+/// );
+///
+/// let a = Dynamic::new(keys);
+/// let b = Dynamic::new(SessionKeys { enc: [1; 32], mac: [2; 32], key_id: *b"k001" });
+/// assert!(a.ct_eq(&b));
+/// assert_eq!(a.with_secret(|k| k.enc[0]), 1);
+/// # }
+/// ```
+#[cfg(feature = "derive")]
+pub use secure_gate_derive::SecretStruct;
+
+/// Paths used by `#[derive(SecretStruct)]`, labeled-alias expansions and
+/// `secure-gate-mem`. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use zeroize;
+
+    /// Body of every never-callable `RevealSecret::into_inner` on an unsized inner
+    /// type (`[T]`, `str`, `[u8]`), whose `Self::Inner: Sized` bound never holds.
+    ///
+    /// Kept out of line: inside a body that assumes `str: Sized`, the promoted
+    /// panic message of an inline `unreachable!` trips a const-eval ICE on
+    /// rustc 1.85. All such impls call this one function so they cannot drift.
+    #[cold]
+    pub fn unsized_into_inner() -> ! {
+        unreachable!("`RevealSecret::into_inner` requires a sized inner type")
+    }
+}
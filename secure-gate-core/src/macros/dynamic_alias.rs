@@ -12,6 +12,8 @@
 /// dynamic_alias!(pub(crate) Name, T);         // crate-visible
 /// dynamic_alias!(Name, T);                    // private
 /// dynamic_alias!(pub Name, T, "doc string");  // with custom doc
+/// dynamic_alias!(pub Name, T, label = "name");              // labeled newtype
+/// dynamic_alias!(pub Name, T, "doc string", label = "name"); // labeled, custom doc
 /// ```
 ///
 /// # Examples
@@ -39,6 +41,24 @@
 /// assert_eq!(token.expose_secret(), &[1, 2, 3]);
 /// ```
 ///
+/// With a redaction label (see [`RedactionPolicy`](crate::RedactionPolicy)):
+///
+/// ```rust
+/// use secure_gate::{dynamic_alias, RedactionPolicy, RevealSecret};
+///
+/// dynamic_alias!(pub ApiKey, String, label = "api_key");
+///
+/// let key = ApiKey::new("sk_live_123");
+/// // `[REDACTED api_key]`, or `[REDACTED api_key; 11 bytes]` with `redact-length`.
+/// let expected = RedactionPolicy::DEFAULT.label("api_key").redact(11);
+/// assert_eq!(format!("{:?}", key), expected.to_string());
+/// assert_eq!(key.len(), 11);
+/// ```
+///
+/// As with [`fixed_alias!`](crate::fixed_alias), the `label` form generates a
+/// `#[repr(transparent)]` newtype around `Dynamic<T>` instead of a `type` alias, since an
+/// alias cannot have its own `Debug`. See there for what the newtype provides.
+///
 /// # Implementation Notes
 ///
 /// `dynamic_alias!` has **no zero-size or type-level guard** — any `T` is accepted.
@@ -65,6 +85,17 @@
 #[cfg(feature = "alloc")]
 #[macro_export]
 macro_rules! dynamic_alias {
+    ($vis:vis $name:ident, $inner:ty, $doc:literal, label = $label:literal) => {
+        $crate::__labeled_alias!($vis $name, $crate::Dynamic<$inner>, $label, $doc);
+    };
+    ($vis:vis $name:ident, $inner:ty, label = $label:literal) => {
+        $crate::__labeled_alias!(
+            $vis $name,
+            $crate::Dynamic<$inner>,
+            $label,
+            concat!("Secure heap-allocated ", stringify!($inner), ", labeled `", $label, "` in `Debug`")
+        );
+    };
     ($vis:vis $name:ident, $inner:ty, $doc:literal) => {
         #[doc = $doc]
         $vis type $name = $crate::Dynamic<$inner>;
@@ -11,6 +11,8 @@
 /// fixed_alias!(pub(crate) Name, N);         // crate-visible
 /// fixed_alias!(Name, N);                    // private
 /// fixed_alias!(pub Name, N, "doc string");  // with custom doc
+/// fixed_alias!(pub Name, N, label = "name");              // labeled newtype
+/// fixed_alias!(pub Name, N, "doc string", label = "name"); // labeled, custom doc
 /// ```
 ///
 /// # Examples
@@ -38,6 +40,28 @@
 /// assert_eq!(key.expose_secret(), &[0u8; 32]);
 /// ```
 ///
+/// With a redaction label (see [`RedactionPolicy`](crate::RedactionPolicy)):
+///
+/// ```rust
+/// use secure_gate::{fixed_alias, RedactionPolicy, RevealSecret};
+///
+/// fixed_alias!(pub SigningKey, 32, label = "signing_key");
+///
+/// let key = SigningKey::new([7u8; 32]);
+/// // `[REDACTED signing_key]`, or `[REDACTED signing_key; 32 bytes]` with `redact-length`.
+/// let expected = RedactionPolicy::DEFAULT.label("signing_key").redact(32);
+/// assert_eq!(format!("{:?}", key), expected.to_string());
+/// key.with_secret(|b| assert_eq!(b[0], 7));
+/// ```
+///
+/// A `type` alias cannot have its own `Debug`, so the `label` form generates a
+/// `#[repr(transparent)]` **newtype** around `Fixed<[u8; N]>` rather than an alias. It
+/// implements [`RevealSecret`](crate::RevealSecret) /
+/// [`RevealSecretMut`](crate::RevealSecretMut), dereferences to the `Fixed` (for
+/// `to_hex()`, `ct_eq()` and the other inherent methods), converts with `new`,
+/// `From<Fixed<[u8; N]>>` and `into_secret()`, and exposes its policy as
+/// `Name::REDACTION`. Unlike the plain form, two labeled types are distinct.
+///
 /// Zero-size is a **compile error** (caught by the zero-size guard):
 ///
 /// ```rust,compile_fail
@@ -71,6 +95,19 @@
 /// - [`dynamic_alias!`](crate::dynamic_alias) — heap-allocated alternative
 #[macro_export]
 macro_rules! fixed_alias {
+    ($vis:vis $name:ident, $size:literal, $doc:literal, label = $label:literal) => {
+        const _: () = { let _ = [(); $size][0]; };
+        $crate::__labeled_alias!($vis $name, $crate::Fixed<[u8; $size]>, $label, $doc);
+    };
+    ($vis:vis $name:ident, $size:literal, label = $label:literal) => {
+        const _: () = { let _ = [(); $size][0]; };
+        $crate::__labeled_alias!(
+            $vis $name,
+            $crate::Fixed<[u8; $size]>,
+            $label,
+            concat!("Fixed-size secure secret (", stringify!($size), " bytes), labeled `", $label, "` in `Debug`")
+        );
+    };
     ($vis:vis $name:ident, $size:literal, $doc:literal) => {
         const _: () = { let _ = [(); $size][0]; };
         #[doc = $doc]
@@ -0,0 +1,120 @@
+/// Shared expansion of the `label = "..."` form of [`fixed_alias!`](crate::fixed_alias)
+/// and [`dynamic_alias!`](crate::dynamic_alias). Not public API.
+///
+/// A `type` alias cannot carry its own `Debug`, so the labeled form emits a
+/// `#[repr(transparent)]` newtype around the wrapper instead. It dereferences to
+/// the wrapper (never to the secret), forwards [`RevealSecret`](crate::RevealSecret)
+/// / [`RevealSecretMut`](crate::RevealSecretMut), and prints
+/// `[REDACTED <label>]` under [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __labeled_alias {
+    ($vis:vis $name:ident, $wrapper:ty, $label:literal, $doc:expr) => {
+        #[doc = $doc]
+        #[repr(transparent)]
+        $vis struct $name($wrapper);
+
+        impl $name {
+            /// Redaction policy used by this type's `Debug`.
+            pub const REDACTION: $crate::RedactionPolicy =
+                $crate::RedactionPolicy::DEFAULT.label($label);
+
+            /// Wraps `value`, converting it into the underlying secret wrapper.
+            #[inline(always)]
+            pub fn new(value: impl ::core::convert::Into<$wrapper>) -> Self {
+                Self(value.into())
+            }
+
+            /// Unwraps into the underlying secret wrapper.
+            #[inline(always)]
+            pub fn into_secret(self) -> $wrapper {
+                self.0
+            }
+        }
+
+        impl ::core::convert::From<$wrapper> for $name {
+            #[inline(always)]
+            fn from(secret: $wrapper) -> Self {
+                Self(secret)
+            }
+        }
+
+        impl ::core::ops::Deref for $name {
+            type Target = $wrapper;
+
+            #[inline(always)]
+            fn deref(&self) -> &$wrapper {
+                &self.0
+            }
+        }
+
+        impl ::core::ops::DerefMut for $name {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut $wrapper {
+                &mut self.0
+            }
+        }
+
+        impl $crate::RevealSecret for $name {
+            type Inner = <$wrapper as $crate::RevealSecret>::Inner;
+
+            #[inline(always)]
+            #[track_caller]
+            fn with_secret<F, R>(&self, f: F) -> R
+            where
+                F: FnOnce(&Self::Inner) -> R,
+            {
+                $crate::RevealSecret::with_secret(&self.0, f)
+            }
+
+            #[inline(always)]
+            #[track_caller]
+            fn expose_secret(&self) -> &Self::Inner {
+                $crate::RevealSecret::expose_secret(&self.0)
+            }
+
+            #[inline(always)]
+            fn len(&self) -> usize {
+                $crate::RevealSecret::len(&self.0)
+            }
+
+            #[inline(always)]
+            fn byte_len(&self) -> usize {
+                $crate::RevealSecret::byte_len(&self.0)
+            }
+
+            #[inline(always)]
+            #[track_caller]
+            fn into_inner(self) -> $crate::InnerSecret<Self::Inner>
+            where
+                Self: Sized,
+                Self::Inner: Sized + $crate::SentinelValue + $crate::__private::zeroize::Zeroize,
+            {
+                $crate::RevealSecret::into_inner(self.0)
+            }
+        }
+
+        impl $crate::RevealSecretMut for $name {
+            #[inline(always)]
+            #[track_caller]
+            fn with_secret_mut<F, R>(&mut self, f: F) -> R
+            where
+                F: FnOnce(&mut Self::Inner) -> R,
+            {
+                $crate::RevealSecretMut::with_secret_mut(&mut self.0, f)
+            }
+
+            #[inline(always)]
+            #[track_caller]
+            fn expose_secret_mut(&mut self) -> &mut Self::Inner {
+                $crate::RevealSecretMut::expose_secret_mut(&mut self.0)
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0.redacted(Self::REDACTION), f)
+            }
+        }
+    };
+}
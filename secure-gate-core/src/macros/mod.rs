@@ -11,6 +11,10 @@
 //! between distinct cryptographic roles. If you need nominal separation, wrap the alias
 //! in a `struct` newtype yourself.
 //!
+//! The exception is the `label = "..."` form of [`fixed_alias!`] and [`dynamic_alias!`]:
+//! a label must live on the type's `Debug` impl, so it generates a transparent newtype
+//! that prints `[REDACTED <label>]` (see [`RedactionPolicy`](crate::RedactionPolicy)).
+//!
 //! | Macro                   | Generates                   | Feature   |
 //! |-------------------------|-----------------------------|-----------|
 //! | [`fixed_alias!`]        | `Fixed<[u8; N]>` alias      | Always    |
@@ -36,3 +40,4 @@ mod dynamic_alias;
 mod dynamic_generic_alias;
 mod fixed_alias;
 mod fixed_generic_alias;
+mod labeled_alias;
@@ -0,0 +1,207 @@
+//! Configurable redaction for log output: labels and length hints.
+//!
+//! Bare `Debug` on every wrapper prints `[REDACTED]` and nothing else — a log line
+//! holding ten secrets becomes ten identical markers. A [`RedactionPolicy`] adds
+//! public metadata to the marker without ever touching the secret bytes:
+//!
+//! | Policy | Output |
+//! |--------|--------|
+//! | [`RedactionPolicy::BARE`] | `[REDACTED]` |
+//! | `.label("api_key")` | `[REDACTED api_key]` |
+//! | `.length_hint(true)` | `[REDACTED; 32 bytes]` |
+//! | both | `[REDACTED api_key; 32 bytes]` |
+//!
+//! Apply a policy with `redacted(policy)` on [`Fixed`], `Dynamic`, [`InnerSecret`]
+//! and `EncodedSecret`; the returned [`Redacted`] implements `Debug` and `Display`.
+//! For a label that sticks to a type, pass `label = "..."` to
+//! [`fixed_alias!`](crate::fixed_alias) or [`dynamic_alias!`](crate::dynamic_alias).
+//!
+//! # Crate-level default
+//!
+//! [`RedactionPolicy::DEFAULT`] drives the plain `Debug` output of every secret
+//! wrapper and view in this crate, and is the starting point for labeled aliases.
+//! It is [`BARE`](RedactionPolicy::BARE) unless the `redact-length` feature is
+//! enabled, in which case it carries a length hint: `format!("{:?}", key)` then
+//! prints `[REDACTED; 32 bytes]`, and a labeled alias of the same key prints
+//! `[REDACTED signing_key; 32 bytes]`.
+//!
+//! The length hint needs a length. `Fixed<T>` uses `size_of::<T>()`; `Frozen`,
+//! `Budgeted`, `Expiring`, `Shielded` and `EncodedSecret` use the wrapped
+//! secret's length, and `SecretRef` / `SecretMut` the size of the borrowed range.
+//! `Dynamic<T>` and [`InnerSecret<T>`] have `Debug` for every `T`, but a generic
+//! `T` may keep its bytes behind a pointer (`Vec`, `String`), so their `Debug`
+//! prints the default policy without a length hint. Call `redacted(policy)` on
+//! them for a length-aware marker.
+//!
+//! # Length is metadata, not secret — usually
+//!
+//! A length hint reads only [`RevealSecret::byte_len`], never the bytes. For
+//! fixed-size secrets the length is already public in the type. For passwords
+//! and other variable-length secrets the length narrows a guessing attack; keep
+//! the hint off for those.
+//!
+//! # Example
+//!
+//! ```rust
+//! use secure_gate::{Fixed, RedactionPolicy};
+//!
+//! let key = Fixed::new([0u8; 32]);
+//! let policy = RedactionPolicy::BARE.label("signing_key").length_hint(true);
+//! assert_eq!(format!("{:?}", key.redacted(policy)), "[REDACTED signing_key; 32 bytes]");
+//! ```
+
+use core::fmt;
+
+use crate::{Fixed, InnerSecret, RevealSecret};
+
+/// What a redacted marker may say about a secret: an optional label and an
+/// optional length hint. Never the secret itself.
+///
+/// Built with `const fn` combinators, so policies can live in `const` items.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RedactionPolicy {
+    label: Option<&'static str>,
+    length_hint: bool,
+}
+
+impl RedactionPolicy {
+    /// Prints `[REDACTED]` — no label, no length.
+    pub const BARE: Self = Self {
+        label: None,
+        length_hint: false,
+    };
+
+    /// Crate-level default: [`BARE`](Self::BARE), plus a length hint when the
+    /// `redact-length` feature is enabled.
+    pub const DEFAULT: Self = Self::BARE.length_hint(cfg!(feature = "redact-length"));
+
+    /// Sets the label printed after `REDACTED`, e.g. `[REDACTED api_key]`.
+    #[inline]
+    pub const fn label(self, label: &'static str) -> Self {
+        Self {
+            label: Some(label),
+            ..self
+        }
+    }
+
+    /// Turns the `; N bytes` length hint on or off.
+    #[inline]
+    pub const fn length_hint(self, enabled: bool) -> Self {
+        Self {
+            length_hint: enabled,
+            ..self
+        }
+    }
+
+    /// Binds the policy to a secret length, producing a printable marker.
+    #[inline]
+    pub const fn redact(self, byte_len: usize) -> Redacted {
+        Redacted {
+            policy: self,
+            byte_len,
+        }
+    }
+    /// Marker for a secret whose length is not known: the policy minus its
+    /// length hint.
+    #[inline]
+    pub(crate) const fn redact_unknown_len(self) -> Redacted {
+        self.length_hint(false).redact(0)
+    }
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A redacted marker ready to print. `Debug` and `Display` both produce the
+/// same `[REDACTED ...]` text.
+///
+/// Holds only the policy and the secret's length — no borrow of the secret.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Redacted {
+    policy: RedactionPolicy,
+    byte_len: usize,
+}
+
+impl fmt::Display for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED")?;
+        if let Some(label) = self.policy.label {
+            write!(f, " {label}")?;
+        }
+        if self.policy.length_hint {
+            let unit = if self.byte_len == 1 { "byte" } else { "bytes" };
+            write!(f, "; {} {unit}", self.byte_len)?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<T: zeroize::Zeroize> Fixed<T>
+where
+    Self: RevealSecret,
+{
+    /// A redacted marker for this secret under `policy`.
+    ///
+    /// Reads only [`byte_len`](RevealSecret::byte_len); the secret is not revealed.
+    ///
+    /// ```rust
+    /// use secure_gate::{Fixed, RedactionPolicy};
+    ///
+    /// let nonce = Fixed::new([0u8; 12]);
+    /// let policy = RedactionPolicy::BARE.length_hint(true);
+    /// assert_eq!(nonce.redacted(policy).to_string(), "[REDACTED; 12 bytes]");
+    /// ```
+    #[inline]
+    pub fn redacted(&self, policy: RedactionPolicy) -> Redacted {
+        policy.redact(self.byte_len())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + zeroize::Zeroize> crate::Dynamic<T>
+where
+    Self: RevealSecret,
+{
+    /// A redacted marker for this secret under `policy`.
+    ///
+    /// Reads only [`byte_len`](RevealSecret::byte_len); the secret is not revealed.
+    ///
+    /// ```rust
+    /// use secure_gate::{Dynamic, RedactionPolicy};
+    ///
+    /// let token = Dynamic::<String>::new(String::from("tok_3f9a"));
+    /// let policy = RedactionPolicy::BARE.label("session");
+    /// assert_eq!(format!("{:?}", token.redacted(policy)), "[REDACTED session]");
+    /// ```
+    #[inline]
+    pub fn redacted(&self, policy: RedactionPolicy) -> Redacted {
+        policy.redact(self.byte_len())
+    }
+}
+
+impl<T: zeroize::Zeroize + AsRef<[u8]>> InnerSecret<T> {
+    /// A redacted marker for this value under `policy`, using its byte length.
+    #[inline]
+    pub fn redacted(&self, policy: RedactionPolicy) -> Redacted {
+        policy.redact((**self).as_ref().len())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl crate::EncodedSecret {
+    /// A redacted marker for this encoded string under `policy`, using its
+    /// length in bytes.
+    #[inline]
+    pub fn redacted(&self, policy: RedactionPolicy) -> Redacted {
+        policy.redact(self.len())
+    }
+}
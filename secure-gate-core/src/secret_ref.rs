@@ -0,0 +1,384 @@
+//! Borrowed sub-secret views.
+//!
+//! [`SecretRef<'a, T>`] and [`SecretMut<'a, T>`] are lifetime-bound views into part
+//! of a secret held by a [`Fixed`](crate::Fixed) or [`Dynamic`](crate::Dynamic).
+//! They are returned by the wrappers' `slice` / `slice_mut` methods and let a
+//! sub-range of a key (bytes `16..32` of a `Fixed<[u8; 48]>`, say) be handed to
+//! another component **without** calling `expose_secret` and passing a raw `&[u8]`.
+//!
+//! # Security invariants
+//!
+//! - **No `Deref`, `AsRef`, `Clone` or `Copy`** — the view is read through
+//!   [`RevealSecret`] (and written through [`RevealSecretMut`] for `SecretMut`),
+//!   exactly like the owning wrapper.
+//! - **`Debug` is redacted** — the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT)
+//!   marker, with the view's length under `redact-length`.
+//! - **Borrowed, never owned** — the view cannot outlive the wrapper it came from,
+//!   and the wrapper remains responsible for zeroization. A view does not zeroize
+//!   anything on drop.
+//! - **`into_inner` is not callable** — the inner type is always unsized (`[T]` or
+//!   `str`), and a borrow cannot give up ownership anyway.
+//!
+//! Views can be narrowed further with their own `slice` / `slice_mut`, so generic
+//! code written against [`RevealSecret`] can take sub-views of sub-views.
+//!
+//! # Example
+//!
+//! ```rust
+//! use secure_gate::{Fixed, RedactionPolicy, RevealSecret, SecretRef};
+//!
+//! fn mac_key_len<S: RevealSecret<Inner = [u8]>>(key: &S) -> usize {
+//!     key.with_secret(|k| k.len())
+//! }
+//!
+//! let schedule = Fixed::new([0xA5u8; 48]);
+//! let mac: SecretRef<'_, [u8]> = schedule.slice(16..32);
+//! assert_eq!(mac_key_len(&mac), 16);
+//! assert_eq!(format!("{:?}", mac), RedactionPolicy::DEFAULT.redact(16).to_string());
+//! ```
+//!
+//! # Panics
+//!
+//! `slice` and `slice_mut` panic when the range is out of bounds (or, for `str`,
+//! not on a `char` boundary), matching slice indexing. The panic message contains
+//! only indices and lengths, never secret bytes.
+
+use core::slice::SliceIndex;
+
+use crate::{RevealSecret, RevealSecretMut};
+
+/// Read-only borrowed view into part of a secret.
+///
+/// Obtained from `Fixed::slice`, `Dynamic::slice` or [`SecretRef::slice`]. Read
+/// through [`RevealSecret`]; no `Deref`, `Clone` or `Copy`, and `Debug` is redacted.
+/// The owning wrapper stays responsible for zeroization.
+pub struct SecretRef<'a, T: ?Sized> {
+    inner: &'a T,
+}
+
+/// Mutable borrowed view into part of a secret.
+///
+/// Obtained from `Fixed::slice_mut`, `Dynamic::slice_mut` or [`SecretMut::slice_mut`].
+/// Accessed through [`RevealSecret`] / [`RevealSecretMut`]; same invariants as
+/// [`SecretRef`].
+pub struct SecretMut<'a, T: ?Sized> {
+    inner: &'a mut T,
+}
+
+impl<'a, T: ?Sized> SecretRef<'a, T> {
+    #[inline(always)]
+    pub(crate) fn new(inner: &'a T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, T: ?Sized> SecretMut<'a, T> {
+    #[inline(always)]
+    pub(crate) fn new(inner: &'a mut T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, T> SecretRef<'a, [T]> {
+    /// Narrows the view to `index` (any range type).
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    #[inline(always)]
+    pub fn slice<I>(&self, index: I) -> SecretRef<'a, [T]>
+    where
+        I: SliceIndex<[T], Output = [T]>,
+    {
+        SecretRef::new(&self.inner[index])
+    }
+}
+
+impl<'a> SecretRef<'a, str> {
+    /// Narrows the view to `index` (any byte range type).
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds or not on a `char` boundary.
+    #[inline(always)]
+    pub fn slice<I>(&self, index: I) -> SecretRef<'a, str>
+    where
+        I: SliceIndex<str, Output = str>,
+    {
+        SecretRef::new(&self.inner[index])
+    }
+}
+
+impl<T> SecretMut<'_, [T]> {
+    /// Narrows the view to `index` (any range type), read-only.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    #[inline(always)]
+    pub fn slice<I>(&self, index: I) -> SecretRef<'_, [T]>
+    where
+        I: SliceIndex<[T], Output = [T]>,
+    {
+        SecretRef::new(&self.inner[index])
+    }
+
+    /// Narrows the view to `index` (any range type), mutably.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    #[inline(always)]
+    pub fn slice_mut<I>(&mut self, index: I) -> SecretMut<'_, [T]>
+    where
+        I: SliceIndex<[T], Output = [T]>,
+    {
+        SecretMut::new(&mut self.inner[index])
+    }
+}
+
+impl SecretMut<'_, str> {
+    /// Narrows the view to `index` (any byte range type), read-only.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds or not on a `char` boundary.
+    #[inline(always)]
+    pub fn slice<I>(&self, index: I) -> SecretRef<'_, str>
+    where
+        I: SliceIndex<str, Output = str>,
+    {
+        SecretRef::new(&self.inner[index])
+    }
+
+    /// Narrows the view to `index` (any byte range type), mutably.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds or not on a `char` boundary.
+    #[inline(always)]
+    pub fn slice_mut<I>(&mut self, index: I) -> SecretMut<'_, str>
+    where
+        I: SliceIndex<str, Output = str>,
+    {
+        SecretMut::new(&mut self.inner[index])
+    }
+}
+
+// ---------------------------------------------------------------------------
+// RevealSecret / RevealSecretMut
+// ---------------------------------------------------------------------------
+
+/// Read-only access to a borrowed slice view.
+impl<T> RevealSecret for SecretRef<'_, [T]> {
+    type Inner = [T];
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&[T]) -> R,
+    {
+        audit_access!(Scoped, false);
+        f(self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &[T] {
+        audit_access!(Direct, false);
+        self.inner
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        core::mem::size_of_val(self.inner)
+    }
+
+    /// Never callable — `[T]` is unsized, so the trait bounds cannot hold.
+    fn into_inner(self) -> crate::InnerSecret<[T]>
+    where
+        Self: Sized,
+        Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        crate::__private::unsized_into_inner()
+    }
+}
+
+/// Read-only access to a borrowed string view.
+impl RevealSecret for SecretRef<'_, str> {
+    type Inner = str;
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&str) -> R,
+    {
+        audit_access!(Scoped, false);
+        f(self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &str {
+        audit_access!(Direct, false);
+        self.inner
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Never callable — `str` is unsized, so the trait bounds cannot hold.
+    fn into_inner(self) -> crate::InnerSecret<str>
+    where
+        Self: Sized,
+        // See the `Dynamic<str>` impl for why `for<'a>` is needed here.
+        for<'a> Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        crate::__private::unsized_into_inner()
+    }
+}
+
+/// Read-only access to a mutable slice view.
+impl<T> RevealSecret for SecretMut<'_, [T]> {
+    type Inner = [T];
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&[T]) -> R,
+    {
+        audit_access!(Scoped, false);
+        f(self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &[T] {
+        audit_access!(Direct, false);
+        self.inner
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        core::mem::size_of_val(self.inner)
+    }
+
+    /// Never callable — `[T]` is unsized, so the trait bounds cannot hold.
+    fn into_inner(self) -> crate::InnerSecret<[T]>
+    where
+        Self: Sized,
+        Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        crate::__private::unsized_into_inner()
+    }
+}
+
+/// Read-only access to a mutable string view.
+impl RevealSecret for SecretMut<'_, str> {
+    type Inner = str;
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&str) -> R,
+    {
+        audit_access!(Scoped, false);
+        f(self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret(&self) -> &str {
+        audit_access!(Direct, false);
+        self.inner
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Never callable — `str` is unsized, so the trait bounds cannot hold.
+    fn into_inner(self) -> crate::InnerSecret<str>
+    where
+        Self: Sized,
+        // See the `Dynamic<str>` impl for why `for<'a>` is needed here.
+        for<'a> Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+    {
+        crate::__private::unsized_into_inner()
+    }
+}
+
+/// Mutable access to a mutable slice view.
+impl<T> RevealSecretMut for SecretMut<'_, [T]> {
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut [T]) -> R,
+    {
+        audit_access!(Scoped, true);
+        f(self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret_mut(&mut self) -> &mut [T] {
+        audit_access!(Direct, true);
+        self.inner
+    }
+}
+
+/// Mutable access to a mutable string view.
+impl RevealSecretMut for SecretMut<'_, str> {
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut str) -> R,
+    {
+        audit_access!(Scoped, true);
+        f(self.inner)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn expose_secret_mut(&mut self) -> &mut str {
+        audit_access!(Direct, true);
+        self.inner
+    }
+}
+
+/// Prints the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT) marker
+/// for the size of the borrowed range.
+impl<T: ?Sized> core::fmt::Debug for SecretRef<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let marker = crate::RedactionPolicy::DEFAULT.redact(core::mem::size_of_val(self.inner));
+        core::fmt::Display::fmt(&marker, f)
+    }
+}
+
+/// Prints the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT) marker
+/// for the size of the borrowed range.
+impl<T: ?Sized> core::fmt::Debug for SecretMut<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let marker = crate::RedactionPolicy::DEFAULT.redact(core::mem::size_of_val(self.inner));
+        core::fmt::Display::fmt(&marker, f)
+    }
+}
@@ -0,0 +1,365 @@
+//! In-memory encryption of idle secrets (OpenSSH-style key shielding).
+//!
+//! [`Shielded<T>`] keeps its secret encrypted while nobody is using it. The key
+//! is derived from a large random **prekey** (16 KiB, as in OpenSSH's
+//! `sshkey_shield_private`) and a per-access salt:
+//!
+//! ```text
+//! key ‖ nonce = BLAKE2b-512(prekey ‖ salt)      ciphertext = ChaCha20(key, nonce) ⊕ secret
+//! ```
+//!
+//! An attacker who reads process memory — a heap scrape, a cold-boot image, a
+//! Rowhammer-assisted leak — must recover every bit of the prekey to decrypt the
+//! secret. Bit errors anywhere in 16 KiB destroy the key, which is the point.
+//!
+//! [`with_secret`](crate::RevealSecret::with_secret) decrypts into a zeroizing
+//! temporary that lives only for the closure. Every access also **re-randomizes**
+//! the encryption: a fresh salt is drawn and the secret is re-encrypted before the
+//! closure runs, so the ciphertext at rest changes after each use.
+//!
+//! *Requires feature `shielded`* (pulls in `rand`, `chacha20` and `blake2`).
+//!
+//! # Security invariants
+//!
+//! - **Plaintext exists only inside `with_secret`**, in a `Zeroizing` temporary
+//!   that is wiped when the closure returns or unwinds.
+//! - **Direct access caches plaintext.** [`expose_secret`](crate::RevealSecret::expose_secret)
+//!   must return a reference, so it decrypts once into an internal zeroizing cache
+//!   that stays until [`reshield`](Shielded::reshield) or drop. Prefer
+//!   `with_secret`.
+//! - **No mutable access** — `Shielded` implements [`RevealSecret`] only.
+//! - **Zeroize on drop** — prekey, ciphertext and any cached plaintext.
+//! - **`Debug` is redacted** — the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT)
+//!   marker, with the secret's length under `redact-length`.
+//! - **Not `Sync`** — the state is re-encrypted behind `&self`.
+//!
+//! Shielding raises the cost of passive memory disclosure; it does not protect
+//! against an attacker who can run code in the process, who can simply call
+//! `with_secret`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use secure_gate::{Fixed, RedactionPolicy, RevealSecret, Shielded};
+//!
+//! let key = Shielded::new(Fixed::new([0x42u8; 32]));
+//! assert_eq!(key.with_secret(|k| k[0]), 0x42);
+//! assert_eq!(format!("{:?}", key), RedactionPolicy::DEFAULT.redact(32).to_string());
+//! ```
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::{OnceCell, RefCell};
+
+use blake2::{Blake2b512, Digest};
+use chacha20::ChaCha20;
+use chacha20::cipher::{KeyIvInit, StreamCipher};
+use rand::{TryRng, rngs::SysRng};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{Dynamic, Fixed, RevealSecret};
+
+/// Size of the random prekey. Matches OpenSSH's `SSHKEY_SHIELD_PREKEY_LEN`.
+const PREKEY_LEN: usize = 16 * 1024;
+const SALT_LEN: usize = 16;
+
+/// Encrypted form of a secret plus the material needed to decrypt it.
+struct ShieldState {
+    prekey: Box<[u8; PREKEY_LEN]>,
+    salt: [u8; SALT_LEN],
+    ciphertext: Vec<u8>,
+}
+
+impl ShieldState {
+    /// Draws a fresh prekey and encrypts `plaintext` under it.
+    fn seal(plaintext: &[u8]) -> Self {
+        let mut prekey = Box::new([0u8; PREKEY_LEN]);
+        SysRng
+            .try_fill_bytes(&mut prekey[..])
+            .expect("SysRng failure is a program error");
+        let mut state = Self {
+            prekey,
+            salt: [0u8; SALT_LEN],
+            ciphertext: alloc::vec![0u8; plaintext.len()],
+        };
+        state.reseal(plaintext);
+        state
+    }
+
+    /// Re-encrypts `plaintext` under a freshly drawn salt.
+    fn reseal(&mut self, plaintext: &[u8]) {
+        SysRng
+            .try_fill_bytes(&mut self.salt)
+            .expect("SysRng failure is a program error");
+        self.ciphertext.copy_from_slice(plaintext);
+        self.cipher().apply_keystream(&mut self.ciphertext);
+    }
+
+    /// Decrypts into `out`, which must be exactly `ciphertext.len()` bytes.
+    fn open_into(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.ciphertext);
+        self.cipher().apply_keystream(out);
+    }
+
+    /// `key ‖ nonce = BLAKE2b-512(prekey ‖ salt)`.
+    fn cipher(&self) -> ChaCha20 {
+        let mut hasher = Blake2b512::new();
+        hasher.update(&self.prekey[..]);
+        hasher.update(self.salt);
+        let mut okm = hasher.finalize();
+        let key: &[u8; 32] = okm[..32].try_into().expect("64-byte digest");
+        let nonce: &[u8; 12] = okm[32..44].try_into().expect("64-byte digest");
+        let cipher = ChaCha20::new(key.into(), nonce.into());
+        okm.as_mut_slice().zeroize();
+        cipher
+    }
+
+    /// Decrypts, re-randomizes the stored ciphertext, and returns the plaintext.
+    fn open_and_reseal(&mut self, out: &mut [u8]) {
+        self.open_into(out);
+        self.reseal(out);
+    }
+}
+
+impl Drop for ShieldState {
+    fn drop(&mut self) {
+        self.prekey.zeroize();
+        self.salt.zeroize();
+        self.ciphertext.zeroize();
+    }
+}
+
+/// Secret kept encrypted in memory and decrypted only for the duration of a
+/// `with_secret` closure.
+///
+/// Implemented for `Shielded<[u8; N]>` (from [`Fixed<[u8; N]>`](crate::Fixed)),
+/// `Shielded<Vec<u8>>` and `Shielded<String>` (from the matching
+/// [`Dynamic<T>`](crate::Dynamic)). Implements [`RevealSecret`] only.
+pub struct Shielded<T: Zeroize> {
+    state: RefCell<ShieldState>,
+    /// Plaintext handed out by `expose_secret`, kept until `reshield` or drop.
+    exposed: OnceCell<Zeroizing<T>>,
+}
+
+impl<T: Zeroize> Shielded<T> {
+    /// Encrypts `secret` in memory: a [`Fixed<[u8; N]>`](crate::Fixed),
+    /// [`Dynamic<Vec<u8>>`](crate::Dynamic) or `Dynamic<String>`. The original
+    /// wrapper is consumed and zeroized.
+    #[inline]
+    pub fn new<S>(secret: S) -> Self
+    where
+        S: Into<Self>,
+    {
+        secret.into()
+    }
+
+    fn from_plaintext(plaintext: &[u8]) -> Self {
+        Self {
+            state: RefCell::new(ShieldState::seal(plaintext)),
+            exposed: OnceCell::new(),
+        }
+    }
+
+    /// Decrypts into `out` and re-randomizes the stored ciphertext.
+    fn open(&self, out: &mut [u8]) {
+        self.state.borrow_mut().open_and_reseal(out);
+    }
+
+    /// Length of the secret in bytes.
+    fn secret_len(&self) -> usize {
+        self.state.borrow().ciphertext.len()
+    }
+
+    /// Wipes the plaintext cached by `expose_secret` (if any) and re-encrypts
+    /// under a fresh prekey.
+    ///
+    /// Call this after finishing with a reference from `expose_secret` to return
+    /// the secret to its fully shielded state.
+    pub fn reshield(&mut self) {
+        self.exposed.take();
+        let state = self.state.get_mut();
+        let mut plain = Zeroizing::new(alloc::vec![0u8; state.ciphertext.len()]);
+        state.open_into(&mut plain);
+        *state = ShieldState::seal(&plain);
+    }
+
+    /// `true` if `expose_secret` has left a decrypted copy in memory that
+    /// [`reshield`](Self::reshield) has not yet wiped.
+    #[inline]
+    pub fn is_exposed(&self) -> bool {
+        self.exposed.get().is_some()
+    }
+}
+
+impl<const N: usize> Shielded<[u8; N]> {
+    fn decrypt(&self) -> Zeroizing<[u8; N]> {
+        let mut plain = Zeroizing::new([0u8; N]);
+        self.open(&mut plain[..]);
+        plain
+    }
+}
+
+impl Shielded<Vec<u8>> {
+    fn decrypt(&self) -> Zeroizing<Vec<u8>> {
+        let mut plain = Zeroizing::new(alloc::vec![0u8; self.secret_len()]);
+        self.open(&mut plain);
+        plain
+    }
+}
+
+impl Shielded<String> {
+    fn decrypt(&self) -> Zeroizing<String> {
+        let mut plain = Zeroizing::new(alloc::vec![0u8; self.secret_len()]);
+        self.open(&mut plain);
+        // Only corrupted ciphertext fails here. `Utf8Error` holds no bytes, unlike
+        // `FromUtf8Error`, whose `Debug` would print the decrypted buffer.
+        if core::str::from_utf8(&plain).is_err() {
+            plain.zeroize();
+            panic!("shielded String did not decrypt to valid UTF-8");
+        }
+        // Moves the buffer without copying; validated above, so this cannot fail.
+        let s = String::from_utf8(core::mem::take(&mut *plain))
+            .unwrap_or_else(|_| unreachable!("validated as UTF-8 above"));
+        Zeroizing::new(s)
+    }
+}
+
+/// Shields a fixed-size key; the `Fixed` is zeroized on drop.
+impl<const N: usize> From<Fixed<[u8; N]>> for Shielded<[u8; N]> {
+    fn from(secret: Fixed<[u8; N]>) -> Self {
+        secret.with_secret(|b| Self::from_plaintext(b))
+    }
+}
+
+/// Shields a heap byte buffer; the `Dynamic` is zeroized on drop.
+impl From<Dynamic<Vec<u8>>> for Shielded<Vec<u8>> {
+    fn from(secret: Dynamic<Vec<u8>>) -> Self {
+        secret.with_secret(|b| Self::from_plaintext(b))
+    }
+}
+
+/// Shields a password or other string secret; the `Dynamic` is zeroized on drop.
+impl From<Dynamic<String>> for Shielded<String> {
+    fn from(secret: Dynamic<String>) -> Self {
+        secret.with_secret(|s| Self::from_plaintext(s.as_bytes()))
+    }
+}
+
+/// Implements `RevealSecret` for a `Shielded<T>` that has an inherent `decrypt`.
+macro_rules! impl_reveal_shielded {
+    ($([$($gen:tt)*] $ty:ty),+ $(,)?) => {$(
+        /// Scoped access decrypts into a zeroizing temporary and re-randomizes the
+        /// stored ciphertext; direct access caches the plaintext until
+        /// [`reshield`](Shielded::reshield).
+        impl<$($gen)*> RevealSecret for Shielded<$ty> {
+            type Inner = $ty;
+
+            #[inline]
+            #[cfg_attr(feature = "audit", track_caller)]
+            fn with_secret<F, R>(&self, f: F) -> R
+            where
+                F: FnOnce(&$ty) -> R,
+            {
+                audit_access!(Scoped, false);
+                let plain = self.decrypt();
+                f(&plain)
+            }
+
+            #[inline]
+            #[cfg_attr(feature = "audit", track_caller)]
+            fn expose_secret(&self) -> &$ty {
+                audit_access!(Direct, false);
+                self.exposed.get_or_init(|| self.decrypt())
+            }
+
+            #[inline(always)]
+            fn len(&self) -> usize {
+                self.secret_len()
+            }
+
+            #[inline(always)]
+            fn byte_len(&self) -> usize {
+                self.secret_len()
+            }
+
+            /// Decrypts into an owned [`InnerSecret`](crate::InnerSecret) and drops the
+            /// shielded state (prekey and ciphertext are zeroized).
+            #[inline]
+            #[cfg_attr(feature = "audit", track_caller)]
+            fn into_inner(self) -> crate::InnerSecret<$ty>
+            where
+                Self: Sized,
+                Self::Inner: Sized + crate::SentinelValue + zeroize::Zeroize,
+            {
+                audit_access!(Owned, false);
+                let mut plain = self.decrypt();
+                crate::InnerSecret::new(core::mem::replace(
+                    &mut *plain,
+                    <$ty as crate::SentinelValue>::sentinel_value(),
+                ))
+            }
+        }
+    )+};
+}
+
+impl_reveal_shielded!(
+    [const N: usize] [u8; N],
+    [] Vec<u8>,
+    [] String,
+);
+
+/// Prints the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT) marker
+/// for the secret's length, read from the ciphertext; nothing is decrypted.
+impl<T: Zeroize> core::fmt::Debug for Shielded<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let marker = crate::RedactionPolicy::DEFAULT.redact(self.secret_len());
+        core::fmt::Display::fmt(&marker, f)
+    }
+}
+
+impl<T: Zeroize> zeroize::ZeroizeOnDrop for Shielded<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ciphertext_differs_from_plaintext_and_changes_per_access() {
+        let key = Shielded::new(Fixed::new([0x42u8; 32]));
+        let before = key.state.borrow().ciphertext.clone();
+        assert_ne!(before, [0x42u8; 32]);
+
+        assert_eq!(key.with_secret(|k| k[0]), 0x42);
+        let after = key.state.borrow().ciphertext.clone();
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn reshield_replaces_prekey_and_clears_cache() {
+        let mut key = Shielded::new(Dynamic::<Vec<u8>>::new(alloc::vec![1u8, 2, 3]));
+        assert_eq!(key.expose_secret(), &[1, 2, 3]);
+        assert!(key.is_exposed());
+
+        let old_prekey = key.state.borrow().prekey[..32].to_vec();
+        key.reshield();
+        assert!(!key.is_exposed());
+        assert_ne!(key.state.borrow().prekey[..32], old_prekey[..]);
+        assert_eq!(key.with_secret(|k| k.clone()), [1, 2, 3]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn corrupted_string_panics_without_printing_plaintext() {
+        let key = Shielded::new(Dynamic::<String>::new(String::from("hunter2")));
+        // Stream cipher: flipping the top bit turns 'h' into a lone UTF-8 lead byte.
+        key.state.borrow_mut().ciphertext[0] ^= 0x80;
+
+        let panic = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            key.with_secret(|s| s.len())
+        }))
+        .unwrap_err();
+        let msg = panic.downcast_ref::<&str>().copied().unwrap_or_default();
+        assert_eq!(msg, "shielded String did not decrypt to valid UTF-8");
+    }
+}
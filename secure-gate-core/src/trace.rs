@@ -0,0 +1,244 @@
+//! Structured, redacted values for `tracing` and `log` fields.
+//!
+//! Secret-bearing values end up in spans and events. A `#[instrument]` argument or
+//! a `?secret` field already goes through the wrapper's `Debug`, so it prints
+//! `[REDACTED]`. That is safe but tells the reader nothing. This module gives log
+//! pipelines a **structured record** instead:
+//!
+//! | Field | Value |
+//! |-------|-------|
+//! | `type` | [`core::any::type_name`] of the wrapper, e.g. `secure_gate::fixed::Fixed<[u8; 32]>` |
+//! | `len` | [`byte_len`](crate::RevealSecret::byte_len) — public metadata, not the bytes |
+//! | `fingerprint` | only if attached with `SecretRecord::with_fingerprint` (`fingerprint` feature) |
+//!
+//! The record never contains secret bytes. Building it reads only the length and
+//! is not reported to the `audit` hook. Attaching a fingerprint is a Tier 1
+//! reveal, done explicitly by the caller.
+//!
+//! *Requires feature `tracing`* (pulls in `valuable`, `no_std`-compatible).
+//!
+//! # Two ways in
+//!
+//! - **`Display`** — [`SecretRecord`] prints as
+//!   `[REDACTED <type>; <len> bytes]`. Record it with tracing's `%` sigil:
+//!   `info!(key = %key.trace_record())`. This works on stable tracing today.
+//! - **[`valuable::Valuable`]** — `Fixed`, `Dynamic`, [`InnerSecret`] and
+//!   `EncodedSecret` implement `Valuable` / `Structable` directly, as a struct
+//!   named `Redacted` with the fields above. Use it with
+//!   `tracing::field::valuable(&key)` (tracing's `valuable` support needs
+//!   `RUSTFLAGS="--cfg tracing_unstable"`) or any other `valuable` consumer.
+//!
+//! `tracing::Value` is a sealed trait, so it cannot be implemented outside
+//! `tracing-core`. Both routes above end in one of its built-in impls.
+//!
+//! # Example
+//!
+//! ```rust
+//! use secure_gate::Fixed;
+//!
+//! let key = Fixed::new([0x42u8; 32]);
+//! let record = key.trace_record();
+//! assert_eq!(record.byte_len(), 32);
+//! assert_eq!(
+//!     record.to_string(),
+//!     "[REDACTED secure_gate::fixed::Fixed<[u8; 32]>; 32 bytes]"
+//! );
+//! // With tracing: tracing::info!(key = %key.trace_record(), "loaded signing key");
+//! ```
+
+use core::fmt;
+
+use valuable::{Fields, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit};
+
+use crate::{Fixed, InnerSecret, RevealSecret};
+
+const FIELDS: &[NamedField<'static>] = &[NamedField::new("type"), NamedField::new("len")];
+
+#[cfg(feature = "fingerprint")]
+const FIELDS_WITH_FINGERPRINT: &[NamedField<'static>] = &[
+    NamedField::new("type"),
+    NamedField::new("len"),
+    NamedField::new("fingerprint"),
+];
+
+/// Struct name reported to `valuable` visitors.
+const STRUCT_NAME: &str = "Redacted";
+
+/// Redacted description of a secret: its type and length, and optionally a
+/// keyed [`Fingerprint`](crate::Fingerprint). Never the secret bytes.
+///
+/// Obtained from `trace_record()` on [`Fixed`], `Dynamic`, [`InnerSecret`] and
+/// `EncodedSecret`. Implements `Display` and [`Valuable`]. Requires `tracing`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SecretRecord {
+    type_name: &'static str,
+    byte_len: usize,
+    #[cfg(feature = "fingerprint")]
+    fingerprint: Option<crate::Fingerprint>,
+}
+
+impl SecretRecord {
+    fn new<W: ?Sized>(byte_len: usize) -> Self {
+        Self {
+            type_name: core::any::type_name::<W>(),
+            byte_len,
+            #[cfg(feature = "fingerprint")]
+            fingerprint: None,
+        }
+    }
+
+    /// [`core::any::type_name`] of the wrapper. Informational only — the format
+    /// is not stable across compiler versions.
+    #[inline]
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Length of the secret in bytes.
+    #[inline]
+    pub const fn byte_len(&self) -> usize {
+        self.byte_len
+    }
+
+    /// Attaches a keyed fingerprint, so records of the same secret can be
+    /// correlated across log lines. Requires `fingerprint`.
+    ///
+    /// ```rust
+    /// use secure_gate::{Fixed, FingerprintKey};
+    ///
+    /// let fp_key = FingerprintKey::random();
+    /// let key = Fixed::new([0x42u8; 32]);
+    /// let record = key.trace_record().with_fingerprint(key.fingerprint(&fp_key));
+    /// assert!(record.to_string().contains("fingerprint "));
+    /// ```
+    #[cfg(feature = "fingerprint")]
+    #[inline]
+    pub const fn with_fingerprint(self, fingerprint: crate::Fingerprint) -> Self {
+        Self {
+            fingerprint: Some(fingerprint),
+            ..self
+        }
+    }
+
+    /// The attached fingerprint, if any. Requires `fingerprint`.
+    #[cfg(feature = "fingerprint")]
+    #[inline]
+    pub const fn fingerprint(&self) -> Option<crate::Fingerprint> {
+        self.fingerprint
+    }
+
+    /// Visits the record as a `Redacted { type, len[, fingerprint] }` struct.
+    fn visit_fields(&self, visit: &mut dyn Visit) {
+        #[cfg(feature = "fingerprint")]
+        if let Some(fp) = self.fingerprint {
+            let hex = fp.hex();
+            let hex = core::str::from_utf8(&hex).expect("hex digits are ASCII");
+            visit.visit_named_fields(&NamedValues::new(
+                FIELDS_WITH_FINGERPRINT,
+                &[
+                    Value::String(self.type_name),
+                    Value::Usize(self.byte_len),
+                    Value::String(hex),
+                ],
+            ));
+            return;
+        }
+        visit.visit_named_fields(&NamedValues::new(
+            FIELDS,
+            &[Value::String(self.type_name), Value::Usize(self.byte_len)],
+        ));
+    }
+
+    fn definition(&self) -> StructDef<'static> {
+        #[cfg(feature = "fingerprint")]
+        if self.fingerprint.is_some() {
+            return StructDef::new_static(STRUCT_NAME, Fields::Named(FIELDS_WITH_FINGERPRINT));
+        }
+        StructDef::new_static(STRUCT_NAME, Fields::Named(FIELDS))
+    }
+}
+
+impl fmt::Display for SecretRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[REDACTED {}; {} bytes", self.type_name, self.byte_len)?;
+        #[cfg(feature = "fingerprint")]
+        if let Some(fp) = self.fingerprint {
+            write!(f, "; fingerprint {fp}")?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl Valuable for SecretRecord {
+    fn as_value(&self) -> Value<'_> {
+        Value::Structable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        self.visit_fields(visit);
+    }
+}
+
+impl Structable for SecretRecord {
+    fn definition(&self) -> StructDef<'_> {
+        SecretRecord::definition(self)
+    }
+}
+
+/// `trace_record()` plus `Valuable` / `Structable` for a wrapper type, all in
+/// terms of its `SecretRecord`. `$len` computes the byte length from `$s`.
+macro_rules! impl_trace_record {
+    (
+        $(#[$attr:meta])*
+        [$($gen:tt)*] $ty:ty, where [$($bound:tt)*],
+        |$s:ident| $len:expr
+    ) => {
+        $(#[$attr])*
+        impl<$($gen)*> $ty where $($bound)* {
+            /// Redacted record of this secret (type and length) for structured
+            /// logs. Does not reveal the secret. Requires `tracing`.
+            #[inline]
+            pub fn trace_record(&self) -> SecretRecord {
+                let $s = self;
+                SecretRecord::new::<Self>($len)
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($gen)*> Valuable for $ty where $($bound)* {
+            fn as_value(&self) -> Value<'_> {
+                Value::Structable(self)
+            }
+
+            fn visit(&self, visit: &mut dyn Visit) {
+                self.trace_record().visit_fields(visit);
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($gen)*> Structable for $ty where $($bound)* {
+            fn definition(&self) -> StructDef<'_> {
+                self.trace_record().definition()
+            }
+        }
+    };
+}
+
+impl_trace_record!(
+    [T: zeroize::Zeroize] Fixed<T>, where [Self: RevealSecret],
+    |s| s.byte_len()
+);
+impl_trace_record!(
+    #[cfg(feature = "alloc")]
+    [T: ?Sized + zeroize::Zeroize] crate::Dynamic<T>, where [Self: RevealSecret],
+    |s| s.byte_len()
+);
+impl_trace_record!(
+    [T: zeroize::Zeroize + AsRef<[u8]>] InnerSecret<T>, where [],
+    |s| (**s).as_ref().len()
+);
+impl_trace_record!(
+    #[cfg(feature = "alloc")]
+    [] crate::EncodedSecret, where [],
+    |s| s.len()
+);
@@ -50,18 +50,32 @@
 //!
 //! - `&[u8]` / `[u8]` (byte slices)
 //! - `[u8; N]` (fixed-size byte arrays)
+//! - `str` (string slices)
 //! - `Vec<u8>` / `String` (when `alloc` feature is enabled)
+//! - `[T]`, `[T; N]` and `Vec<T>` for the wider unsigned integers `u16`, `u32`,
+//!   `u64`, `u128` and `usize` (e.g. `Fixed<[u32; 8]>` key schedules)
 //!
 //! These cover the most common secret types in cryptographic applications.
+//! Any other type that already implements [`subtle::ConstantTimeEq`] (a secret
+//! struct, a field element) opts in with the [`SubtleConstantTimeEq`] marker.
+//!
+//! # Composing checks
+//!
+//! `ct_eq` returns `bool`, which the caller branches on immediately. When several
+//! checks must all pass (a MAC *and* a header, a key id *and* a tag), use
+//! [`ct_eq_choice`](ConstantTimeEq::ct_eq_choice) and combine the resulting
+//! [`subtle::Choice`] values with `&` / `|`, or fold a list with [`ct_eq_all`].
+//! Convert to `bool` once, at the very end.
 //!
 //! # Length is not constant-time
 //!
-//! For variable-length inputs (`[u8]`, `Vec<u8>`, `String`) the underlying `subtle`
+//! For variable-length inputs (`[u8]`, `str`, `Vec<u8>`, `String`) the underlying `subtle`
 //! impl returns `false` immediately when lengths differ — only the equal-length
 //! comparison is constant-time. Length is generally not secret in real-world use
 //! (key sizes, MAC sizes, and signature sizes are public protocol parameters), so
 //! this is acceptable. **If length itself is sensitive in your threat model, use a
-//! fixed-size type (`[u8; N]` / `Fixed<[u8; N]>`).**
+//! fixed-size type (`[u8; N]` / `Fixed<[u8; N]>`), or [`ct_eq_padded`] for
+//! passwords and tokens of varying length.**
 
 /// Constant-time equality comparison, preventing timing side-channel attacks.
 ///
@@ -74,6 +88,48 @@ pub trait ConstantTimeEq {
     /// The execution time is independent of the actual data values,
     /// preventing timing side-channel attacks.
     fn ct_eq(&self, other: &Self) -> bool;
+
+    /// Like [`ct_eq`](Self::ct_eq), but returns a [`subtle::Choice`] so the
+    /// result can be combined with other checks (`&`, `|`) before any branch.
+    ///
+    /// Every built-in impl overrides this to stay in the `Choice` domain end to
+    /// end. The default converts the result of `ct_eq`, so impls written before
+    /// this method existed keep compiling; override it when you can.
+    #[inline]
+    fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+        subtle::Choice::from(u8::from(self.ct_eq(other)))
+    }
+
+    /// Constant-time inequality: `!ct_eq`.
+    #[inline]
+    fn ct_ne(&self, other: &Self) -> bool {
+        (!self.ct_eq_choice(other)).into()
+    }
+}
+
+/// Folds several comparisons into one [`subtle::Choice`] without short-circuiting.
+///
+/// Every pair is compared even after a mismatch, so the time taken does not reveal
+/// *which* pair differed. Returns `Choice(1)` for an empty list. To combine checks on
+/// values of different types (e.g. a MAC and a header), `&` their
+/// [`ct_eq_choice`](ConstantTimeEq::ct_eq_choice) results directly.
+///
+/// ```rust
+/// use secure_gate::{ct_eq_all, ConstantTimeEq, Fixed};
+///
+/// let tag = Fixed::new([7u8; 16]);
+/// let expected_tag = Fixed::new([7u8; 16]);
+/// let header: &[u8] = b"v1";
+///
+/// let ok = ct_eq_all(&[(&tag, &expected_tag)]) & header.ct_eq_choice(b"v1".as_slice());
+/// assert!(bool::from(ok));
+/// ```
+#[cfg(feature = "ct-eq")]
+#[inline]
+pub fn ct_eq_all<T: ConstantTimeEq + ?Sized>(pairs: &[(&T, &T)]) -> subtle::Choice {
+    pairs.iter().fold(subtle::Choice::from(1), |acc, (a, b)| {
+        acc & a.ct_eq_choice(b)
+    })
 }
 
 #[cfg(feature = "ct-eq")]
@@ -82,7 +138,12 @@ impl ConstantTimeEq for [u8] {
     /// Compares two byte slices in constant time using `subtle`.
     #[inline(always)]
     fn ct_eq(&self, other: &Self) -> bool {
-        subtle::ConstantTimeEq::ct_eq(self, other).into()
+        self.ct_eq_choice(other).into()
+    }
+
+    #[inline(always)]
+    fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(self, other)
     }
 }
 
@@ -94,6 +155,11 @@ impl<const N: usize> ConstantTimeEq for [u8; N] {
     fn ct_eq(&self, other: &Self) -> bool {
         self.as_slice().ct_eq(other.as_slice())
     }
+
+    #[inline(always)]
+    fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+        self.as_slice().ct_eq_choice(other.as_slice())
+    }
 }
 
 #[cfg(all(feature = "ct-eq", feature = "alloc"))]
@@ -104,6 +170,11 @@ impl ConstantTimeEq for alloc::vec::Vec<u8> {
     fn ct_eq(&self, other: &Self) -> bool {
         self.as_slice().ct_eq(other.as_slice())
     }
+
+    #[inline(always)]
+    fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+        self.as_slice().ct_eq_choice(other.as_slice())
+    }
 }
 
 #[cfg(all(feature = "ct-eq", feature = "alloc"))]
@@ -117,4 +188,170 @@ impl ConstantTimeEq for alloc::string::String {
     fn ct_eq(&self, other: &Self) -> bool {
         self.as_bytes().ct_eq(other.as_bytes())
     }
+
+    #[inline(always)]
+    fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+        self.as_bytes().ct_eq_choice(other.as_bytes())
+    }
+}
+
+#[cfg(feature = "ct-eq")]
+/// Constant-time equality for string slices.
+///
+/// Compares raw UTF-8 bytes — callers must normalize Unicode before comparing
+/// if normalization matters for their use case.
+impl ConstantTimeEq for str {
+    /// Compares the UTF-8 byte contents of two `str`s in constant time.
+    #[inline(always)]
+    fn ct_eq(&self, other: &Self) -> bool {
+        self.as_bytes().ct_eq(other.as_bytes())
+    }
+
+    #[inline(always)]
+    fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+        self.as_bytes().ct_eq_choice(other.as_bytes())
+    }
+}
+
+/// Constant-time equality for slices, arrays and vectors of wider unsigned integers.
+/// Each impl delegates to `subtle`'s element-wise slice comparison.
+macro_rules! impl_ct_eq_for_uint_collections {
+    ($($t:ty),+ $(,)?) => {$(
+        impl ConstantTimeEq for [$t] {
+            #[inline(always)]
+            fn ct_eq(&self, other: &Self) -> bool {
+                self.ct_eq_choice(other).into()
+            }
+
+            #[inline(always)]
+            fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+                subtle::ConstantTimeEq::ct_eq(self, other)
+            }
+        }
+
+        impl<const N: usize> ConstantTimeEq for [$t; N] {
+            #[inline(always)]
+            fn ct_eq(&self, other: &Self) -> bool {
+                self.as_slice().ct_eq(other.as_slice())
+            }
+
+            #[inline(always)]
+            fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+                self.as_slice().ct_eq_choice(other.as_slice())
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl ConstantTimeEq for alloc::vec::Vec<$t> {
+            #[inline(always)]
+            fn ct_eq(&self, other: &Self) -> bool {
+                self.as_slice().ct_eq(other.as_slice())
+            }
+
+            #[inline(always)]
+            fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+                self.as_slice().ct_eq_choice(other.as_slice())
+            }
+        }
+    )+};
+}
+
+impl_ct_eq_for_uint_collections!(u16, u32, u64, u128, usize);
+
+/// Opt-in marker: derive [`ConstantTimeEq`] from an existing
+/// [`subtle::ConstantTimeEq`] impl.
+///
+/// A blanket impl over every `subtle::ConstantTimeEq` type would collide with the
+/// impls above under Rust's coherence rules, so the bridge is opt-in — one empty
+/// impl per type, in the same spirit as [`OpaqueSecret`](crate::OpaqueSecret).
+/// `ct_eq_choice` then forwards straight to `subtle` without a `bool` round trip.
+///
+/// ```rust
+/// use secure_gate::{ConstantTimeEq, Fixed, OpaqueSecret, SubtleConstantTimeEq};
+///
+/// #[derive(zeroize::Zeroize)]
+/// struct SessionKeys {
+///     tx: [u8; 32],
+///     rx: [u8; 32],
+/// }
+///
+/// impl subtle::ConstantTimeEq for SessionKeys {
+///     fn ct_eq(&self, other: &Self) -> subtle::Choice {
+///         self.tx.ct_eq_choice(&other.tx) & self.rx.ct_eq_choice(&other.rx)
+///     }
+/// }
+/// impl SubtleConstantTimeEq for SessionKeys {}
+/// impl OpaqueSecret for SessionKeys {}
+///
+/// let a = Fixed::new(SessionKeys { tx: [1; 32], rx: [2; 32] });
+/// let b = Fixed::new(SessionKeys { tx: [1; 32], rx: [2; 32] });
+/// assert!(a.ct_eq(&b));
+/// ```
+#[cfg(feature = "ct-eq")]
+pub trait SubtleConstantTimeEq: subtle::ConstantTimeEq {}
+
+#[cfg(feature = "ct-eq")]
+impl<T: SubtleConstantTimeEq + ?Sized> ConstantTimeEq for T {
+    #[inline(always)]
+    fn ct_eq(&self, other: &Self) -> bool {
+        subtle::ConstantTimeEq::ct_eq(self, other).into()
+    }
+
+    #[inline(always)]
+    fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(self, other)
+    }
+}
+
+/// Length-hiding constant-time equality for variable-length secrets such as
+/// passwords and bearer tokens.
+///
+/// Unlike [`ConstantTimeEq::ct_eq`] on `[u8]`, this does not return early when the
+/// lengths differ. It always performs exactly `max_len` iterations, so the
+/// iteration count depends only on `max_len`, never on either input's length.
+///
+/// It does not hide length from an observer of memory access patterns: once an
+/// input is exhausted the loop re-reads its first byte instead of byte `i`, and
+/// an empty input is swapped for a one-byte stand-in with an ordinary branch.
+///
+/// Returns `Choice(1)` only if `a` and `b` have the same length, that length is at
+/// most `max_len`, and the contents are equal. An input longer than `max_len` never
+/// compares equal — pick `max_len` as the longest value your protocol accepts.
+///
+/// ```rust
+/// use secure_gate::ct_eq_padded;
+///
+/// assert!(bool::from(ct_eq_padded(b"hunter2", b"hunter2", 64)));
+/// assert!(!bool::from(ct_eq_padded(b"hunter2", b"hunter22", 64)));
+/// assert!(!bool::from(ct_eq_padded(b"hunter2", b"hunter2", 4))); // over max_len
+/// ```
+#[cfg(feature = "ct-eq")]
+pub fn ct_eq_padded(a: &[u8], b: &[u8], max_len: usize) -> subtle::Choice {
+    use subtle::{ConstantTimeEq as _, ConstantTimeGreater};
+
+    let (a_len, b_len, max) = (a.len() as u64, b.len() as u64, max_len as u64);
+    let mut ok = a_len.ct_eq(&b_len) & !a_len.ct_gt(&max);
+    // Exhausted inputs keep reading a fixed in-bounds byte. An empty input has
+    // none, so it gets a stand-in; this branch reveals only emptiness.
+    let a_src: &[u8] = if a.is_empty() { &[0] } else { a };
+    let b_src: &[u8] = if b.is_empty() { &[0] } else { b };
+    let mut diff = 0u8;
+    for i in 0..max_len {
+        let x = padded_byte(a_src, a_len, i);
+        let y = padded_byte(b_src, b_len, i);
+        diff |= x ^ y;
+    }
+    ok &= diff.ct_eq(&0);
+    ok
+}
+
+/// Byte `i` of `src` if `i < len`, else `0` — reading `src[0]` in that case so the
+/// index stays in bounds.
+#[cfg(feature = "ct-eq")]
+#[inline(always)]
+fn padded_byte(src: &[u8], len: u64, i: usize) -> u8 {
+    use subtle::{ConditionallySelectable, ConstantTimeGreater};
+    let live = len.ct_gt(&(i as u64));
+    let idx = u64::conditional_select(&0, &(i as u64), live) as usize;
+    u8::conditional_select(&0, &src[idx], live)
 }
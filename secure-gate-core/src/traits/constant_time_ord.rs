@@ -0,0 +1,163 @@
+//! Constant-time ordering comparison for fixed-width secret integers.
+//!
+//! > **Import path:** `use secure_gate::{ByteOrder, ConstantTimeOrd};`
+//!
+//! Range checks, counters and nonce-replay windows compare secret byte strings as
+//! unsigned integers. A naive `<` on the exposed bytes stops at the first
+//! differing byte, leaking its position through execution time. [`ConstantTimeOrd`]
+//! visits every byte of both operands and builds the result with `subtle`'s
+//! constant-time primitives, returning [`subtle::Choice`] values that can be
+//! combined with other checks before any branch.
+//!
+//! Requires the `ct-eq` feature to be enabled.
+//!
+//! # Byte order
+//!
+//! The bytes are interpreted as one unsigned integer. Pass
+//! [`ByteOrder::BigEndian`] when the most significant byte comes first (network
+//! order, and the same result as lexicographic slice ordering), or
+//! [`ByteOrder::LittleEndian`] when the least significant byte comes first.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use secure_gate::{ByteOrder, ConstantTimeOrd, Fixed};
+//!
+//! let counter = Fixed::new([0x00u8, 0x10]);
+//! let window_end = Fixed::new([0x01u8, 0x00]);
+//!
+//! // 0x0010 < 0x0100 big-endian...
+//! assert!(bool::from(counter.ct_lt(&window_end, ByteOrder::BigEndian)));
+//! // ...but 0x1000 > 0x0001 little-endian.
+//! assert!(bool::from(counter.ct_gt(&window_end, ByteOrder::LittleEndian)));
+//! ```
+//!
+//! # Length is not constant-time
+//!
+//! Both operands must have the same length. For `[u8; N]` and `Fixed<[u8; N]>` the
+//! type system enforces it, so only they implement the trait. `Dynamic<Vec<u8>>`
+//! has an inherent [`ct_cmp`](crate::Dynamic::ct_cmp) instead that returns `None`
+//! on a mismatch: length is public, so the check may branch.
+
+use subtle::{Choice, ConstantTimeGreater, ConstantTimeLess};
+
+/// How a byte string is read as an unsigned integer by [`ConstantTimeOrd`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ByteOrder {
+    /// Most significant byte first (network order). Matches lexicographic order.
+    BigEndian,
+    /// Least significant byte first.
+    LittleEndian,
+}
+
+/// Result of [`ConstantTimeOrd::ct_cmp`]: the ordering as a pair of
+/// [`Choice`] flags, so it can be combined without branching.
+///
+/// At most one of `is_lt` / `is_gt` is set; neither means equal. Converting to
+/// [`core::cmp::Ordering`] with `From` branches on the result — do it only once
+/// the decision may become public.
+#[derive(Clone, Copy, Debug)]
+pub struct CtOrdering {
+    lt: Choice,
+    gt: Choice,
+}
+
+impl CtOrdering {
+    /// `Choice(1)` if `self < other`.
+    #[inline]
+    pub fn is_lt(&self) -> Choice {
+        self.lt
+    }
+
+    /// `Choice(1)` if `self > other`.
+    #[inline]
+    pub fn is_gt(&self) -> Choice {
+        self.gt
+    }
+
+    /// `Choice(1)` if `self == other`.
+    #[inline]
+    pub fn is_eq(&self) -> Choice {
+        !(self.lt | self.gt)
+    }
+
+    /// `Choice(1)` if `self <= other`.
+    #[inline]
+    pub fn is_le(&self) -> Choice {
+        !self.gt
+    }
+
+    /// `Choice(1)` if `self >= other`.
+    #[inline]
+    pub fn is_ge(&self) -> Choice {
+        !self.lt
+    }
+}
+
+impl From<CtOrdering> for core::cmp::Ordering {
+    fn from(ord: CtOrdering) -> Self {
+        if bool::from(ord.lt) {
+            core::cmp::Ordering::Less
+        } else if bool::from(ord.gt) {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }
+}
+
+/// Constant-time unsigned-integer ordering over byte strings of equal length.
+///
+/// Requires the `ct-eq` feature. Backed by the [`subtle`] crate internally.
+pub trait ConstantTimeOrd {
+    /// Compares `self` with `other` as unsigned integers in the given byte order.
+    ///
+    /// Every byte of both operands is read regardless of where they first differ.
+    fn ct_cmp(&self, other: &Self, order: ByteOrder) -> CtOrdering;
+
+    /// `Choice(1)` if `self < other`.
+    #[inline]
+    fn ct_lt(&self, other: &Self, order: ByteOrder) -> Choice {
+        self.ct_cmp(other, order).is_lt()
+    }
+
+    /// `Choice(1)` if `self > other`.
+    #[inline]
+    fn ct_gt(&self, other: &Self, order: ByteOrder) -> Choice {
+        self.ct_cmp(other, order).is_gt()
+    }
+}
+
+/// Shared kernel: walks from the least to the most significant byte, letting each
+/// more significant byte override the running result unless it is equal.
+///
+/// Callers guarantee equal lengths (by type, or by checking first).
+#[inline]
+pub(crate) fn ct_cmp_bytes(a: &[u8], b: &[u8], order: ByteOrder) -> CtOrdering {
+    debug_assert_eq!(a.len(), b.len(), "ct_cmp: length mismatch");
+    let mut ord = CtOrdering {
+        lt: Choice::from(0),
+        gt: Choice::from(0),
+    };
+    let mut step = |x: &u8, y: &u8| {
+        let lt = x.ct_lt(y);
+        let gt = x.ct_gt(y);
+        let eq = !(lt | gt);
+        ord.lt = lt | (eq & ord.lt);
+        ord.gt = gt | (eq & ord.gt);
+    };
+    match order {
+        // `order` is a public parameter, so branching on it is fine.
+        ByteOrder::BigEndian => a.iter().zip(b).rev().for_each(|(x, y)| step(x, y)),
+        ByteOrder::LittleEndian => a.iter().zip(b).for_each(|(x, y)| step(x, y)),
+    }
+    ord
+}
+
+/// Constant-time ordering for fixed-size byte arrays.
+impl<const N: usize> ConstantTimeOrd for [u8; N] {
+    #[inline]
+    fn ct_cmp(&self, other: &Self, order: ByteOrder) -> CtOrdering {
+        ct_cmp_bytes(self, other, order)
+    }
+}
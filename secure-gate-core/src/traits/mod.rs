@@ -15,8 +15,11 @@
 //! |------------------------|----------------------------------------------|--------------------------|-----------------------------------------------------------------------|
 //! | [`RevealSecret`]       | Read-only scoped / direct access + metadata  | Always available         | Preferred: `with_secret` (scoped); escape hatch: `expose_secret`      |
 //! | [`RevealSecretMut`]    | Mutable scoped / direct access               | Always available         | Same preference: `with_secret_mut` over `expose_secret_mut`           |
-//! | [`SentinelValue`]      | Inert placeholder left by `into_inner`       | Always available         | Implemented for `[T; N]` (any `N`), `String`, `Vec<T>`                |
+//! | [`TryRevealSecret`]    | Fallible scoped access for policy wrappers   | Always available         | `try_with_secret(&mut self)`; `Budgeted<S>`, `Expiring<S, C>` (std)  |
+//! | [`SentinelValue`]      | Inert placeholder left by `into_inner`       | Always available         | Implemented for `[T; N]` (any `N`), `String`, `Vec<T>`, integers      |
+//! | [`OpaqueSecret`]       | Opt-in marker: reveal a composite `T` whole  | Always available         | Unlocks `RevealSecret` on `Fixed<T>`/`Dynamic<T>`; `len() == 1`       |
 //! | [`ConstantTimeEq`]     | Deterministic constant-time equality         | `ct-eq`                  | Timing-attack resistant byte comparison                               |
+//! | [`ConstantTimeOrd`]    | Constant-time `<` / `>` on byte integers     | `ct-eq`                  | Big- or little-endian via [`ByteOrder`]; returns `Choice`             |
 //! | [`CloneableSecret`]    | Opt-in marker for safe cloning               | `cloneable`              | Requires explicit impl on inner type; zeroize preserved. See [`SECURITY.md`](https://github.com/Slurp9187/secure-gate/blob/main/SECURITY.md) for opt-in risk details. |
 //! | [`SerializableSecret`] | Opt-in marker for Serde serialization        | `serde-serialize`        | Serialization exposes secret — use with extreme caution. See [`SECURITY.md`](https://github.com/Slurp9187/secure-gate/blob/main/SECURITY.md) for opt-in risk details. |
 //! | [`SecureEncoding`]     | Marker + blanket impl for encoding traits    | Any `encoding-*`         | Enables `ToHex`, `ToBase64Url`, `ToBech32`, `ToBech32m`               |
@@ -35,7 +38,7 @@
 //!
 //! Some traits are only available when their corresponding Cargo features are enabled:
 //!
-//! - `ct-eq`          → [`ConstantTimeEq`]
+//! - `ct-eq`          → [`ConstantTimeEq`], [`ConstantTimeOrd`]
 //! - `cloneable`      → [`CloneableSecret`]
 //! - `serde-serialize`→ [`SerializableSecret`]
 //! - `encoding-*`     → [`SecureEncoding`], [`SecureDecoding`], and per-format traits
@@ -56,13 +59,24 @@ pub use reveal_secret::RevealSecret;
 pub mod reveal_secret_mut;
 pub use reveal_secret_mut::RevealSecretMut;
 
+pub mod try_reveal_secret;
+pub use try_reveal_secret::TryRevealSecret;
+
 pub mod sentinel_value;
 pub use sentinel_value::SentinelValue;
 
+pub mod opaque_secret;
+pub use opaque_secret::OpaqueSecret;
+
 #[cfg(feature = "ct-eq")]
 pub mod constant_time_eq;
 #[cfg(feature = "ct-eq")]
-pub use constant_time_eq::ConstantTimeEq;
+pub use constant_time_eq::{ConstantTimeEq, SubtleConstantTimeEq, ct_eq_all, ct_eq_padded};
+
+#[cfg(feature = "ct-eq")]
+pub mod constant_time_ord;
+#[cfg(feature = "ct-eq")]
+pub use constant_time_ord::{ByteOrder, ConstantTimeOrd, CtOrdering};
 
 pub mod decoding;
 pub mod encoding;
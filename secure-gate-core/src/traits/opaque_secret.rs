@@ -0,0 +1,80 @@
+//! Opt-in marker trait for revealing composite secrets as a single value.
+//!
+//! > **Import path:** `use secure_gate::OpaqueSecret;`
+//!
+//! [`RevealSecret`](crate::RevealSecret) is implemented for the container inner
+//! types the crate knows how to measure (`String`, `Vec<T>`, `[T; N]`, `[T]`,
+//! `str`). A struct holding several keys and a counter is none of those, so
+//! `Dynamic<MyKeys>` could be constructed but never read.
+//!
+//! Implementing `OpaqueSecret` on the inner type unlocks
+//! [`RevealSecret`](crate::RevealSecret) / [`RevealSecretMut`](crate::RevealSecretMut)
+//! for both `Fixed<T>` and `Dynamic<T>`, treating the value as one opaque unit:
+//!
+//! | Method | Result for `T: OpaqueSecret` |
+//! |---|---|
+//! | `len()` | `1` — a single secret value |
+//! | `byte_len()` | `size_of::<T>()` — the inline size; heap data owned by `T` is not counted |
+//! | `is_empty()` | always `false` |
+//! | `into_inner()` | available when `T: SentinelValue` |
+//!
+//! The primitive integer types (`u8`…`u128`, `usize`, `i8`…`i128`, `isize`)
+//! implement `OpaqueSecret` and [`SentinelValue`](crate::SentinelValue) out of the
+//! box, so `Fixed<u64>` counters, seeds and PINs are fully usable.
+//!
+//! # Why a marker instead of a blanket impl
+//!
+//! A blanket `impl<T: Zeroize> RevealSecret for Dynamic<T>` would overlap with the
+//! dedicated `String` / `Vec<T>` / `[T; N]` impls, which report element counts
+//! rather than `1`. The marker keeps the two families disjoint: the crate never
+//! implements it for container types, and the orphan rule prevents downstream
+//! crates from doing so.
+//!
+//! Cloning still requires [`CloneableSecret`](crate::CloneableSecret) and
+//! serialization still requires [`SerializableSecret`](crate::SerializableSecret) —
+//! `OpaqueSecret` only grants *access*, not any new way to copy the secret.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use secure_gate::{Dynamic, OpaqueSecret, RevealSecret, RevealSecretMut};
+//! use zeroize::Zeroize;
+//!
+//! #[derive(Zeroize)]
+//! struct SessionKeys {
+//!     enc: [u8; 32],
+//!     mac: [u8; 32],
+//!     counter: u64,
+//! }
+//!
+//! impl OpaqueSecret for SessionKeys {}
+//!
+//! let mut keys = Dynamic::new(SessionKeys { enc: [1; 32], mac: [2; 32], counter: 0 });
+//! keys.with_secret_mut(|k| k.counter += 1);
+//! assert_eq!(keys.with_secret(|k| k.counter), 1);
+//! assert_eq!(keys.len(), 1);
+//! # }
+//! ```
+
+/// Marker trait that opts a scalar or composite inner type into
+/// [`RevealSecret`](crate::RevealSecret) and [`RevealSecretMut`](crate::RevealSecretMut)
+/// on [`Fixed<T>`](crate::Fixed) and [`Dynamic<T>`](crate::Dynamic).
+///
+/// No methods. The value is revealed as a whole: `len()` is `1` and `byte_len()`
+/// is `size_of::<T>()`. Implement [`SentinelValue`](crate::SentinelValue) as well to
+/// make `into_inner` callable.
+pub trait OpaqueSecret: zeroize::Zeroize {}
+
+macro_rules! impl_opaque {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl OpaqueSecret for $t {}
+        )*
+    };
+}
+
+impl_opaque!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
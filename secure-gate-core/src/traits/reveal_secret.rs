@@ -36,7 +36,7 @@
 //! # Security Model
 //!
 //! - **Core wrappers** (`Fixed<T>`, `Dynamic<T>`) implement both traits → full access.
-//! - **Read-only wrappers** (encoding wrappers, random types) implement only `RevealSecret` → mutation prevented.
+//! - **Read-only wrappers** ([`Frozen<S>`](crate::Frozen), borrowed [`SecretRef`](crate::SecretRef) views) implement only `RevealSecret` → mutation prevented.
 //! - **Zero-cost** — all methods are `#[inline(always)]` where possible.
 //! - **Scoped access preferred** — `with_secret` / `with_secret_mut` limit borrow lifetime, reducing leak risk.
 //! - **Direct exposure** (`expose_secret` / `expose_secret_mut`) is provided for legitimate needs (FFI, third-party APIs), but marked as an escape hatch.
@@ -231,10 +231,16 @@ pub trait RevealSecret {
     /// where no safe placeholder value exists), `into_inner` is not callable — use
     /// `with_secret` or `expose_secret` instead.
     ///
-    /// The three concrete implementations in this crate all satisfy the bounds:
+    /// The concrete implementations in this crate satisfy the bounds as follows:
     /// - `Fixed<[u8; N]>` — `[u8; N]: SentinelValue + Zeroize` for **any** `N` ✓
     /// - `Dynamic<String>` — `String: SentinelValue + Zeroize` ✓
     /// - `Dynamic<Vec<T>>` — `Vec<T>: SentinelValue + Zeroize` ✓
+    /// - `Dynamic<[T; N]>` — same bounds as `Fixed<[T; N]>` ✓
+    /// - `Dynamic<T>` for `T: OpaqueSecret` — callable when `T: SentinelValue`
+    ///
+    /// The unsized `Dynamic<[T]>` and `Dynamic<str>` cannot satisfy `Sized`; they
+    /// provide an inherent `into_inner` returning `InnerSecret<Box<[T]>>` /
+    /// `InnerSecret<Box<str>>` instead, which takes precedence at call sites.
     ///
     /// # Debug Behavior
     ///
@@ -5,8 +5,9 @@
 //! This module defines the [`RevealSecretMut`] trait, which extends
 //! [`RevealSecret`] to provide controlled mutable access to secrets.
 //!
-//! Only the core secret wrappers (`Fixed<T>` and `Dynamic<T>`) implement this
-//! trait. Read-only wrappers (e.g., encoding types, random generators) deliberately
+//! Only the core secret wrappers (`Fixed<T>` and `Dynamic<T>`) and mutable
+//! [`SecretMut`](crate::SecretMut) views implement this trait. Read-only wrappers
+//! ([`Frozen<S>`](crate::Frozen), [`SecretRef`](crate::SecretRef)) deliberately
 //! **do not** implement it to prevent accidental mutation.
 //!
 //! # Security Model
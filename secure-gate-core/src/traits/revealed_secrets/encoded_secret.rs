@@ -15,7 +15,7 @@
 //!
 //! # `Display` is intentionally transparent
 //!
-//! Unlike `Debug` (which always prints `[REDACTED]`), `Display` (`{}`) outputs the
+//! Unlike `Debug` (always a `[REDACTED ...]` marker), `Display` (`{}`) outputs the
 //! encoded secret content verbatim — needed so the value can be written to a sink,
 //! used in format strings, or sent over a wire. **Do not log `EncodedSecret` values
 //! with `{}` in production.** Prefer `{:?}` for diagnostic output to avoid accidental
@@ -72,10 +72,12 @@ impl core::ops::Deref for EncodedSecret {
     }
 }
 
+/// Prints a marker under [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT),
+/// using the encoded length.
 #[cfg(feature = "alloc")]
 impl core::fmt::Debug for EncodedSecret {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("[REDACTED]")
+        core::fmt::Display::fmt(&self.redacted(crate::RedactionPolicy::DEFAULT), f)
     }
 }
 
@@ -2,7 +2,9 @@
 ///
 /// `InnerSecret<T>` preserves the zeroization contract by wrapping
 /// [`zeroize::Zeroizing<T>`], while restoring a strict redaction policy for `Debug`:
-/// formatting this type always prints `[REDACTED]`, regardless of `T`.
+/// formatting this type prints the
+/// [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT) marker without a
+/// length hint (`[REDACTED]`), regardless of `T`.
 ///
 /// This is **not** a secret wrapper like [`Fixed`](crate::Fixed) or
 /// [`Dynamic`](crate::Dynamic) — it is the owned extraction result from
@@ -49,9 +51,13 @@ impl<T: zeroize::Zeroize> InnerSecret<T> {
     }
 }
 
+/// Prints the [`RedactionPolicy::DEFAULT`](crate::RedactionPolicy::DEFAULT) marker
+/// without a length hint: an arbitrary `T` has no byte length to report. Use
+/// [`redacted`](InnerSecret::redacted) for a length-aware marker.
 impl<T: zeroize::Zeroize> core::fmt::Debug for InnerSecret<T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("[REDACTED]")
+        let marker = crate::RedactionPolicy::DEFAULT.redact_unknown_len();
+        core::fmt::Display::fmt(&marker, f)
     }
 }
 
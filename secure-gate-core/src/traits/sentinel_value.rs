@@ -21,13 +21,14 @@
 /// A sentinel must be cheap to construct and must never contain secret
 /// material — it exists only so the wrapper's `Drop` impl zeroizes a harmless
 /// value instead of the already-moved secret. The provided implementations
-/// return an all-default array, an empty `String`, or an empty `Vec`.
+/// return an all-default array, an empty `String`, an empty `Vec`, or `0`.
 ///
 /// # Provided implementations
 ///
 /// | Type | Sentinel | Notes |
 /// |------|----------|-------|
 /// | `[T; N]` where `T: Default` | `[T::default(); N]` | Any `N` — not limited to 32 like `Default` |
+/// | Primitive integers (`u8`…`u128`, `usize`, `i8`…`i128`, `isize`) | `0` | For `Fixed<u64>` counters, seeds, PINs |
 /// | `String` | `String::new()` | Requires `alloc`; no allocation |
 /// | `Vec<T>` | `Vec::new()` | Requires `alloc`; no allocation |
 ///
@@ -63,3 +64,20 @@ impl<T> SentinelValue for alloc::vec::Vec<T> {
         alloc::vec::Vec::new()
     }
 }
+
+macro_rules! impl_zero_sentinel {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl SentinelValue for $t {
+                #[inline(always)]
+                fn sentinel_value() -> Self {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_zero_sentinel!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
@@ -0,0 +1,52 @@
+//! Fallible secret revelation for access-limited wrappers.
+//!
+//! > **Import path:** `use secure_gate::TryRevealSecret;`
+//!
+//! [`RevealSecret::with_secret`](crate::RevealSecret::with_secret) cannot fail, so
+//! it cannot express a secret that stops being readable — one whose reveal budget
+//! is spent, for instance. Wrappers with such a policy implement
+//! [`TryRevealSecret`] **instead of** `RevealSecret`, so there is no infallible
+//! path around the policy.
+//!
+//! `try_with_secret` takes `&mut self`: a reveal may update the wrapper's state
+//! (decrement a counter, zeroize the secret once it is no longer readable).
+//!
+//! | Implementor | Fails with | Feature |
+//! |---|---|---|
+//! | [`Budgeted<S>`](crate::Budgeted) | [`RevealError::BudgetExhausted`](crate::RevealError::BudgetExhausted) | Always |
+//! | `Expiring<S, C>` | [`RevealError::Expired`](crate::RevealError::Expired) | `std` |
+//!
+//! # Example
+//!
+//! ```rust
+//! use secure_gate::{Budgeted, Fixed, RevealError, TryRevealSecret};
+//!
+//! fn consume<S: TryRevealSecret<Inner = [u8; 16]>>(s: &mut S) -> Result<u8, RevealError> {
+//!     s.try_with_secret(|code| code[0])
+//! }
+//!
+//! let mut code = Budgeted::one_shot(Fixed::new([9u8; 16]));
+//! assert_eq!(consume(&mut code), Ok(9));
+//! assert_eq!(consume(&mut code), Err(RevealError::BudgetExhausted));
+//! ```
+
+/// Fallible, scoped read access to a secret whose availability is limited by a
+/// policy (access budget, expiry, ...).
+///
+/// There is deliberately no `try_expose_secret`: a long-lived reference would let
+/// the caller keep reading after the policy says the secret is gone.
+pub trait TryRevealSecret {
+    /// The inner secret type being revealed.
+    type Inner: ?Sized;
+
+    /// Runs `f` with a scoped borrow of the secret if the wrapper's policy still
+    /// allows it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RevealError`](crate::RevealError) describing why the secret is no
+    /// longer readable; `f` is not called.
+    fn try_with_secret<F, R>(&mut self, f: F) -> Result<R, crate::RevealError>
+    where
+        F: FnOnce(&Self::Inner) -> R;
+}
@@ -0,0 +1,119 @@
+//! Runtime access-audit hook tests (`audit` feature).
+//!
+//! The audit hook is global process state, so this is a separate integration test
+//! binary with one aggregate `#[test]` (`audit_hook_reports_every_tier`). Splitting
+//! it into several `#[test]` functions would let parallel tests interleave events.
+
+#![cfg(feature = "audit")]
+
+use secure_gate::{AccessTier, AuditEvent, Fixed, RevealSecret, RevealSecretMut};
+use std::sync::Mutex;
+
+struct Seen {
+    tier: AccessTier,
+    mutable: bool,
+    file: &'static str,
+    line: u32,
+    type_name: &'static str,
+}
+
+static EVENTS: Mutex<Vec<Seen>> = Mutex::new(Vec::new());
+
+fn record(event: &AuditEvent) {
+    EVENTS.lock().unwrap().push(Seen {
+        tier: event.tier,
+        mutable: event.mutable,
+        file: event.location.file(),
+        line: event.location.line(),
+        type_name: event.type_name,
+    });
+}
+
+fn take_events() -> Vec<Seen> {
+    core::mem::take(&mut *EVENTS.lock().unwrap())
+}
+
+#[test]
+fn audit_hook_reports_every_tier() {
+    secure_gate::set_audit_hook(record);
+
+    // Tier / mutability mapping and caller location.
+    let mut key = Fixed::new([0u8; 32]);
+    let line = line!() + 1;
+    key.with_secret(|_| ());
+    key.with_secret_mut(|k| k[0] = 1);
+    let _ = key.expose_secret();
+    let _ = key.expose_secret_mut();
+    let _ = key.into_inner();
+
+    let events = take_events();
+    let got: Vec<_> = events.iter().map(|e| (e.tier, e.mutable)).collect();
+    assert_eq!(
+        got,
+        [
+            (AccessTier::Scoped, false),
+            (AccessTier::Scoped, true),
+            (AccessTier::Direct, false),
+            (AccessTier::Direct, true),
+            (AccessTier::Owned, false),
+        ]
+    );
+    for (i, e) in events.iter().enumerate() {
+        assert_eq!(e.file, file!(), "location must be the caller's file");
+        assert_eq!(
+            e.line,
+            line + i as u32,
+            "location must be the caller's line"
+        );
+        assert!(e.type_name.contains("Fixed<[u8; 32]>"), "{}", e.type_name);
+    }
+
+    // Crate-internal reveal paths report the application's call site.
+    let frozen = Fixed::new(7u64).freeze();
+    let line = line!() + 1;
+    frozen.with_secret(|_| ());
+    let mapped: Fixed<u64> = Fixed::new(1u64).map_secret(|v| v + 1);
+    let events = take_events();
+    assert_eq!(events.len(), 2);
+    assert_eq!((events[0].file, events[0].line), (file!(), line));
+    assert_eq!((events[1].file, events[1].line), (file!(), line + 1));
+
+    // Budgeted forwards the location too.
+    {
+        use secure_gate::{Budgeted, TryRevealSecret};
+        let mut token = Budgeted::one_shot(Fixed::new([1u8; 4]));
+        let line = line!() + 1;
+        token.try_with_secret(|_| ()).unwrap();
+        let events = take_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].line, line);
+    }
+
+    // Metadata and constant-time comparison are not reveals.
+    assert_eq!(mapped.len(), 1);
+    assert!(!mapped.is_empty());
+    #[cfg(feature = "ct-eq")]
+    {
+        use secure_gate::ConstantTimeEq;
+        assert!(Fixed::new([1u8; 4]).ct_eq(&Fixed::new([1u8; 4])));
+    }
+    assert!(take_events().is_empty());
+
+    // Heap wrappers and borrowed views.
+    let pw = secure_gate::Dynamic::<String>::new("hunter2".to_string());
+    pw.with_secret(|_| ());
+    pw.slice(..3).with_secret(|_| ());
+    let events = take_events();
+    assert_eq!(events.len(), 2);
+    assert!(
+        events[0]
+            .type_name
+            .contains("Dynamic<alloc::string::String>")
+    );
+    assert!(events[1].type_name.contains("SecretRef<str>"));
+
+    // After clearing, nothing is reported.
+    secure_gate::clear_audit_hook();
+    let _ = pw.expose_secret();
+    assert!(take_events().is_empty());
+}
@@ -13,7 +13,7 @@ pub use secure_gate::{RevealSecret, RevealSecretMut};
 
 /// Asserts that the `Debug` output is exactly `[REDACTED]` in both normal and
 /// alternate (`{:#?}`) format — the canonical security invariant for all secret
-/// wrapper types.
+/// wrapper types whose `Debug` reports no length.
 // Called only by serde_suite (gated on serde-serialize + serde-deserialize).
 // Under minimal feature sets those call sites are compiled out, so suppress
 // the dead_code lint that would otherwise appear.
@@ -22,3 +22,16 @@ pub fn assert_redacted_debug(value: &impl core::fmt::Debug) {
     assert_eq!(format!("{value:?}"), "[REDACTED]");
     assert_eq!(format!("{value:#?}"), "[REDACTED]");
 }
+
+/// Like [`assert_redacted_debug`], for wrappers whose `Debug` reports a length:
+/// the expected output is the
+/// [`RedactionPolicy::DEFAULT`](secure_gate::RedactionPolicy::DEFAULT) marker for
+/// `byte_len` bytes (`[REDACTED]` unless `redact-length` is on).
+#[allow(dead_code)]
+pub fn assert_redacted_debug_len(value: &impl core::fmt::Debug, byte_len: usize) {
+    let expected = secure_gate::RedactionPolicy::DEFAULT
+        .redact(byte_len)
+        .to_string();
+    assert_eq!(format!("{value:?}"), expected);
+    assert_eq!(format!("{value:#?}"), expected);
+}
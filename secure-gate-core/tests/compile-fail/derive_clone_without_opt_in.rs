@@ -0,0 +1,15 @@
+// This should fail to compile because the derived struct did not opt into
+// CloneableSecret with `#[secret(cloneable)]`. Deriving `Clone` on the inner type
+// is not enough: cloning a wrapper must stay a deliberate, visible decision.
+use secure_gate::{Dynamic, SecretStruct};
+
+#[derive(Clone, SecretStruct)]
+struct Keys {
+    enc: [u8; 32],
+}
+
+fn main() {
+    let a = Dynamic::new(Keys { enc: [0; 32] });
+    // Fails: Keys does not implement CloneableSecret.
+    let _b = a.clone();
+}
@@ -0,0 +1,13 @@
+// This should fail to compile: a field-level `ct_eq` only adds skipped metadata to
+// a derived ConstantTimeEq, so it is meaningless without `#[secret(ct_eq)]` on the
+// struct itself.
+use secure_gate::SecretStruct;
+
+#[derive(SecretStruct)]
+struct Keys {
+    enc: [u8; 32],
+    #[secret(skip, ct_eq)]
+    id: [u8; 4],
+}
+
+fn main() {}
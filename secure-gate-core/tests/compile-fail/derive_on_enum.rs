@@ -0,0 +1,11 @@
+// This should fail to compile: SecretStruct only supports structs. An enum's
+// variants have no single set of fields to zeroize or compare.
+use secure_gate::SecretStruct;
+
+#[derive(SecretStruct)]
+enum Key {
+    A([u8; 32]),
+    B([u8; 16]),
+}
+
+fn main() {}
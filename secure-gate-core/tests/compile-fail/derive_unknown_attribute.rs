@@ -0,0 +1,13 @@
+// This should fail to compile: a misspelled `#[secret(...)]` option must be an
+// error, never silently ignored — `skipp` would otherwise leave metadata redacted
+// and, worse, a misspelled opt-out would be treated as secret or vice versa.
+use secure_gate::SecretStruct;
+
+#[derive(SecretStruct)]
+struct Keys {
+    enc: [u8; 32],
+    #[secret(skipp)]
+    id: u32,
+}
+
+fn main() {}
@@ -2,6 +2,7 @@ use secure_gate::Dynamic;
 
 fn main() {
     let secret: Dynamic<String> = Dynamic::new(String::from("not_bytes"));
-    // Dynamic<String> must NOT have encoding methods — only Dynamic<Vec<u8>> does.
+    // Dynamic<String> must NOT have encoding methods — only the byte containers
+    // (Dynamic<Vec<u8>>, Dynamic<[u8]>) do.
     let _ = secret.to_hex();
 }
@@ -0,0 +1,13 @@
+use std::io::Write;
+
+use secure_gate::{Dynamic, RevealSecretMut};
+
+fn main() {
+    let mut key = Dynamic::<Vec<u8>>::with_fixed_capacity(4);
+    key.secure_push(1);
+    key.write_all(&[0u8; 8]).unwrap();
+    key.with_secret_mut(|v| v.push(1));
+
+    let mut pw = Dynamic::<String>::with_fixed_capacity(4);
+    pw.secure_push_str("too long");
+}
@@ -0,0 +1,6 @@
+use secure_gate::{Fixed, RevealSecretMut};
+
+fn main() {
+    let mut root = Fixed::new([0x42u8; 32]).freeze();
+    root.with_secret_mut(|k| k[0] = 0);
+}
@@ -33,3 +33,41 @@ fn dynamic_string_no_hex_compile_fail() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/compile-fail/dynamic_string_no_hex.rs");
 }
+
+// Compile-fail test: Frozen<S> must never implement RevealSecretMut.
+#[cfg(not(miri))]
+#[test]
+fn frozen_no_mutation_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile-fail/frozen_no_mutation.rs");
+}
+
+// Compile-fail test: FixedCapacity<T> must expose no growing write — no
+// `secure_*` mutators, no `io::Write`, no `RevealSecretMut`.
+#[cfg(feature = "std")]
+#[cfg(not(miri))]
+#[test]
+fn fixed_capacity_no_growth_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile-fail/fixed_capacity_no_growth.rs");
+}
+
+// Compile-fail tests for #[derive(SecretStruct)]: non-struct input, unknown or
+// misplaced `#[secret(...)]` options, and cloning without the explicit opt-in.
+#[cfg(feature = "derive")]
+#[cfg(not(miri))]
+#[test]
+fn derive_secret_struct_misuse_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile-fail/derive_on_enum.rs");
+    t.compile_fail("tests/compile-fail/derive_unknown_attribute.rs");
+    t.compile_fail("tests/compile-fail/derive_field_ct_eq_without_struct.rs");
+}
+
+#[cfg(all(feature = "derive", feature = "cloneable", feature = "alloc"))]
+#[cfg(not(miri))]
+#[test]
+fn derive_clone_without_opt_in_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile-fail/derive_clone_without_opt_in.rs");
+}
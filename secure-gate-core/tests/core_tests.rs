@@ -59,10 +59,17 @@ fn debug_is_redacted() {
     let key = Fixed::new([0u8; 32]);
     let pw = Dynamic::<String>::new("hunter2".to_string());
 
-    assert_eq!(format!("{key:?}"), "[REDACTED]");
+    // `[REDACTED]`, or `[REDACTED; N bytes]` under `redact-length`.
+    let marker = |len| {
+        secure_gate::RedactionPolicy::DEFAULT
+            .redact(len)
+            .to_string()
+    };
+    assert_eq!(format!("{key:?}"), marker(32));
     assert_eq!(format!("{pw:?}"), "[REDACTED]");
-    assert_eq!(format!("{key:#?}"), "[REDACTED]");
+    assert_eq!(format!("{key:#?}"), marker(32));
     assert_eq!(format!("{pw:#?}"), "[REDACTED]");
+    assert!(!format!("{pw:?}").contains("hunter2"));
 }
 
 // === Byte Array Access ===
@@ -191,6 +198,8 @@ fn fixed_into_inner_beyond_default_limit() {
 fn sentinel_value_provided_impls() {
     use secure_gate::SentinelValue;
     assert_eq!(<[u8; 64]>::sentinel_value(), [0u8; 64]);
+    assert_eq!(u64::sentinel_value(), 0);
+    assert_eq!(i128::sentinel_value(), 0);
     #[cfg(feature = "alloc")]
     {
         assert_eq!(String::sentinel_value(), String::new());
@@ -214,6 +223,58 @@ fn dynamic_vec_into_inner_returns_zeroizing() {
     assert_eq!(*owned, [1u8, 2, 3]);
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_slice_into_inner_returns_boxed_slice() {
+    let secret: Dynamic<[u8]> = Dynamic::new(&[1u8, 2, 3][..]);
+    let owned: secure_gate::InnerSecret<Box<[u8]>> = secret.into_inner();
+    assert_eq!(&**owned, &[1u8, 2, 3]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_str_into_inner_returns_boxed_str() {
+    let pw: Dynamic<str> = Dynamic::new("hunter2");
+    let owned: secure_gate::InnerSecret<Box<str>> = pw.into_inner();
+    assert_eq!(&**owned, "hunter2");
+    assert_eq!(format!("{:?}", owned), "[REDACTED]");
+}
+
+// === Unsized Dynamic<[T]> / Dynamic<str> ===
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_slice_reveal_and_mutate() {
+    let mut secret: Dynamic<[u8]> = Dynamic::from(vec![1u8, 2, 3].into_boxed_slice());
+    assert_eq!(secret.len(), 3);
+    assert!(!secret.is_empty());
+    secret.with_secret_mut(|s| s[0] = 9);
+    assert_eq!(secret.expose_secret(), &[9u8, 2, 3]);
+    secret.expose_secret_mut().reverse();
+    secret.with_secret(|s| assert_eq!(s, &[3u8, 2, 9]));
+    assert_eq!(format!("{:?}", secret), "[REDACTED]");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_slice_byte_len_counts_element_size() {
+    let secret: Dynamic<[u32]> = Dynamic::new(&[1u32, 2][..]);
+    assert_eq!(secret.len(), 2);
+    assert_eq!(secret.byte_len(), 8);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_str_reveal_and_mutate() {
+    let mut pw: Dynamic<str> = Dynamic::new("hunter2");
+    assert_eq!(pw.len(), 7);
+    pw.with_secret_mut(|s| s.make_ascii_uppercase());
+    assert_eq!(pw.expose_secret(), "HUNTER2");
+
+    let empty: Dynamic<str> = Dynamic::new("");
+    assert!(empty.is_empty());
+}
+
 // === Streaming I/O (std) ===
 
 #[cfg(feature = "std")]
@@ -286,6 +347,308 @@ fn dynamic_write_then_read_roundtrip() {
     assert_eq!(out, b"roundtrip");
 }
 
+// === Zeroize-on-grow mutation ===
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_vec_secure_extend_grows_and_preserves_contents() {
+    let mut secret = Dynamic::<Vec<u8>>::new(Vec::with_capacity(2));
+    secret.secure_extend_from_slice(&[1, 2]);
+    secret.secure_extend_from_slice(&[3, 4, 5]);
+    secret.secure_push(6);
+    secret.with_secret(|v| {
+        assert_eq!(v.as_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert!(v.capacity() >= 6);
+    });
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_vec_secure_reserve_is_noop_when_capacity_suffices() {
+    let mut secret = Dynamic::<Vec<u8>>::new(Vec::with_capacity(16));
+    let before = secret.with_secret(|v| v.as_ptr());
+    secret.secure_reserve(16);
+    secret.secure_extend_from_slice(&[0xAA; 16]);
+    assert_eq!(secret.with_secret(|v| v.as_ptr()), before);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_string_secure_push_str_grows_and_preserves_contents() {
+    let mut pw = Dynamic::<String>::new(String::new());
+    pw.secure_push_str("hunter");
+    pw.secure_push('2');
+    pw.secure_push('é');
+    pw.secure_reserve(64);
+    pw.with_secret(|s| {
+        assert_eq!(s.as_str(), "hunter2é");
+        assert!(s.capacity() >= s.len() + 64);
+    });
+}
+
+// === Capacity-bounded buffers ===
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_vec_fixed_capacity_rejects_overflow_without_growing() {
+    let mut secret = Dynamic::<Vec<u8>>::with_fixed_capacity(4);
+    let ptr = secret.with_secret(|v| v.as_ptr());
+    secret.try_extend_from_slice(&[1, 2, 3]).unwrap();
+    assert_eq!(secret.remaining_capacity(), 1);
+    assert!(secret.try_extend_from_slice(&[4, 5]).is_err());
+    secret.try_push(4).unwrap();
+    assert!(secret.try_push(5).is_err());
+    secret.with_secret(|v| {
+        assert_eq!(v.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(v.capacity(), 4);
+        assert_eq!(v.as_ptr(), ptr);
+    });
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_string_fixed_capacity_rejects_overflow_without_growing() {
+    let mut pw = Dynamic::<String>::with_fixed_capacity(4);
+    pw.try_push_str("ab").unwrap();
+    // 'é' is 2 bytes and fits exactly; the next char does not.
+    pw.try_push('é').unwrap();
+    assert!(pw.try_push('c').is_err());
+    assert!(pw.try_push_str("").is_ok());
+    pw.with_secret(|s| {
+        assert_eq!(s.as_str(), "abé");
+        assert_eq!(s.capacity(), 4);
+    });
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn fixed_capacity_into_dynamic_restores_growable_wrapper() {
+    let mut secret = Dynamic::<Vec<u8>>::with_fixed_capacity(2);
+    assert_eq!(secret.capacity(), 2);
+    secret.try_extend_from_slice(&[1, 2]).unwrap();
+    assert!(format!("{secret:?}").starts_with("[REDACTED"));
+    let mut grown: Dynamic<Vec<u8>> = secret.into_dynamic();
+    grown.secure_push(3);
+    assert_eq!(grown.expose_secret(), &[1, 2, 3]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn dynamic_writer_is_all_or_nothing() {
+    use std::io::Write;
+    let mut secret = Dynamic::<Vec<u8>>::with_fixed_capacity(8);
+    {
+        let mut w = secret.as_writer();
+        w.write_all(b"12345").unwrap();
+        let err = w.write(b"6789").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::StorageFull);
+        assert!(matches!(
+            err.get_ref()
+                .and_then(|e| e.downcast_ref::<secure_gate::CapacityError>()),
+            Some(secure_gate::CapacityError::CapacityExceeded {
+                capacity: 8,
+                required: 9,
+                ..
+            })
+        ));
+        w.write_all(b"678").unwrap();
+        w.flush().unwrap();
+    }
+    secret.with_secret(|v| {
+        assert_eq!(v.as_slice(), b"12345678");
+        assert_eq!(v.capacity(), 8);
+    });
+}
+
+// === Boxed arrays and OpaqueSecret composites ===
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_array_reveal_and_into_inner() {
+    let mut key: Dynamic<[u8; 64]> = Dynamic::new([0xABu8; 64]);
+    assert_eq!(key.len(), 64);
+    key.with_secret_mut(|k| k[0] = 1);
+    assert_eq!(key.expose_secret()[..2], [1, 0xAB]);
+    let owned: secure_gate::InnerSecret<[u8; 64]> = key.into_inner();
+    assert_eq!(owned[1], 0xAB);
+
+    let words: Dynamic<[u32; 4]> = Dynamic::new([7u32; 4]);
+    assert_eq!(words.len(), 4);
+    assert_eq!(words.byte_len(), 16);
+}
+
+#[derive(zeroize::Zeroize, Default)]
+#[cfg_attr(feature = "cloneable", derive(Clone))]
+struct SessionKeys {
+    enc: [u8; 32],
+    counter: u64,
+}
+
+impl secure_gate::OpaqueSecret for SessionKeys {}
+
+impl secure_gate::SentinelValue for SessionKeys {
+    fn sentinel_value() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(feature = "cloneable")]
+impl CloneableSecret for SessionKeys {}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_opaque_struct_reveal_and_mutate() {
+    let mut keys = Dynamic::new(SessionKeys {
+        enc: [3u8; 32],
+        counter: 0,
+    });
+    assert_eq!(keys.len(), 1);
+    assert!(!keys.is_empty());
+    assert_eq!(keys.byte_len(), core::mem::size_of::<SessionKeys>());
+    keys.with_secret_mut(|k| k.counter += 1);
+    keys.expose_secret_mut().enc[0] = 9;
+    keys.with_secret(|k| {
+        assert_eq!(k.counter, 1);
+        assert_eq!(k.enc[..2], [9, 3]);
+    });
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_opaque_struct_into_inner() {
+    let keys = Dynamic::new(SessionKeys {
+        enc: [5u8; 32],
+        counter: 42,
+    });
+    let owned: secure_gate::InnerSecret<SessionKeys> = keys.into_inner();
+    assert_eq!(owned.counter, 42);
+    assert_eq!(format!("{:?}", owned), "[REDACTED]");
+}
+
+#[cfg(all(feature = "alloc", feature = "cloneable"))]
+#[test]
+fn dynamic_opaque_struct_clone() {
+    let keys = Dynamic::new(SessionKeys {
+        enc: [6u8; 32],
+        counter: 1,
+    });
+    let copy = keys.clone();
+    copy.with_secret(|k| assert_eq!(k.enc, [6u8; 32]));
+}
+
+#[test]
+fn fixed_scalar_reveal_and_into_inner() {
+    let mut counter: Fixed<u64> = Fixed::new(41);
+    assert_eq!(counter.len(), 1);
+    assert_eq!(counter.byte_len(), 8);
+    counter.with_secret_mut(|c| *c += 1);
+    assert_eq!(*counter.expose_secret(), 42);
+    let owned: secure_gate::InnerSecret<u64> = counter.into_inner();
+    assert_eq!(*owned, 42);
+
+    let pin: Fixed<i16> = Fixed::new(-1234);
+    pin.with_secret(|p| assert_eq!(*p, -1234));
+}
+
+#[test]
+fn fixed_opaque_struct_reveal_and_into_inner() {
+    let mut keys = Fixed::new(SessionKeys {
+        enc: [4u8; 32],
+        counter: 7,
+    });
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys.byte_len(), core::mem::size_of::<SessionKeys>());
+    keys.expose_secret_mut().counter += 1;
+    let owned = keys.into_inner();
+    assert_eq!(owned.counter, 8);
+    assert_eq!(owned.enc, [4u8; 32]);
+}
+
+// === Secret-to-secret transformation ===
+
+#[test]
+fn fixed_map_secret_derives_new_wrapper() {
+    let master = Fixed::new([0x11u8; 32]);
+    let sub: Fixed<[u8; 4]> = master.map_secret(|k| [k[0], k[1], k[2], k[3] ^ 0xFF]);
+    assert_eq!(sub.expose_secret(), &[0x11, 0x11, 0x11, 0xEE]);
+
+    let seed: Fixed<u64> = Fixed::new(7);
+    let next: Fixed<u64> = seed.map_secret(|s| s.wrapping_mul(31));
+    assert_eq!(*next.expose_secret(), 217);
+}
+
+#[test]
+fn fixed_map_secret_zeroizes_result_on_panic_before_construction() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static WIPED: AtomicBool = AtomicBool::new(false);
+
+    /// Sentinel construction panics, after the closure has produced the value.
+    struct Probe(u8);
+    impl zeroize::Zeroize for Probe {
+        fn zeroize(&mut self) {
+            self.0 = 0;
+            WIPED.store(true, Ordering::SeqCst);
+        }
+    }
+    impl secure_gate::SentinelValue for Probe {
+        fn sentinel_value() -> Self {
+            panic!("sentinel unavailable")
+        }
+    }
+
+    let key = Fixed::new([9u8; 4]);
+    let result = std::panic::catch_unwind(|| key.map_secret(|k| Probe(k[0])));
+    assert!(result.is_err());
+    assert!(
+        WIPED.load(Ordering::SeqCst),
+        "derived value was not zeroized"
+    );
+}
+
+#[test]
+fn fixed_try_map_secret_propagates_error() {
+    let key = Fixed::new([1u8, 2, 3, 4]);
+    let ok: Result<Fixed<[u8; 2]>, &str> = key.try_map_secret(|k| Ok([k[0], k[3]]));
+    assert_eq!(ok.unwrap().expose_secret(), &[1, 4]);
+    let err: Result<Fixed<[u8; 2]>, &str> = key.try_map_secret(|_| Err("bad"));
+    assert_eq!(err.unwrap_err(), "bad");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_map_secret_string_to_bytes() {
+    let pw: Dynamic<String> = Dynamic::new(String::from("hunter2"));
+    let bytes: Dynamic<Vec<u8>> = pw.map_secret(|s| s.as_bytes().to_vec());
+    assert_eq!(bytes.expose_secret(), b"hunter2");
+
+    let boxed: Dynamic<str> = Dynamic::new("abc");
+    let upper: Dynamic<String> = boxed.map_secret(|s| s.to_ascii_uppercase());
+    assert_eq!(upper.expose_secret(), "ABC");
+
+    let key: Dynamic<[u8; 4]> = pw.map_secret(|s| {
+        let mut out = [0u8; 4];
+        out.copy_from_slice(&s.as_bytes()[..4]);
+        out
+    });
+    assert_eq!(key.expose_secret(), b"hunt");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_try_map_secret_propagates_error() {
+    let hex: Dynamic<String> = Dynamic::new(String::from("zz"));
+    let parsed: Result<Dynamic<Vec<u8>>, core::num::ParseIntError> =
+        hex.try_map_secret(|s| u8::from_str_radix(s, 16).map(|b| vec![b]));
+    assert!(parsed.is_err());
+
+    let hex: Dynamic<String> = Dynamic::new(String::from("ff"));
+    let parsed: Dynamic<Vec<u8>> = hex
+        .try_map_secret(|s| u8::from_str_radix(s, 16).map(|b| vec![b]))
+        .unwrap();
+    assert_eq!(parsed.expose_secret(), &[0xFF]);
+}
+
 // === CloneableSecret ===
 
 #[cfg(feature = "cloneable")]
@@ -479,6 +842,669 @@ fn dynamic_from_rng_error_returns_err() {
     assert!(result.is_err());
 }
 
+// === Split and concatenate (Fixed byte arrays) ===
+
+#[test]
+fn fixed_split_preserves_byte_order() {
+    let master = Fixed::new(core::array::from_fn::<u8, 8, _>(|i| i as u8));
+    let (head, tail) = master.split::<3, 5>();
+    head.with_secret(|h| assert_eq!(h, &[0, 1, 2]));
+    tail.with_secret(|t| assert_eq!(t, &[3, 4, 5, 6, 7]));
+}
+
+#[test]
+fn fixed_split_allows_empty_part() {
+    let master = Fixed::new([9u8; 4]);
+    let (head, tail) = master.split::<0, 4>();
+    assert!(head.is_empty());
+    tail.with_secret(|t| assert_eq!(t, &[9u8; 4]));
+}
+
+#[test]
+fn fixed_concat_roundtrips_split() {
+    let master = Fixed::new(core::array::from_fn::<u8, 16, _>(|i| i as u8 * 3));
+    let (a, b) = master.split::<10, 6>();
+    let joined: Fixed<[u8; 16]> = Fixed::concat(&a, &b);
+    assert!(joined.with_secret(|j| master.with_secret(|m| j == m)));
+}
+
+#[test]
+fn fixed_split_array_yields_consecutive_chunks() {
+    let schedule = Fixed::new(core::array::from_fn::<u8, 12, _>(|i| (i / 4) as u8));
+    let parts = schedule.split_array::<4, 3>();
+    for (i, part) in parts.iter().enumerate() {
+        part.with_secret(|p| assert_eq!(p, &[i as u8; 4]));
+    }
+}
+
+// === Borrowed sub-secret views ===
+
+fn view_len<S: RevealSecret<Inner = [u8]>>(secret: &S) -> usize {
+    secret.with_secret(|s| s.len())
+}
+
+#[test]
+fn fixed_slice_view_reveals_sub_range() {
+    let schedule = Fixed::new(core::array::from_fn::<u8, 48, _>(|i| i as u8));
+    let mac = schedule.slice(16..32);
+    assert_eq!(view_len(&mac), 16);
+    assert_eq!(mac.byte_len(), 16);
+    mac.with_secret(|m| assert_eq!((m[0], m[15]), (16, 31)));
+    assert_eq!(
+        format!("{:?}", mac),
+        secure_gate::RedactionPolicy::DEFAULT.redact(16).to_string()
+    );
+}
+
+#[test]
+fn fixed_slice_view_can_be_narrowed() {
+    let key = Fixed::new(core::array::from_fn::<u8, 16, _>(|i| i as u8));
+    let inner = key.slice(4..).slice(..2);
+    inner.with_secret(|s| assert_eq!(s, &[4, 5]));
+}
+
+#[test]
+fn fixed_slice_mut_view_writes_through() {
+    let mut key = Fixed::new([0u8; 8]);
+    {
+        let mut tail = key.slice_mut(4..);
+        tail.with_secret_mut(|t| t.fill(0xAA));
+        tail.slice_mut(..1).with_secret_mut(|t| t[0] = 0x01);
+        assert_eq!(
+            format!("{:?}", tail),
+            secure_gate::RedactionPolicy::DEFAULT.redact(4).to_string()
+        );
+    }
+    key.with_secret(|k| assert_eq!(k, &[0, 0, 0, 0, 0x01, 0xAA, 0xAA, 0xAA]));
+}
+
+#[test]
+#[should_panic]
+fn fixed_slice_out_of_bounds_panics() {
+    let key = Fixed::new([0u8; 4]);
+    let _ = key.slice(2..8);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_slice_views_cover_bytes_and_strings() {
+    let mut blob: Dynamic<Vec<u8>> = Dynamic::new((0u8..8).collect::<Vec<u8>>());
+    assert_eq!(view_len(&blob.slice(2..6)), 4);
+    blob.slice_mut(..2).with_secret_mut(|b| b.fill(9));
+    blob.with_secret(|b| assert_eq!(&b[..3], &[9, 9, 2]));
+
+    let boxed: Dynamic<[u8]> = Dynamic::new(&[1u8, 2, 3][..]);
+    boxed.slice(1..).with_secret(|b| assert_eq!(b, &[2, 3]));
+
+    let mut pw: Dynamic<String> = Dynamic::new(String::from("hunter2"));
+    pw.slice(..6).with_secret(|s| assert_eq!(s, "hunter"));
+    pw.slice_mut(..1)
+        .with_secret_mut(|s| s.make_ascii_uppercase());
+    pw.with_secret(|s| assert_eq!(s, "Hunter2"));
+
+    let frozen: Dynamic<str> = Dynamic::new("correct horse");
+    let view = frozen.slice(8..);
+    assert_eq!(view.len(), 5);
+    assert_eq!(
+        format!("{:?}", view),
+        secure_gate::RedactionPolicy::DEFAULT.redact(5).to_string()
+    );
+}
+
+// === Frozen (read-only) wrapper ===
+
+#[test]
+fn fixed_freeze_reveals_but_keeps_metadata() {
+    let root = Fixed::new([0x42u8; 32]).freeze();
+    assert_eq!(root.len(), 32);
+    assert_eq!(root.byte_len(), 32);
+    root.with_secret(|k| assert_eq!(k, &[0x42u8; 32]));
+    assert_eq!(
+        format!("{:?}", root),
+        secure_gate::RedactionPolicy::DEFAULT.redact(32).to_string()
+    );
+}
+
+#[test]
+fn frozen_from_and_into_inner_roundtrip() {
+    let root: secure_gate::Frozen<Fixed<u64>> = Fixed::new(99u64).into();
+    assert_eq!(*root.into_inner(), 99);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_freeze_works_for_sized_and_unsized() {
+    let token = Dynamic::<String>::new("root-token".to_string()).freeze();
+    assert_eq!(token.with_secret(|t| t.len()), 10);
+
+    let boxed: Dynamic<[u8]> = Dynamic::new(&[1u8, 2, 3][..]);
+    let frozen = boxed.freeze();
+    assert_eq!(frozen.expose_secret(), &[1, 2, 3]);
+}
+
+// === Reveal budget (Budgeted / TryRevealSecret) ===
+
+#[test]
+fn budgeted_counts_down_and_then_refuses() {
+    use secure_gate::{Budgeted, RevealError, TryRevealSecret};
+
+    let mut token = Budgeted::new(Fixed::new([0x5Au8; 4]), 2);
+    assert_eq!(token.remaining(), 2);
+    assert_eq!(token.try_with_secret(|t| t[0]), Ok(0x5A));
+    assert_eq!(token.remaining(), 1);
+    assert!(!token.is_exhausted());
+    assert_eq!(token.try_with_secret(|t| *t), Ok([0x5A; 4]));
+    assert!(token.is_exhausted());
+
+    let mut called = false;
+    let err = token.try_with_secret(|_| called = true);
+    assert_eq!(err, Err(RevealError::BudgetExhausted));
+    assert!(!called, "closure must not run once the budget is spent");
+    assert_eq!(
+        format!("{:?}", token),
+        secure_gate::RedactionPolicy::DEFAULT.redact(4).to_string()
+    );
+}
+
+#[test]
+fn budgeted_zero_budget_is_immediately_exhausted() {
+    use secure_gate::{Budgeted, RevealError, TryRevealSecret};
+
+    let mut token = Budgeted::new(Fixed::new(42u32), 0);
+    assert!(token.is_exhausted());
+    assert_eq!(
+        token.try_with_secret(|v| *v),
+        Err(RevealError::BudgetExhausted)
+    );
+}
+
+#[test]
+fn budgeted_counts_panicking_reveal() {
+    use secure_gate::{Budgeted, TryRevealSecret};
+
+    let mut token = Budgeted::one_shot(Fixed::new([1u8; 4]));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _ = token.try_with_secret(|_| panic!("consumer failed"));
+    }));
+    assert!(result.is_err());
+    assert!(token.is_exhausted());
+}
+
+#[test]
+fn budgeted_zeroizes_when_last_reveal_panics() {
+    use secure_gate::{Budgeted, TryRevealSecret};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static WIPED: AtomicBool = AtomicBool::new(false);
+
+    struct Probe;
+    impl zeroize::Zeroize for Probe {
+        fn zeroize(&mut self) {
+            WIPED.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let mut token = Budgeted::new(Fixed::new([Probe]), 2);
+    assert_eq!(token.try_with_secret(|_| ()), Ok(()));
+    assert!(
+        !WIPED.load(Ordering::SeqCst),
+        "wiped before the last reveal"
+    );
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _ = token.try_with_secret(|_| panic!("consumer failed"));
+    }));
+    assert!(result.is_err());
+    // `token` is still alive here, so only the last-reveal guard can have wiped it.
+    assert!(
+        WIPED.load(Ordering::SeqCst),
+        "secret not zeroized on unwind"
+    );
+    assert!(token.is_exhausted());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn budgeted_one_shot_dynamic() {
+    use secure_gate::{Budgeted, RevealError, TryRevealSecret};
+
+    let mut code = Budgeted::one_shot(Dynamic::<String>::new("enrol-1234".to_string()));
+    assert_eq!(code.try_with_secret(|c| c.len()), Ok(10));
+    assert_eq!(
+        code.try_with_secret(|c| c.len()),
+        Err(RevealError::BudgetExhausted)
+    );
+}
+
+// === Time-limited secrets (Expiring) ===
+
+#[cfg(feature = "std")]
+struct FakeClock {
+    base: std::time::Instant,
+    elapsed: std::cell::Cell<std::time::Duration>,
+}
+
+#[cfg(feature = "std")]
+impl FakeClock {
+    fn new() -> Self {
+        Self {
+            base: std::time::Instant::now(),
+            elapsed: std::cell::Cell::new(std::time::Duration::ZERO),
+        }
+    }
+
+    fn advance(&self, secs: u64) {
+        self.elapsed
+            .set(self.elapsed.get() + std::time::Duration::from_secs(secs));
+    }
+}
+
+#[cfg(feature = "std")]
+impl secure_gate::Clock for FakeClock {
+    fn now(&self) -> std::time::Instant {
+        self.base + self.elapsed.get()
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn expiring_reveals_until_deadline_then_fails() {
+    use secure_gate::{Expiring, RevealError, TryRevealSecret};
+    use std::time::Duration;
+
+    let clock = FakeClock::new();
+    let mut key = Expiring::with_clock(Fixed::new([7u8; 16]), Duration::from_secs(60), &clock);
+    assert_eq!(key.remaining_ttl(), Duration::from_secs(60));
+    assert_eq!(key.try_with_secret(|k| k[0]), Ok(7));
+
+    clock.advance(59);
+    assert!(!key.is_expired());
+    assert_eq!(key.try_with_secret(|k| k[15]), Ok(7));
+
+    clock.advance(1);
+    assert!(key.is_expired());
+    let mut called = false;
+    assert_eq!(
+        key.try_with_secret(|_| called = true),
+        Err(RevealError::Expired)
+    );
+    assert!(!called, "closure must not run after expiry");
+    assert_eq!(key.remaining_ttl(), Duration::ZERO);
+    assert_eq!(
+        format!("{:?}", key),
+        secure_gate::RedactionPolicy::DEFAULT.redact(16).to_string()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn expiring_purge_if_expired_only_after_deadline() {
+    use secure_gate::{Expiring, RevealError, TryRevealSecret};
+    use std::time::Duration;
+
+    let clock = FakeClock::new();
+    let mut token = Expiring::with_clock(
+        Dynamic::<String>::new("oauth-token".to_string()),
+        Duration::from_secs(10),
+        &clock,
+    );
+    assert!(!token.purge_if_expired());
+    assert_eq!(token.try_with_secret(|t| t.len()), Ok(11));
+
+    clock.advance(10);
+    assert!(token.purge_if_expired());
+    assert!(token.purge_if_expired(), "purge is idempotent");
+    assert_eq!(
+        token.try_with_secret(|t| t.len()),
+        Err(RevealError::Expired)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn expiring_system_clock_default() {
+    use secure_gate::{Expiring, TryRevealSecret};
+    use std::time::Duration;
+
+    let mut session = Expiring::new(Fixed::new(5u64), Duration::from_secs(3600));
+    assert!(!session.is_expired());
+    assert_eq!(session.try_with_secret(|v| *v), Ok(5));
+
+    let mut stale = Expiring::new(Fixed::new(5u64), Duration::ZERO);
+    assert!(stale.purge_if_expired());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn expiring_overflowing_ttl_never_expires() {
+    use secure_gate::{Expiring, TryRevealSecret};
+    use std::time::Duration;
+
+    let clock = FakeClock::new();
+    let mut key = Expiring::with_clock(Fixed::new(9u8), Duration::MAX, &clock);
+    assert_eq!(key.deadline(), None);
+    assert_eq!(key.remaining_ttl(), Duration::MAX);
+
+    clock.advance(100 * 365 * 24 * 60 * 60);
+    assert!(!key.is_expired());
+    assert!(!key.purge_if_expired());
+    assert_eq!(key.try_with_secret(|v| *v), Ok(9));
+
+    let session = Expiring::new(Fixed::new(1u8), Duration::MAX);
+    assert!(!session.is_expired());
+}
+
+// === In-memory shielding (Shielded) ===
+
+#[cfg(feature = "shielded")]
+#[test]
+fn shielded_fixed_roundtrip() {
+    use secure_gate::Shielded;
+
+    let key = Shielded::new(Fixed::new([0x42u8; 32]));
+    assert_eq!(key.len(), 32);
+    assert_eq!(key.byte_len(), 32);
+    for _ in 0..3 {
+        // Re-randomized on every access; plaintext is unchanged.
+        assert_eq!(key.with_secret(|k| *k), [0x42u8; 32]);
+    }
+    assert_eq!(*key.into_inner(), [0x42u8; 32]);
+}
+
+#[cfg(feature = "shielded")]
+#[test]
+fn shielded_dynamic_roundtrip() {
+    use secure_gate::Shielded;
+
+    let pw: Shielded<String> = Dynamic::<String>::new("hunter2".to_string()).into();
+    assert_eq!(pw.len(), 7);
+    pw.with_secret(|s| assert_eq!(s, "hunter2"));
+    assert_eq!(&*pw.into_inner(), "hunter2");
+
+    let blob = Shielded::new(Dynamic::<Vec<u8>>::new(vec![9u8; 100]));
+    assert_eq!(
+        blob.with_secret(|b| b.iter().map(|&x| x as u32).sum::<u32>()),
+        900
+    );
+
+    let empty = Shielded::new(Dynamic::<Vec<u8>>::new(Vec::new()));
+    assert!(empty.is_empty());
+}
+
+#[cfg(feature = "shielded")]
+#[test]
+fn shielded_expose_caches_until_reshield() {
+    use secure_gate::Shielded;
+
+    let mut key = Shielded::new(Fixed::new([7u8; 16]));
+    assert!(!key.is_exposed());
+    assert_eq!(key.expose_secret(), &[7u8; 16]);
+    assert!(key.is_exposed());
+    key.reshield();
+    assert!(!key.is_exposed());
+    assert_eq!(key.with_secret(|k| k[15]), 7);
+    assert_eq!(
+        format!("{:?}", key),
+        secure_gate::RedactionPolicy::DEFAULT.redact(16).to_string()
+    );
+}
+
+// === Keyed fingerprints (fingerprint) ===
+
+#[cfg(feature = "fingerprint")]
+#[test]
+fn fingerprint_is_stable_per_key_and_distinguishes_secrets() {
+    use secure_gate::FingerprintKey;
+
+    let k1 = FingerprintKey::new([1u8; 32]);
+    let k2 = FingerprintKey::new([2u8; 32]);
+    let a = Fixed::new([0xAAu8; 32]);
+
+    assert_eq!(
+        a.fingerprint(&k1),
+        Fixed::new([0xAAu8; 32]).fingerprint(&k1)
+    );
+    assert_ne!(
+        a.fingerprint(&k1),
+        Fixed::new([0xABu8; 32]).fingerprint(&k1)
+    );
+    // Same secret, different key: unrelated fingerprints.
+    assert_ne!(a.fingerprint(&k1), a.fingerprint(&k2));
+
+    // String and Vec<u8> secrets with the same bytes agree.
+    let pw = Dynamic::<String>::new("hunter2".to_string());
+    let raw = Dynamic::<Vec<u8>>::new(b"hunter2".to_vec());
+    assert_eq!(pw.fingerprint(&k1), raw.fingerprint(&k1));
+    assert_eq!(
+        Dynamic::<str>::new("hunter2").fingerprint(&k1),
+        pw.fingerprint(&k1)
+    );
+}
+
+#[cfg(feature = "fingerprint")]
+#[test]
+fn fingerprint_display_is_hex_and_key_debug_is_redacted() {
+    use secure_gate::FingerprintKey;
+
+    let key = FingerprintKey::new([9u8; 32]);
+    let fp = Fixed::new([0u8; 16]).fingerprint(&key);
+    let hex = fp.to_string();
+    assert_eq!(hex.len(), 32);
+    assert!(
+        hex.bytes()
+            .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+    );
+    assert_eq!(format!("{fp:?}"), format!("Fingerprint({hex})"));
+    assert_eq!(fp.as_bytes().len(), 16);
+    assert_eq!(format!("{key:?}"), "[REDACTED]");
+
+    // Usable as a std HashMap key.
+    let mut seen = std::collections::HashSet::new();
+    assert!(seen.insert(fp));
+    assert!(!seen.insert(Fixed::new([0u8; 16]).fingerprint(&key)));
+}
+
+#[cfg(all(feature = "fingerprint", feature = "std"))]
+#[test]
+fn fingerprint_process_key_is_shared() {
+    use secure_gate::FingerprintKey;
+
+    assert!(core::ptr::eq(
+        FingerprintKey::process(),
+        FingerprintKey::process()
+    ));
+    let secret = Fixed::new([5u8; 8]);
+    assert_eq!(
+        secret.fingerprint(FingerprintKey::process()),
+        secret.fingerprint(FingerprintKey::process())
+    );
+}
+
+#[cfg(feature = "fingerprint")]
+#[test]
+fn secret_keyed_map_looks_up_by_secret_value() {
+    use secure_gate::SecretKeyedMap;
+
+    let mut map = SecretKeyedMap::new();
+    assert!(map.is_empty());
+    let alice = Dynamic::<String>::new("tok_alice".to_string());
+    let bob = Fixed::new(*b"tok_bob!");
+
+    assert_eq!(map.insert(&alice, 1), None);
+    assert_eq!(map.insert(&bob, 2), None);
+    assert_eq!(
+        map.insert(&Dynamic::<String>::new("tok_alice".to_string()), 10),
+        Some(1)
+    );
+    assert_eq!(map.len(), 2);
+
+    assert_eq!(
+        map.get(&Dynamic::<Vec<u8>>::new(b"tok_alice".to_vec())),
+        Some(&10)
+    );
+    assert!(map.contains_key(&Fixed::new(*b"tok_bob!")));
+    assert!(!map.contains_key(&Fixed::new(*b"tok_eve!")));
+    *map.get_mut(&bob).unwrap() += 1;
+    assert_eq!(map.get(&bob), Some(&3));
+    assert!(map.iter().any(|(fp, _)| *fp == map.fingerprint(&bob)));
+
+    assert_eq!(map.remove(&alice), Some(10));
+    assert_eq!(map.len(), 1);
+    assert_eq!(format!("{map:?}"), "SecretKeyedMap { len: 1, .. }");
+}
+
+// === Redaction policies ===
+
+#[test]
+fn redaction_policy_formats_label_and_length() {
+    use secure_gate::RedactionPolicy;
+
+    let key = Fixed::new([0u8; 32]);
+    let bare = RedactionPolicy::BARE;
+    assert_eq!(key.redacted(bare).to_string(), "[REDACTED]");
+    assert_eq!(
+        key.redacted(bare.label("api_key")).to_string(),
+        "[REDACTED api_key]"
+    );
+    assert_eq!(
+        key.redacted(bare.length_hint(true)).to_string(),
+        "[REDACTED; 32 bytes]"
+    );
+    assert_eq!(
+        format!("{:?}", key.redacted(bare.label("k").length_hint(true))),
+        "[REDACTED k; 32 bytes]"
+    );
+    assert_eq!(
+        Fixed::new([0u8; 1])
+            .redacted(bare.length_hint(true))
+            .to_string(),
+        "[REDACTED; 1 byte]"
+    );
+    // byte_len, not element count.
+    assert_eq!(
+        Fixed::new([0u32; 4])
+            .redacted(bare.length_hint(true))
+            .to_string(),
+        "[REDACTED; 16 bytes]"
+    );
+    // Plain Debug follows the crate-level default.
+    assert_eq!(
+        format!("{:?}", key),
+        key.redacted(RedactionPolicy::DEFAULT).to_string()
+    );
+    #[cfg(not(feature = "redact-length"))]
+    assert_eq!(format!("{:?}", key), "[REDACTED]");
+    #[cfg(feature = "redact-length")]
+    assert_eq!(format!("{:?}", key), "[REDACTED; 32 bytes]");
+}
+
+#[test]
+fn views_and_policy_wrappers_debug_follows_default_policy() {
+    use secure_gate::{Budgeted, RedactionPolicy};
+
+    let marker = |len| RedactionPolicy::DEFAULT.redact(len).to_string();
+    let mut schedule = Fixed::new([0xA5u8; 48]);
+    assert_eq!(format!("{:?}", schedule.slice(16..32)), marker(16));
+    assert_eq!(format!("{:?}", schedule.slice_mut(0..8)), marker(8));
+    assert_eq!(
+        format!("{:?}", Budgeted::new(Fixed::new([1u8; 4]), 1)),
+        marker(4)
+    );
+    assert_eq!(format!("{:?}", schedule.freeze()), marker(48));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_debug_needs_no_revealable_inner() {
+    #[derive(zeroize::Zeroize)]
+    struct Opaque([u8; 4]);
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Holder(Dynamic<Opaque>);
+
+    let holder = Holder(Dynamic::new(Opaque([1, 2, 3, 4])));
+    assert_eq!(format!("{holder:?}"), "Holder([REDACTED])");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn redaction_policy_applies_to_dynamic_and_outputs() {
+    use secure_gate::RedactionPolicy;
+
+    let policy = RedactionPolicy::BARE.label("pw").length_hint(true);
+    let pw = Dynamic::<String>::new("hunter2".to_string());
+    assert_eq!(pw.redacted(policy).to_string(), "[REDACTED pw; 7 bytes]");
+    let blob = Dynamic::<Vec<u8>>::new(vec![0u8; 100]);
+    assert_eq!(
+        blob.redacted(policy).to_string(),
+        "[REDACTED pw; 100 bytes]"
+    );
+
+    let inner = Fixed::new([1u8; 8]).into_inner();
+    assert_eq!(inner.redacted(policy).to_string(), "[REDACTED pw; 8 bytes]");
+    assert_eq!(format!("{:?}", inner), "[REDACTED]");
+}
+
+#[test]
+fn redaction_policy_default_follows_redact_length_feature() {
+    use secure_gate::RedactionPolicy;
+
+    let expected = if cfg!(feature = "redact-length") {
+        "[REDACTED; 4 bytes]"
+    } else {
+        "[REDACTED]"
+    };
+    assert_eq!(
+        Fixed::new([0u8; 4])
+            .redacted(RedactionPolicy::DEFAULT)
+            .to_string(),
+        expected
+    );
+    assert_eq!(RedactionPolicy::default(), RedactionPolicy::DEFAULT);
+}
+
+// === Fallible allocation (try_*) ===
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_try_constructors_succeed() {
+    let v = Dynamic::<Vec<u8>>::try_new_with(16, |v| v.resize(16, 0x11)).unwrap();
+    assert_eq!(v.expose_secret(), &[0x11u8; 16]);
+    let copied = Dynamic::<Vec<u8>>::try_from_slice(&[1, 2, 3]).unwrap();
+    assert_eq!(copied.expose_secret(), &[1, 2, 3]);
+    let s = Dynamic::<String>::try_new_with(5, |s| s.push_str("hello")).unwrap();
+    assert_eq!(s.expose_secret(), "hello");
+    let bytes = Dynamic::<[u8]>::try_new(&[9, 8, 7]).unwrap();
+    assert_eq!(bytes.expose_secret(), &[9, 8, 7]);
+    let text = Dynamic::<str>::try_new("pw").unwrap();
+    assert_eq!(text.expose_secret(), "pw");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_try_new_with_reports_failed_request_without_running_closure() {
+    let mut called = false;
+    let err = Dynamic::<Vec<u8>>::try_new_with(usize::MAX, |_| called = true)
+        .expect_err("usize::MAX bytes cannot be reserved");
+    assert!(!called);
+    match err {
+        secure_gate::AllocError::AllocationFailed { requested, .. } => {
+            assert_eq!(requested, usize::MAX)
+        }
+        _ => panic!("expected AllocationFailed"),
+    }
+    assert!(Dynamic::<String>::try_new_with(usize::MAX, |_| {}).is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn dynamic_try_from_random_fills_requested_length() {
+    let nonce = Dynamic::<Vec<u8>>::try_from_random(24).unwrap();
+    assert_eq!(nonce.len(), 24);
+    assert!(Dynamic::<Vec<u8>>::try_from_random(usize::MAX).is_err());
+}
+
 // === len() element-count semantics (issue #5) ===
 
 #[cfg(feature = "alloc")]
@@ -63,6 +63,371 @@ fn wrapper_ct_eq_dynamic_string() {
     assert!(!d1.ct_eq(&d4));
 }
 
+#[cfg(all(feature = "ct-eq", feature = "alloc"))]
+#[test]
+fn wrapper_ct_eq_dynamic_unsized() {
+    let s1: Dynamic<[u8]> = Dynamic::new(&[1u8, 2, 3][..]);
+    let s2: Dynamic<[u8]> = Dynamic::new(&[1u8, 2, 3][..]);
+    let s3: Dynamic<[u8]> = Dynamic::new(&[1u8, 2, 4][..]);
+    assert!(s1.ct_eq(&s2));
+    assert!(!s1.ct_eq(&s3));
+
+    let p1: Dynamic<str> = Dynamic::new("secret");
+    let p2: Dynamic<str> = Dynamic::new("secret");
+    let p3: Dynamic<str> = Dynamic::new("short");
+    assert!(p1.ct_eq(&p2));
+    assert!(!p1.ct_eq(&p3));
+}
+
+#[cfg(all(feature = "ct-eq", feature = "alloc"))]
+#[test]
+fn wrapper_ct_eq_dynamic_opaque_struct() {
+    #[derive(zeroize::Zeroize)]
+    struct Pair([u8; 4], [u8; 4]);
+    impl secure_gate::OpaqueSecret for Pair {}
+    impl ConstantTimeEq for Pair {
+        fn ct_eq(&self, other: &Self) -> bool {
+            self.0.ct_eq(&other.0) & self.1.ct_eq(&other.1)
+        }
+    }
+
+    let a = Dynamic::new(Pair([1; 4], [2; 4]));
+    let b = Dynamic::new(Pair([1; 4], [2; 4]));
+    let c = Dynamic::new(Pair([1; 4], [3; 4]));
+    assert!(a.ct_eq(&b));
+    assert!(!a.ct_eq(&c));
+}
+
+#[cfg(all(feature = "ct-eq", feature = "alloc"))]
+#[test]
+fn wrapper_ct_eq_frozen_forwards() {
+    let a = Fixed::new([7u8; 16]).freeze();
+    let b = Fixed::new([7u8; 16]).freeze();
+    let c = Fixed::new([8u8; 16]).freeze();
+    assert!(a.ct_eq(&b));
+    assert!(!a.ct_eq(&c));
+
+    let d = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]).freeze();
+    let e = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]).freeze();
+    assert!(d.ct_eq(&e));
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn fixed_subtle_ct_eq_returns_choice() {
+    let a = secure_gate::Fixed::new([5u8; 8]);
+    let b = secure_gate::Fixed::new([5u8; 8]);
+    let c = secure_gate::Fixed::new([6u8; 8]);
+    assert_eq!(subtle::ConstantTimeEq::ct_eq(&a, &b).unwrap_u8(), 1);
+    assert_eq!(subtle::ConstantTimeEq::ct_eq(&a, &c).unwrap_u8(), 0);
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn fixed_ct_select_assign_and_swap() {
+    use secure_gate::{Choice, Fixed, RevealSecret};
+    let a = Fixed::new([1u8; 4]);
+    let b = Fixed::new([2u8; 4]);
+    assert_eq!(
+        Fixed::ct_select(&a, &b, Choice::from(0)).expose_secret(),
+        &[1u8; 4]
+    );
+    assert_eq!(
+        Fixed::ct_select(&a, &b, Choice::from(1)).expose_secret(),
+        &[2u8; 4]
+    );
+
+    let mut x = Fixed::new([1u8; 4]);
+    x.conditional_assign(&b, Choice::from(0));
+    assert_eq!(x.expose_secret(), &[1u8; 4]);
+    x.conditional_assign(&b, Choice::from(1));
+    assert_eq!(x.expose_secret(), &[2u8; 4]);
+
+    let mut p = Fixed::new([3u8; 4]);
+    let mut q = Fixed::new([4u8; 4]);
+    Fixed::conditional_swap(&mut p, &mut q, Choice::from(0));
+    assert_eq!(p.expose_secret(), &[3u8; 4]);
+    Fixed::conditional_swap(&mut p, &mut q, Choice::from(1));
+    assert_eq!(p.expose_secret(), &[4u8; 4]);
+    assert_eq!(q.expose_secret(), &[3u8; 4]);
+}
+
+#[cfg(all(feature = "ct-eq", feature = "alloc"))]
+#[test]
+fn dynamic_vec_subtle_select_and_assign() {
+    use secure_gate::{Choice, RevealSecret};
+    let a = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    let b = Dynamic::<Vec<u8>>::new(vec![4, 5, 6]);
+    assert_eq!(subtle::ConstantTimeEq::ct_eq(&a, &b).unwrap_u8(), 0);
+    assert_eq!(
+        Dynamic::ct_select(&a, &b, Choice::from(1))
+            .unwrap()
+            .expose_secret(),
+        &[4, 5, 6]
+    );
+
+    let mut x = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    assert_eq!(x.conditional_assign(&b, Choice::from(1)), Ok(()));
+    assert_eq!(subtle::ConstantTimeEq::ct_eq(&x, &b).unwrap_u8(), 1);
+}
+
+#[cfg(all(feature = "ct-eq", feature = "alloc"))]
+#[test]
+fn dynamic_vec_select_and_assign_reject_length_mismatch() {
+    use secure_gate::{Choice, FromSliceError, RevealSecret};
+    let mut a = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    let b = Dynamic::<Vec<u8>>::new(vec![4, 5]);
+    assert!(Dynamic::ct_select(&a, &b, Choice::from(1)).is_none());
+    assert!(matches!(
+        a.conditional_assign(&b, Choice::from(1)),
+        Err(FromSliceError::InvalidLength {
+            expected: 3,
+            got: 2,
+            ..
+        })
+    ));
+    assert_eq!(a.expose_secret(), &[1, 2, 3]);
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn ct_eq_choice_and_ct_ne_agree_with_ct_eq() {
+    let a = [1u8, 2, 3];
+    let b = [1u8, 2, 3];
+    let c = [1u8, 2, 4];
+    assert_eq!(a.ct_eq_choice(&b).unwrap_u8(), 1);
+    assert_eq!(a.ct_eq_choice(&c).unwrap_u8(), 0);
+    assert!(!a.ct_ne(&b));
+    assert!(a.ct_ne(&c));
+    assert_eq!("pw".ct_eq_choice("pw").unwrap_u8(), 1);
+}
+
+#[cfg(all(feature = "ct-eq", feature = "alloc"))]
+#[test]
+fn ct_eq_choice_forwards_through_wrappers() {
+    let a = Fixed::new([9u8; 8]);
+    let b = Fixed::new([9u8; 8]);
+    assert_eq!(a.ct_eq_choice(&b).unwrap_u8(), 1);
+    let d1 = Dynamic::<Vec<u8>>::new(vec![1, 2]);
+    let d2 = Dynamic::<Vec<u8>>::new(vec![1, 3]);
+    assert_eq!(d1.ct_eq_choice(&d2).unwrap_u8(), 0);
+    assert!(d1.ct_ne(&d2));
+    let f1 = Fixed::new([4u8; 2]).freeze();
+    let f2 = Fixed::new([4u8; 2]).freeze();
+    assert_eq!(f1.ct_eq_choice(&f2).unwrap_u8(), 1);
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn ct_eq_choice_default_for_bool_only_impls() {
+    struct Legacy(u8);
+    impl ConstantTimeEq for Legacy {
+        fn ct_eq(&self, other: &Self) -> bool {
+            [self.0].ct_eq(&[other.0])
+        }
+    }
+    assert_eq!(Legacy(1).ct_eq_choice(&Legacy(1)).unwrap_u8(), 1);
+    assert!(Legacy(1).ct_ne(&Legacy(2)));
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn ct_eq_all_folds_every_pair() {
+    let x: &[u8] = b"mac";
+    let y: &[u8] = b"hdr";
+    let z: &[u8] = b"bad";
+    assert_eq!(secure_gate::ct_eq_all(&[(x, x), (y, y)]).unwrap_u8(), 1);
+    assert_eq!(secure_gate::ct_eq_all(&[(x, z), (y, y)]).unwrap_u8(), 0);
+    assert_eq!(secure_gate::ct_eq_all(&[(x, x), (y, z)]).unwrap_u8(), 0);
+    assert_eq!(secure_gate::ct_eq_all::<[u8]>(&[]).unwrap_u8(), 1);
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn fixed_ct_lookup_hit_and_miss() {
+    use secure_gate::Fixed;
+    let k1 = Fixed::new([1u8; 8]);
+    let k2 = Fixed::new([2u8; 8]);
+    let table = [(&[10u8][..], &k1), (&[20u8][..], &k2)];
+
+    let first = Fixed::ct_lookup(&table, &[10u8][..])
+        .into_option()
+        .expect("first entry");
+    assert_eq!(*first, [1u8; 8]);
+    let second = Fixed::ct_lookup(&table, &[20u8][..])
+        .into_option()
+        .expect("second entry");
+    assert_eq!(*second, [2u8; 8]);
+    assert_eq!(
+        Fixed::ct_lookup(&table, &[30u8][..]).is_none().unwrap_u8(),
+        1
+    );
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn ct_ord_big_and_little_endian() {
+    use secure_gate::{ByteOrder, ConstantTimeOrd, Fixed};
+    let a = Fixed::new([0x01u8, 0x00]);
+    let b = Fixed::new([0x00u8, 0x02]);
+    // 0x0100 > 0x0002 big-endian; 0x0001 < 0x0200 little-endian.
+    assert!(bool::from(a.ct_gt(&b, ByteOrder::BigEndian)));
+    assert!(bool::from(a.ct_lt(&b, ByteOrder::LittleEndian)));
+    let eq = a.ct_cmp(&a, ByteOrder::BigEndian);
+    assert!(bool::from(eq.is_eq() & eq.is_le() & eq.is_ge()));
+    assert_eq!(
+        core::cmp::Ordering::from(b.ct_cmp(&a, ByteOrder::BigEndian)),
+        core::cmp::Ordering::Less
+    );
+    assert!(bool::from(
+        [0u8; 0].ct_cmp(&[], ByteOrder::BigEndian).is_eq()
+    ));
+}
+
+#[cfg(all(feature = "ct-eq", feature = "alloc"))]
+#[test]
+fn ct_ord_dynamic_rejects_length_mismatch() {
+    use secure_gate::ByteOrder;
+    let a = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    let b = Dynamic::<Vec<u8>>::new(vec![1, 2]);
+    assert!(a.ct_cmp(&b, ByteOrder::BigEndian).is_none());
+    let ord = a.ct_cmp(&a, ByteOrder::LittleEndian).expect("same length");
+    assert!(bool::from(ord.is_eq()));
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn ct_eq_padded_semantics() {
+    use secure_gate::ct_eq_padded;
+    let yes = |a: &[u8], b: &[u8], max| bool::from(ct_eq_padded(a, b, max));
+    assert!(yes(b"secret", b"secret", 16));
+    assert!(yes(b"secret", b"secret", 6));
+    assert!(!yes(b"secret", b"secreT", 16));
+    assert!(!yes(b"secret", b"secret\0", 16)); // zero padding is not a match
+    assert!(!yes(b"secret", b"secret", 5)); // longer than max_len
+    assert!(yes(b"", b"", 0));
+    assert!(yes(b"", b"", 8));
+    assert!(!yes(b"", b"\0", 8));
+}
+
+#[cfg(all(feature = "ct-eq", feature = "alloc"))]
+#[test]
+fn ct_eq_padded_on_dynamic_wrappers() {
+    let a = Dynamic::<String>::new("correct horse".to_string());
+    let b = Dynamic::<String>::new("correct horse".to_string());
+    let c = Dynamic::<String>::new("correct".to_string());
+    assert!(bool::from(a.ct_eq_padded(&b, 64)));
+    assert!(!bool::from(a.ct_eq_padded(&c, 64)));
+
+    let v = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    let w = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    assert!(bool::from(v.ct_eq_padded(&w, 3)));
+}
+
+/// Statistical timing check: with a fixed `max_len`, comparing 1-byte inputs
+/// must cost about the same as comparing `max_len`-byte inputs. An early return
+/// on length mismatch (as in the plain `[u8]` impl) would make the short and
+/// mismatched cases orders of magnitude faster. Uses the minimum over many
+/// interleaved rounds, which filters scheduler noise; the bound is loose enough
+/// for shared CI runners. Wall-clock based, so it is ignored by default and runs
+/// as a dedicated release-profile CI step.
+#[cfg(all(feature = "ct-eq", not(miri)))]
+#[test]
+#[ignore = "wall-clock timing check; run with --release -- --ignored"]
+fn ct_eq_padded_timing_independent_of_input_length() {
+    use secure_gate::ct_eq_padded;
+    use std::hint::black_box;
+    use std::time::{Duration, Instant};
+
+    const MAX: usize = 4096;
+    const ROUNDS: usize = 60;
+    const CALLS: usize = 20;
+
+    let long_a = vec![0xA5u8; MAX];
+    let long_b = vec![0xA5u8; MAX];
+    let short: &[u8] = &[0xA5];
+    let cases: [(&[u8], &[u8]); 3] = [
+        (short, short),     // both 1 byte
+        (&long_a, &long_b), // both max_len, equal
+        (short, &long_b),   // length mismatch
+    ];
+
+    let mut best = [Duration::MAX; 3];
+    for _ in 0..ROUNDS {
+        for (slot, (a, b)) in cases.iter().enumerate() {
+            let start = Instant::now();
+            for _ in 0..CALLS {
+                black_box(ct_eq_padded(black_box(a), black_box(b), black_box(MAX)));
+            }
+            best[slot] = best[slot].min(start.elapsed());
+        }
+    }
+
+    let fastest = best.iter().min().unwrap().as_nanos().max(1);
+    let slowest = best.iter().max().unwrap().as_nanos();
+    let ratio = slowest as f64 / fastest as f64;
+    assert!(
+        ratio < 3.0,
+        "ct_eq_padded timing depends on input length: {best:?} (ratio {ratio:.2})"
+    );
+}
+
+#[cfg(all(feature = "ct-eq", feature = "alloc"))]
+#[test]
+fn wrapper_ct_eq_wide_unsigned_integers() {
+    let a = Fixed::new([0xDEAD_BEEFu32; 8]);
+    let b = Fixed::new([0xDEAD_BEEFu32; 8]);
+    let mut other = [0xDEAD_BEEFu32; 8];
+    other[7] ^= 1;
+    assert!(a.ct_eq(&b));
+    assert!(!a.ct_eq(&Fixed::new(other)));
+
+    assert!(Fixed::new([u64::MAX; 4]).ct_eq(&Fixed::new([u64::MAX; 4])));
+    assert!(!Fixed::new([1u128, 2]).ct_eq(&Fixed::new([1u128, 3])));
+    assert!(
+        Fixed::new([7usize; 3])
+            .ct_eq_choice(&Fixed::new([7usize; 3]))
+            .unwrap_u8()
+            == 1
+    );
+
+    let v1 = Dynamic::<Vec<u32>>::new(vec![1, 2, 3]);
+    let v2 = Dynamic::<Vec<u32>>::new(vec![1, 2, 3]);
+    let v3 = Dynamic::<Vec<u32>>::new(vec![1, 2]);
+    assert!(v1.ct_eq(&v2));
+    assert!(!v1.ct_eq(&v3));
+
+    let s1: Dynamic<[u16]> = Dynamic::new(&[1u16, 2, 3][..]);
+    let s2: Dynamic<[u16]> = Dynamic::new(&[1u16, 2, 4][..]);
+    assert!(s1.ct_eq(&s1));
+    assert!(!s1.ct_eq(&s2));
+    let b1: Dynamic<[u8]> = Dynamic::new(&[9u8, 9][..]);
+    assert!(b1.ct_eq(&Dynamic::new(&[9u8, 9][..])));
+}
+
+#[cfg(all(feature = "ct-eq", feature = "alloc"))]
+#[test]
+fn subtle_bridge_marker_forwards_through_wrappers() {
+    #[derive(zeroize::Zeroize)]
+    struct Scalar([u64; 4]);
+    impl subtle::ConstantTimeEq for Scalar {
+        fn ct_eq(&self, other: &Self) -> subtle::Choice {
+            subtle::ConstantTimeEq::ct_eq(&self.0[..], &other.0[..])
+        }
+    }
+    impl secure_gate::SubtleConstantTimeEq for Scalar {}
+    impl secure_gate::OpaqueSecret for Scalar {}
+
+    let a = Fixed::new(Scalar([1, 2, 3, 4]));
+    let b = Fixed::new(Scalar([1, 2, 3, 4]));
+    let c = Dynamic::new(Scalar([1, 2, 3, 5]));
+    let d = Dynamic::new(Scalar([1, 2, 3, 4]));
+    assert!(a.ct_eq(&b));
+    assert_eq!(a.ct_eq_choice(&b).unwrap_u8(), 1);
+    assert!(!d.ct_eq(&c));
+    assert!(d.ct_ne(&c));
+}
+
 #[cfg(all(not(feature = "ct-eq"), feature = "alloc"))]
 #[test]
 fn manual_comparison_without_ct_eq_feature() {
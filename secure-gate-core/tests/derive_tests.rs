@@ -0,0 +1,156 @@
+//! `#[derive(SecretStruct)]` tests (`derive` feature).
+
+#![cfg(feature = "derive")]
+
+#[cfg(feature = "alloc")]
+use secure_gate::Dynamic;
+use secure_gate::{Fixed, RevealSecret, RevealSecretMut, SecretStruct, SentinelValue};
+use zeroize::Zeroize;
+
+#[derive(SecretStruct)]
+struct Keys {
+    enc: [u8; 16],
+    counter: u64,
+    #[secret(skip)]
+    version: u8,
+}
+
+#[derive(SecretStruct)]
+struct Pair([u8; 4], #[secret(skip)] u16);
+
+#[test]
+fn debug_redacts_secret_fields_and_shows_metadata() {
+    let keys = Keys {
+        enc: [0xAB; 16],
+        counter: 42,
+        version: 3,
+    };
+    assert_eq!(
+        format!("{keys:?}"),
+        "Keys { enc: [REDACTED], counter: [REDACTED], version: 3 }"
+    );
+    assert_eq!(format!("{:?}", Pair([1; 4], 9)), "Pair([REDACTED], 9)");
+}
+
+#[test]
+fn zeroize_clears_secret_fields_but_not_metadata() {
+    let mut keys = Keys {
+        enc: [0xAB; 16],
+        counter: 42,
+        version: 3,
+    };
+    keys.zeroize();
+    assert_eq!(keys.enc, [0u8; 16]);
+    assert_eq!(keys.counter, 0);
+    assert_eq!(keys.version, 3);
+}
+
+#[test]
+fn sentinel_uses_field_sentinels_and_default_for_metadata() {
+    let s = Keys::sentinel_value();
+    assert_eq!(s.enc, [0u8; 16]);
+    assert_eq!(s.counter, 0);
+    assert_eq!(s.version, 0);
+}
+
+#[test]
+fn derived_struct_is_revealable_in_wrappers() {
+    let mut fixed = Fixed::new(Pair([7; 4], 1));
+    fixed.with_secret_mut(|p| p.0[0] = 8);
+    assert_eq!(fixed.with_secret(|p| p.0), [8, 7, 7, 7]);
+    assert_eq!(fixed.len(), 1);
+    let owned = fixed.into_inner();
+    assert_eq!(owned.1, 1);
+    assert_eq!(format!("{owned:?}"), "[REDACTED]");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn derived_struct_in_dynamic() {
+    let keys = Dynamic::new(Keys {
+        enc: [1; 16],
+        counter: 0,
+        version: 1,
+    });
+    assert_eq!(keys.with_secret(|k| k.enc[15]), 1);
+    assert_eq!(format!("{keys:?}"), "[REDACTED]");
+}
+
+#[test]
+fn generic_struct_with_bounded_parameter() {
+    #[derive(SecretStruct)]
+    struct Wrapper<K: Zeroize + SentinelValue> {
+        key: K,
+    }
+    let mut w = Wrapper { key: [5u8; 8] };
+    w.zeroize();
+    assert_eq!(w.key, [0u8; 8]);
+}
+
+#[cfg(feature = "ct-eq")]
+mod ct_eq {
+    use secure_gate::{ConstantTimeEq, Fixed, SecretStruct};
+
+    #[derive(SecretStruct)]
+    #[secret(ct_eq)]
+    struct Tagged {
+        key: [u8; 8],
+        #[secret(skip, ct_eq)]
+        id: [u8; 2],
+        #[secret(skip)]
+        label: u8,
+    }
+
+    fn tagged(key: u8, id: u8, label: u8) -> Tagged {
+        Tagged {
+            key: [key; 8],
+            id: [id; 2],
+            label,
+        }
+    }
+
+    #[test]
+    fn compares_secret_fields_and_opted_in_metadata_only() {
+        let relabeled = tagged(1, 1, 2);
+        assert_eq!(relabeled.label, 2);
+        assert!(tagged(1, 1, 1).ct_eq(&relabeled)); // label not compared
+        assert!(!tagged(1, 1, 1).ct_eq(&tagged(2, 1, 1)));
+        assert!(!tagged(1, 1, 1).ct_eq(&tagged(1, 2, 1)));
+        assert_eq!(
+            tagged(1, 1, 1).ct_eq_choice(&tagged(1, 1, 1)).unwrap_u8(),
+            1
+        );
+    }
+
+    #[test]
+    fn forwards_through_fixed() {
+        let a = Fixed::new(tagged(3, 4, 5));
+        let b = Fixed::new(tagged(3, 4, 5));
+        assert!(a.ct_eq(&b));
+    }
+}
+
+#[cfg(all(feature = "cloneable", feature = "alloc"))]
+#[test]
+fn cloneable_opt_in_enables_wrapper_clone() {
+    #[derive(Clone, SecretStruct)]
+    #[secret(cloneable)]
+    struct Seed([u8; 8]);
+
+    let a = Dynamic::new(Seed([9; 8]));
+    let b = a.clone();
+    assert_eq!(b.with_secret(|s| s.0), [9; 8]);
+}
+
+#[cfg(feature = "serde-serialize")]
+#[test]
+fn serializable_opt_in_enables_wrapper_serialize() {
+    #[derive(serde::Serialize, SecretStruct)]
+    #[secret(serializable)]
+    struct Token {
+        value: u32,
+    }
+
+    let t = Fixed::new(Token { value: 7 });
+    assert_eq!(serde_json::to_string(&t).unwrap(), r#"{"value":7}"#);
+}
@@ -44,7 +44,12 @@ fn dynamic_to_base64url_zeroizing_matches_plain() {
 fn fixed_to_base64url_zeroizing_debug_is_redacted() {
     let secret = Fixed::new([0x42u8; 4]);
     let encoded = secret.to_base64url_zeroizing();
-    assert_eq!(format!("{encoded:?}"), "[REDACTED]");
+    assert_eq!(
+        format!("{encoded:?}"),
+        secure_gate::RedactionPolicy::DEFAULT
+            .redact(encoded.len())
+            .to_string()
+    );
 }
 
 #[cfg(feature = "encoding-base64")]
@@ -62,7 +62,12 @@ fn fixed_try_to_bech32_zeroizing_debug_is_redacted() {
     let encoded = fixed
         .try_to_bech32_zeroizing("fuzz")
         .expect("zeroizing encode");
-    assert_eq!(format!("{encoded:?}"), "[REDACTED]");
+    assert_eq!(
+        format!("{encoded:?}"),
+        secure_gate::RedactionPolicy::DEFAULT
+            .redact(encoded.len())
+            .to_string()
+    );
 }
 
 #[cfg(feature = "encoding-bech32")]
@@ -174,7 +179,12 @@ fn fixed_try_to_bech32m_zeroizing_debug_is_redacted() {
     let encoded = fixed
         .try_to_bech32m_zeroizing("fuzzm")
         .expect("zeroizing encode");
-    assert_eq!(format!("{encoded:?}"), "[REDACTED]");
+    assert_eq!(
+        format!("{encoded:?}"),
+        secure_gate::RedactionPolicy::DEFAULT
+            .redact(encoded.len())
+            .to_string()
+    );
 }
 
 #[cfg(feature = "encoding-bech32m")]
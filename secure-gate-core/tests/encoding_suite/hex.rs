@@ -60,7 +60,12 @@ fn dynamic_to_hex_upper_zeroizing_matches_plain() {
 fn fixed_to_hex_zeroizing_debug_is_redacted() {
     let secret = Fixed::new([0xDEu8, 0xAD, 0xBE, 0xEF]);
     let encoded = secret.to_hex_zeroizing();
-    assert_eq!(format!("{encoded:?}"), "[REDACTED]");
+    assert_eq!(
+        format!("{encoded:?}"),
+        secure_gate::RedactionPolicy::DEFAULT
+            .redact(encoded.len())
+            .to_string()
+    );
 }
 
 #[cfg(feature = "encoding-hex")]
@@ -114,6 +119,17 @@ fn dynamic_try_from_hex_roundtrip() {
     decoded.with_secret(|d| assert_eq!(d, &[1, 2, 3, 4]));
 }
 
+#[cfg(all(feature = "encoding-hex", feature = "alloc"))]
+#[test]
+fn dynamic_slice_hex_roundtrip_is_exact_size() {
+    let secret: Dynamic<[u8]> = Dynamic::new(&[0xDEu8, 0xAD, 0xBE, 0xEF][..]);
+    assert_eq!(secret.to_hex(), "deadbeef");
+    assert_eq!(&*secret.to_hex_upper_zeroizing(), "DEADBEEF");
+    let decoded = Dynamic::<[u8]>::try_from_hex("deadbeef").expect("valid hex");
+    decoded.with_secret(|d| assert_eq!(d, &[0xDE, 0xAD, 0xBE, 0xEF]));
+    assert!(Dynamic::<[u8]>::try_from_hex("xyz").is_err());
+}
+
 #[cfg(all(feature = "encoding-hex", feature = "alloc"))]
 #[test]
 fn dynamic_try_from_hex_invalid_input_returns_err() {
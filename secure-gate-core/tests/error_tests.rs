@@ -231,3 +231,52 @@ fn decoding_error_source_bech32() {
         .expect("DecodingError::InvalidBech32 must have a source");
     assert!(source.to_string().contains("bech32 operation failed"));
 }
+
+/// CapacityError reports the fixed capacity and the length the write needed.
+#[cfg(feature = "alloc")]
+#[test]
+fn capacity_error_capacity_exceeded() {
+    let mut secret = secure_gate::Dynamic::<Vec<u8>>::with_fixed_capacity(4);
+    secret.try_extend_from_slice(&[0u8; 3]).unwrap();
+    let err = secret
+        .try_extend_from_slice(&[0u8; 3])
+        .expect_err("write past capacity must fail");
+    match err {
+        secure_gate::CapacityError::CapacityExceeded {
+            capacity, required, ..
+        } => {
+            assert_eq!(capacity, 4);
+            assert_eq!(required, 6);
+        }
+        _ => panic!("expected CapacityExceeded"),
+    }
+    assert_eq!(
+        format!("{}", err),
+        "write exceeds fixed capacity: capacity 4, required 6"
+    );
+}
+
+/// AllocError reports how many bytes the failed reservation asked for.
+#[cfg(feature = "alloc")]
+#[test]
+fn alloc_error_allocation_failed_display() {
+    let err = secure_gate::Dynamic::<Vec<u8>>::try_new_with(usize::MAX, |_| {})
+        .expect_err("reservation must fail");
+    assert_eq!(
+        format!("{}", err),
+        format!("secret allocation failed: {} bytes requested", usize::MAX)
+    );
+}
+
+/// RevealError is heap-free, Copy, and has fixed messages.
+#[test]
+fn reveal_error_budget_exhausted_display() {
+    let err = secure_gate::RevealError::BudgetExhausted;
+    let copy = err;
+    assert_eq!(format!("{}", copy), "reveal budget exhausted");
+    assert_eq!(err, copy);
+    assert_eq!(
+        format!("{}", secure_gate::RevealError::Expired),
+        "secret expired"
+    );
+}
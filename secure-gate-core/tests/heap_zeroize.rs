@@ -416,6 +416,71 @@ fn check_into_inner_vec_zeroed(size: usize) {
     });
 }
 
+// ---------------------------------------------------------------------------
+// Zeroize-on-grow — `secure_*` methods must not leave realloc residue
+//
+// Each check builds a secret whose backing buffer is exactly `size` bytes and
+// fully populated, then grows it past capacity. `Vec::extend_from_slice` would
+// route through `GlobalAlloc::realloc`, whose default implementation copies to
+// a new block and deallocates the old `size`-byte block *unzeroed* — the proxy
+// would catch that. The `secure_*` methods must zeroize the old buffer first.
+//
+// The grown buffer (at least `2 * size` bytes) is dropped inside the window as
+// well but is not inspected, since its size does not match `TARGET_SIZE`.
+// ---------------------------------------------------------------------------
+
+fn check_secure_extend_vec_zeroed(size: usize) {
+    with_proxy_check(size, || {
+        let mut secret: Dynamic<Vec<u8>> = Dynamic::new(Vec::with_capacity(size));
+        secret.with_secret_mut(|v| {
+            v.extend(std::iter::repeat_n(0xDDu8, size));
+            assert_eq!(
+                v.capacity(),
+                size,
+                "allocator rounded up capacity — proxy check would be skipped"
+            );
+        });
+        secret.secure_extend_from_slice(&[0xEEu8; 8]);
+        assert_eq!(secret.len(), size + 8);
+        core::hint::black_box(&secret);
+        drop(secret);
+    });
+}
+
+fn check_secure_push_str_zeroed(size: usize) {
+    with_proxy_check(size, || {
+        let mut secret: Dynamic<String> = Dynamic::new(String::with_capacity(size));
+        secret.with_secret_mut(|s| {
+            s.extend(std::iter::repeat_n('D', size));
+            assert_eq!(
+                s.capacity(),
+                size,
+                "allocator rounded up capacity — proxy check would be skipped"
+            );
+        });
+        secret.secure_push_str("more");
+        assert_eq!(secret.len(), size + 4);
+        core::hint::black_box(&secret);
+        drop(secret);
+    });
+}
+
+#[cfg(feature = "std")]
+fn check_io_write_grow_zeroed(size: usize) {
+    use std::io::Write;
+    with_proxy_check(size, || {
+        let mut secret: Dynamic<Vec<u8>> = Dynamic::new(Vec::with_capacity(size));
+        // Stack source: a heap temporary of `size` bytes would itself trip the proxy.
+        secret
+            .write_all(&[0xDDu8; 128][..size])
+            .expect("in-capacity write");
+        assert_eq!(secret.with_secret(|v| v.capacity()), size);
+        secret.write_all(b"overflow").expect("growing write");
+        core::hint::black_box(&secret);
+        drop(secret);
+    });
+}
+
 // ---------------------------------------------------------------------------
 // Panic-path positive-control test
 //
@@ -548,8 +613,8 @@ fn check_new_with_panic_zeroed_string(size: usize) {
 // ---------------------------------------------------------------------------
 
 /// Verifies `Dynamic<[u8; N]>`, `Dynamic<Vec<u8>>`, `Dynamic<String>`, all
-/// decode paths, all deserialize paths, and the panic-path positive control
-/// all zeroize heap memory before deallocation.
+/// decode paths, all deserialize paths, zeroize-on-grow mutation, and the
+/// panic-path positive control all zeroize heap memory before deallocation.
 ///
 /// This stays as one aggregate test by design to avoid parallel test interleaving
 /// with the global ProxyAllocator state.
@@ -608,6 +673,14 @@ fn all_heap_zeroed() {
         check_into_inner_vec_zeroed(size);
     }
 
+    // Zeroize-on-grow: growth past capacity must zeroize the old buffer.
+    for size in [16usize, 32, 64, 128] {
+        check_secure_extend_vec_zeroed(size);
+        check_secure_push_str_zeroed(size);
+        #[cfg(feature = "std")]
+        check_io_write_grow_zeroed(size);
+    }
+
     // Panic-path positive control: proves Zeroizing zeroes bytes on unwind.
     // Size 8192 avoids collision with panic-machinery allocations (see comment above).
     check_panic_path_bytes_zeroed(8192);
@@ -21,3 +21,22 @@ fn dynamic_alias_zero_size_accepted() {
     let val: LocalDynVec = vec![].into();
     val.with_secret(|s: &Vec<u8>| assert_eq!(s.len(), 0));
 }
+
+#[cfg(feature = "alloc")]
+dynamic_alias!(LabeledToken, String, label = "session_token");
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_alias_label_prints_label_in_debug() {
+    let token = LabeledToken::new("tok_123");
+    assert_eq!(
+        format!("{:?}", token),
+        if cfg!(feature = "redact-length") {
+            "[REDACTED session_token; 7 bytes]"
+        } else {
+            "[REDACTED session_token]"
+        }
+    );
+    token.with_secret(|s: &String| assert_eq!(s, "tok_123"));
+    assert_eq!(&*token.into_inner(), "tok_123");
+}
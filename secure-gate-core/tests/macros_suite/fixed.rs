@@ -1,6 +1,6 @@
 //! macros_suite/fixed.rs — fixed_alias macro tests
 
-use secure_gate::{RevealSecret, fixed_alias};
+use secure_gate::{RevealSecret, RevealSecretMut, fixed_alias};
 
 fixed_alias!(LocalFixed32, 32);
 
@@ -9,3 +9,45 @@ fn fixed_alias_basics() {
     let key: LocalFixed32 = [7u8; 32].into();
     key.with_secret(|s| assert_eq!(s, &[7u8; 32]));
 }
+
+fixed_alias!(LabeledKey, 16, label = "hmac_key");
+fixed_alias!(pub DocLabeledKey, 8, "Key with a custom doc.", label = "doc_key");
+
+#[test]
+fn fixed_alias_label_prints_label_in_debug() {
+    let mut key = LabeledKey::new([3u8; 16]);
+    assert_eq!(
+        format!("{:?}", key),
+        if cfg!(feature = "redact-length") {
+            "[REDACTED hmac_key; 16 bytes]"
+        } else {
+            "[REDACTED hmac_key]"
+        }
+    );
+    assert_eq!(key.len(), 16);
+    key.with_secret_mut(|b| b[0] = 9);
+    assert_eq!(key.expose_secret()[0], 9);
+    // Derefs to the wrapper (not the secret): the inner Fixed keeps its unlabeled Debug.
+    assert_eq!(
+        format!("{:?}", *key),
+        secure_gate::RedactionPolicy::DEFAULT.redact(16).to_string()
+    );
+
+    let from_fixed: DocLabeledKey = secure_gate::Fixed::new([1u8; 8]).into();
+    assert_eq!(*from_fixed.into_inner(), [1u8; 8]);
+    assert_eq!(
+        DocLabeledKey::REDACTION.label("x"),
+        LabeledKey::REDACTION.label("x")
+    );
+}
+
+#[test]
+fn fixed_alias_label_works_with_generic_reveal_code() {
+    fn first_byte<S: RevealSecret<Inner = [u8; 16]>>(s: &S) -> u8 {
+        s.with_secret(|b| b[0])
+    }
+    let key = LabeledKey::new([5u8; 16]);
+    assert_eq!(first_byte(&key), 5);
+    let fixed = key.into_secret();
+    assert_eq!(first_byte(&fixed), 5);
+}
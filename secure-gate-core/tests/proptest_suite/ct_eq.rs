@@ -3,7 +3,7 @@
 #[cfg(all(feature = "ct-eq", feature = "alloc"))]
 mod tests {
     use proptest::prelude::*;
-    use secure_gate::{ConstantTimeEq, dynamic_alias, fixed_alias};
+    use secure_gate::{ByteOrder, ConstantTimeEq, ConstantTimeOrd, dynamic_alias, fixed_alias};
 
     dynamic_alias!(TestDynamic, Vec<u8>);
     dynamic_alias!(TestDynamicStr, String);
@@ -79,5 +79,49 @@ mod tests {
             let db: TestDynamicStr = b.clone().into();
             prop_assert_eq!(da.ct_eq(&db), a == b);
         }
+
+        /// Ordering — big-endian ct_cmp must agree with lexicographic array
+        /// ordering, and little-endian with the ordering of the reversed bytes.
+        #[test]
+        fn fixed_array_ordering_correctness(
+            a in prop::array::uniform32(any::<u8>()),
+            b in prop::array::uniform32(any::<u8>())
+        ) {
+            let fa: TestFixed = TestFixed::new(a);
+            let fb: TestFixed = TestFixed::new(b);
+            let be: core::cmp::Ordering = fa.ct_cmp(&fb, ByteOrder::BigEndian).into();
+            prop_assert_eq!(be, a.cmp(&b));
+            prop_assert_eq!(bool::from(fa.ct_lt(&fb, ByteOrder::BigEndian)), a < b);
+            prop_assert_eq!(bool::from(fa.ct_gt(&fb, ByteOrder::BigEndian)), a > b);
+
+            let (mut ra, mut rb) = (a, b);
+            ra.reverse();
+            rb.reverse();
+            let le: core::cmp::Ordering = fa.ct_cmp(&fb, ByteOrder::LittleEndian).into();
+            prop_assert_eq!(le, ra.cmp(&rb));
+        }
+
+        /// Ordering for same-length Dynamic<Vec<u8>>, including antisymmetry.
+        #[test]
+        fn dynamic_vec_ordering_correctness(
+            pair in (0usize..=64).prop_flat_map(|n| (
+                prop::collection::vec(any::<u8>(), n),
+                prop::collection::vec(any::<u8>(), n),
+            ))
+        ) {
+            let (a, b) = pair;
+            let da: TestDynamic = a.clone().into();
+            let db: TestDynamic = b.clone().into();
+            let cmp = |x: &TestDynamic, y: &TestDynamic, order| {
+                x.ct_cmp(y, order).expect("same length")
+            };
+            let be: core::cmp::Ordering = cmp(&da, &db, ByteOrder::BigEndian).into();
+            prop_assert_eq!(be, a.cmp(&b));
+            prop_assert_eq!(
+                bool::from(cmp(&da, &db, ByteOrder::LittleEndian).is_lt()),
+                bool::from(cmp(&db, &da, ByteOrder::LittleEndian).is_gt())
+            );
+            prop_assert!(bool::from(cmp(&da, &da, ByteOrder::LittleEndian).is_eq()));
+        }
     }
 }
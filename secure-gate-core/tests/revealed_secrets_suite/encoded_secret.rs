@@ -19,8 +19,18 @@ fn encoded_secret_needs_drop() {
 #[test]
 fn encoded_secret_debug_is_redacted() {
     let encoded = sample_hex_secret();
-    assert_eq!(format!("{encoded:?}"), "[REDACTED]");
-    assert_eq!(format!("{encoded:#?}"), "[REDACTED]");
+    assert_eq!(
+        format!("{encoded:?}"),
+        secure_gate::RedactionPolicy::DEFAULT
+            .redact(encoded.len())
+            .to_string()
+    );
+    assert_eq!(
+        format!("{encoded:#?}"),
+        secure_gate::RedactionPolicy::DEFAULT
+            .redact(encoded.len())
+            .to_string()
+    );
 }
 
 #[cfg(feature = "encoding-hex")]
@@ -77,7 +87,12 @@ fn encoded_secret_empty_string() {
     let empty: [u8; 0] = [];
     let encoded = empty.to_hex_zeroizing();
 
-    assert_eq!(format!("{encoded:?}"), "[REDACTED]");
+    assert_eq!(
+        format!("{encoded:?}"),
+        secure_gate::RedactionPolicy::DEFAULT
+            .redact(encoded.len())
+            .to_string()
+    );
     assert_eq!(format!("{encoded}"), "");
     assert_eq!(&*encoded, "");
     assert!(encoded.is_empty());
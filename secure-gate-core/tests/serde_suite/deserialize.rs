@@ -24,6 +24,16 @@ fn dynamic_deserialize_from_string() {
     assert_eq!(result.expose_secret(), "hello");
 }
 
+#[cfg(feature = "serde-deserialize")]
+#[test]
+fn dynamic_unsized_deserialize() {
+    use secure_gate::{Dynamic, RevealSecret};
+    let bytes: Dynamic<[u8]> = serde_json::from_str("[1,2,3,4]").expect("deserialize");
+    assert_eq!(bytes.expose_secret(), &[1, 2, 3, 4]);
+    let pw: Dynamic<str> = serde_json::from_str("\"hello\"").expect("deserialize");
+    assert_eq!(pw.expose_secret(), "hello");
+}
+
 #[cfg(feature = "serde-deserialize")]
 #[test]
 fn fixed_deserialize_wrong_length() {
@@ -222,3 +232,43 @@ fn dynamic_vec_deserialize_with_limit_one() {
         .expect("single element within limit 1");
     assert_eq!(result.expose_secret(), &[42u8]);
 }
+
+#[cfg(feature = "serde-deserialize")]
+#[test]
+fn dynamic_unsized_deserialize_with_limit() {
+    use secure_gate::Dynamic;
+    let mut de = serde_json::Deserializer::from_str("[1,2,3,4]");
+    assert!(Dynamic::<[u8]>::deserialize_with_limit(&mut de, 3).is_err());
+    let mut de = serde_json::Deserializer::from_str("\"hello\"");
+    assert!(Dynamic::<str>::deserialize_with_limit(&mut de, 5).is_ok());
+}
+
+#[cfg(feature = "serde-deserialize")]
+#[test]
+fn dynamic_unsized_try_deserialize_with_limit() {
+    use secure_gate::{Dynamic, RevealSecret};
+    let mut de = serde_json::Deserializer::from_str("[1,2,3]");
+    let bytes = Dynamic::<[u8]>::try_deserialize_with_limit(&mut de, 3).expect("within limit");
+    assert_eq!(bytes.expose_secret(), &[1, 2, 3]);
+    let mut de = serde_json::Deserializer::from_str("[1,2,3,4]");
+    assert!(Dynamic::<[u8]>::try_deserialize_with_limit(&mut de, 3).is_err());
+    let mut de = serde_json::Deserializer::from_str("\"hello\"");
+    let text = Dynamic::<str>::try_deserialize_with_limit(&mut de, 5).expect("within limit");
+    assert_eq!(text.expose_secret(), "hello");
+}
+
+#[cfg(feature = "serde-deserialize")]
+#[test]
+fn dynamic_growable_try_deserialize_with_limit() {
+    use secure_gate::{Dynamic, RevealSecret};
+    let mut de = serde_json::Deserializer::from_str("[1,2,3]");
+    let bytes = Dynamic::<Vec<u8>>::try_deserialize_with_limit(&mut de, 3).expect("within limit");
+    assert_eq!(bytes.expose_secret(), &[1, 2, 3]);
+    let mut de = serde_json::Deserializer::from_str("[1,2,3,4]");
+    assert!(Dynamic::<Vec<u8>>::try_deserialize_with_limit(&mut de, 3).is_err());
+    let mut de = serde_json::Deserializer::from_str("\"hello\"");
+    let text = Dynamic::<String>::try_deserialize_with_limit(&mut de, 5).expect("within limit");
+    assert_eq!(text.expose_secret(), "hello");
+    let mut de = serde_json::Deserializer::from_str("\"hello!\"");
+    assert!(Dynamic::<String>::try_deserialize_with_limit(&mut de, 5).is_err());
+}
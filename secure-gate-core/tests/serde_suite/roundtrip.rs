@@ -82,7 +82,7 @@ fn fixed_wrapper_serializes_correctly() {
 
     let secret = Fixed::new(SecretKey([10, 20, 30, 40]));
     // Security invariant: debug redaction must hold even with serde-serialize enabled.
-    crate::common::assert_redacted_debug(&secret);
+    crate::common::assert_redacted_debug_len(&secret, 4);
     let json = serde_json::to_string(&secret).expect("serialize Fixed<SecretKey>");
     // The wrapper serializes transparently — verify by parsing the raw bytes back.
     let raw: Vec<u8> = serde_json::from_str(&json).expect("parse JSON");
@@ -0,0 +1,246 @@
+//! Structured redacted tracing values (`tracing` feature).
+//!
+//! A capturing subscriber records every span and event field as text, and the
+//! tests assert that no rendering of the secret bytes ever reaches it. The
+//! subscriber is installed per-thread with `with_default`, so tests can run in
+//! parallel.
+
+#![cfg(feature = "tracing")]
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "alloc")]
+use secure_gate::Dynamic;
+use secure_gate::{Fixed, RedactionPolicy, RevealSecret};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Secret bytes chosen so every rendering is easy to grep for.
+const KEY_BYTES: [u8; 32] = [0xA7; 32];
+const PASSWORD: &str = "correct-horse-battery-staple";
+
+/// Renderings of the secrets that must never appear in a captured field.
+const NEEDLES: &[&str] = &[
+    PASSWORD,
+    "167, 167",   // Debug of [0xA7; N]
+    "a7a7a7a7",   // lower hex
+    "A7A7A7A7",   // upper hex
+    "0xa7, 0xa7", // {:#x?}
+    "p6en",       // base64url of 0xA7 0xA7 0xA7 …
+];
+
+/// Subscriber that stores `name=value` for every field it sees.
+#[derive(Clone, Default)]
+struct Capture {
+    fields: Arc<Mutex<Vec<String>>>,
+    next_id: Arc<Mutex<u64>>,
+}
+
+struct Collect<'a>(&'a mut Vec<String>);
+
+impl Visit for Collect<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push(format!("{}={:?}", field.name(), value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push(format!("{}={}", field.name(), value));
+    }
+}
+
+impl Capture {
+    fn fields(&self) -> Vec<String> {
+        self.fields.lock().unwrap().clone()
+    }
+
+    fn assert_no_secret(&self) {
+        let fields = self.fields();
+        assert!(!fields.is_empty(), "subscriber captured nothing");
+        for field in &fields {
+            for needle in NEEDLES {
+                assert!(!field.contains(needle), "secret leaked into `{field}`");
+            }
+        }
+    }
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        span.record(&mut Collect(&mut self.fields.lock().unwrap()));
+        let mut id = self.next_id.lock().unwrap();
+        *id += 1;
+        Id::from_u64(*id)
+    }
+
+    fn record(&self, _: &Id, values: &Record<'_>) {
+        values.record(&mut Collect(&mut self.fields.lock().unwrap()));
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut Collect(&mut self.fields.lock().unwrap()));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[tracing::instrument]
+fn load_key(key: &Fixed<[u8; 32]>) -> u8 {
+    tracing::info!(key = %key.trace_record(), "loaded");
+    key.expose_secret()[0]
+}
+
+#[cfg(feature = "alloc")]
+#[tracing::instrument(skip(password), fields(password = %password.trace_record()))]
+fn login(user: &str, password: &Dynamic<String>) -> usize {
+    tracing::warn!(?password, "debug sigil stays redacted");
+    password.len()
+}
+
+#[test]
+fn instrumented_spans_and_events_never_carry_secret_bytes() {
+    let capture = Capture::default();
+    tracing::subscriber::with_default(capture.clone(), || {
+        let key = Fixed::new(KEY_BYTES);
+        assert_eq!(load_key(&key), 0xA7);
+        tracing::info!(?key, redacted = %key.redacted(RedactionPolicy::BARE.label("k").length_hint(true)));
+
+        let inner = Fixed::new(KEY_BYTES).into_inner();
+        tracing::info!(?inner, record = %inner.trace_record());
+
+        #[cfg(feature = "alloc")]
+        {
+            let pw = Dynamic::<String>::new(PASSWORD.to_string());
+            assert_eq!(login("alice", &pw), PASSWORD.len());
+            let span = tracing::info_span!("session", token = tracing::field::Empty);
+            span.record("token", tracing::field::display(pw.trace_record()));
+        }
+
+        #[cfg(feature = "encoding-hex")]
+        {
+            use secure_gate::ToHex;
+            let encoded = KEY_BYTES.to_hex_zeroizing();
+            tracing::info!(?encoded, record = %encoded.trace_record());
+        }
+    });
+    capture.assert_no_secret();
+
+    let fields = capture.fields();
+    let debug = RedactionPolicy::DEFAULT.redact(32);
+    assert!(fields.contains(&format!("key={debug}")));
+    assert!(fields.contains(&"redacted=[REDACTED k; 32 bytes]".to_string()));
+    assert!(
+        fields
+            .iter()
+            .any(|f| f.starts_with("key=[REDACTED ") && f.ends_with("Fixed<[u8; 32]>; 32 bytes]"))
+    );
+    #[cfg(feature = "alloc")]
+    assert!(
+        fields
+            .iter()
+            .any(|f| f.starts_with("password=[REDACTED ") && f.ends_with("; 28 bytes]"))
+    );
+}
+
+#[cfg(feature = "fingerprint")]
+#[test]
+fn fingerprinted_records_correlate_without_leaking() {
+    use secure_gate::FingerprintKey;
+
+    let fp_key = FingerprintKey::new([1u8; 32]);
+    let key = Fixed::new(KEY_BYTES);
+    let fp = key.fingerprint(&fp_key);
+    let record = key.trace_record().with_fingerprint(fp);
+    assert_eq!(record.fingerprint(), Some(fp));
+
+    let capture = Capture::default();
+    tracing::subscriber::with_default(capture.clone(), || {
+        tracing::info!(key = %record, "first use");
+        tracing::info!(key = %Fixed::new(KEY_BYTES).trace_record().with_fingerprint(fp), "second use");
+    });
+    capture.assert_no_secret();
+    let fields = capture.fields();
+    let keys: Vec<_> = fields.iter().filter(|f| f.starts_with("key=")).collect();
+    assert_eq!(keys.len(), 2);
+    assert_eq!(keys[0], keys[1]);
+    assert!(keys[0].ends_with(&format!("; fingerprint {fp}]")));
+}
+
+/// `valuable` visitor that flattens a structured value into `name=value` pairs.
+#[derive(Default)]
+struct Flatten(Vec<String>);
+
+impl valuable::Visit for Flatten {
+    fn visit_value(&mut self, value: valuable::Value<'_>) {
+        self.0.push(format!("{value:?}"));
+    }
+
+    fn visit_named_fields(&mut self, named: &valuable::NamedValues<'_>) {
+        for (field, value) in named {
+            self.0.push(format!("{}={:?}", field.name(), value));
+        }
+    }
+}
+
+fn flatten(value: &dyn valuable::Valuable) -> Vec<String> {
+    let mut out = Flatten::default();
+    value.visit(&mut out);
+    out.0
+}
+
+#[test]
+fn valuable_emits_type_and_length_only() {
+    use valuable::{Fields, Valuable, Value};
+
+    let key = Fixed::new(KEY_BYTES);
+    let Value::Structable(s) = key.as_value() else {
+        panic!("expected a structable");
+    };
+    let definition = s.definition();
+    assert_eq!(definition.name(), "Redacted");
+    let Fields::Named(names) = definition.fields() else {
+        panic!("expected named fields");
+    };
+    let names: Vec<_> = names.iter().map(|f| f.name()).collect();
+    assert_eq!(names, ["type", "len"]);
+
+    let fields = flatten(&key);
+    assert_eq!(fields.len(), 2);
+    assert!(fields[0].starts_with("type=") && fields[0].contains("Fixed<[u8; 32]>"));
+    assert_eq!(fields[1], "len=32");
+
+    #[cfg(feature = "alloc")]
+    {
+        let pw = Dynamic::<String>::new(PASSWORD.to_string());
+        let fields = flatten(&pw);
+        assert_eq!(fields[1], "len=28");
+        assert!(fields.iter().all(|f| !f.contains(PASSWORD)));
+    }
+}
+
+#[cfg(feature = "fingerprint")]
+#[test]
+fn valuable_record_includes_attached_fingerprint() {
+    use secure_gate::FingerprintKey;
+
+    let fp_key = FingerprintKey::new([2u8; 32]);
+    let key = Fixed::new(KEY_BYTES);
+    let fp = key.fingerprint(&fp_key);
+    let fields = flatten(&key.trace_record().with_fingerprint(fp));
+    assert_eq!(fields.len(), 3);
+    assert_eq!(fields[2], format!("fingerprint={:?}", fp.to_string()));
+    for field in &fields {
+        for needle in NEEDLES {
+            assert!(!field.contains(needle), "secret leaked into `{field}`");
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Parsing of `#[secret(...)]` container and field attributes.
+
+use syn::{Attribute, Result};
+
+/// Options from `#[secret(...)]` on the struct itself.
+#[derive(Default)]
+pub(crate) struct ContainerOpts {
+    pub(crate) ct_eq: bool,
+    pub(crate) cloneable: bool,
+    pub(crate) serializable: bool,
+}
+
+/// Options from `#[secret(...)]` on one field.
+#[derive(Default)]
+pub(crate) struct FieldOpts {
+    pub(crate) skip: bool,
+    pub(crate) ct_eq: bool,
+}
+
+impl ContainerOpts {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut opts = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("secret")) {
+            attr.parse_nested_meta(|meta| {
+                let flag = if meta.path.is_ident("ct_eq") {
+                    &mut opts.ct_eq
+                } else if meta.path.is_ident("cloneable") {
+                    &mut opts.cloneable
+                } else if meta.path.is_ident("serializable") {
+                    &mut opts.serializable
+                } else {
+                    return Err(meta.error(
+                        "unknown struct attribute; expected `ct_eq`, `cloneable` or `serializable`",
+                    ));
+                };
+                set_once(flag, &meta)
+            })?;
+        }
+        Ok(opts)
+    }
+}
+
+impl FieldOpts {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut opts = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("secret")) {
+            attr.parse_nested_meta(|meta| {
+                let flag = if meta.path.is_ident("skip") {
+                    &mut opts.skip
+                } else if meta.path.is_ident("ct_eq") {
+                    &mut opts.ct_eq
+                } else {
+                    return Err(meta.error("unknown field attribute; expected `skip` or `ct_eq`"));
+                };
+                set_once(flag, &meta)
+            })?;
+        }
+        Ok(opts)
+    }
+}
+
+fn set_once(flag: &mut bool, meta: &syn::meta::ParseNestedMeta<'_>) -> Result<()> {
+    if *flag {
+        return Err(meta.error("duplicate `secret` attribute"));
+    }
+    *flag = true;
+    Ok(())
+}
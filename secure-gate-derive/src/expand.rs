@@ -0,0 +1,189 @@
+//! Code generation for `#[derive(SecretStruct)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Member, Result};
+
+use crate::attr::{ContainerOpts, FieldOpts};
+
+struct Field<'a> {
+    member: Member,
+    field: &'a syn::Field,
+    opts: FieldOpts,
+}
+
+pub(crate) fn secret_struct(input: &DeriveInput) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "`SecretStruct` can only be derived for structs",
+        ));
+    };
+    let container = ContainerOpts::parse(&input.attrs)?;
+    let fields = data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(i.into()),
+            };
+            let opts = FieldOpts::parse(&field.attrs)?;
+            if opts.ct_eq && !opts.skip {
+                return Err(Error::new_spanned(
+                    field,
+                    "secret fields are always compared; field-level `ct_eq` only opts \
+                     `#[secret(skip)]` metadata into the comparison",
+                ));
+            }
+            if opts.ct_eq && !container.ct_eq {
+                return Err(Error::new_spanned(
+                    field,
+                    "`#[secret(skip, ct_eq)]` requires `#[secret(ct_eq)]` on the struct",
+                ));
+            }
+            Ok(Field {
+                member,
+                field,
+                opts,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let zeroize = quote!(::secure_gate::__private::zeroize);
+
+    let debug_body = debug_body(name, &data.fields, &fields);
+
+    let zeroize_fields = fields.iter().filter(|f| !f.opts.skip).map(|f| {
+        let m = &f.member;
+        quote!(#zeroize::Zeroize::zeroize(&mut self.#m);)
+    });
+
+    let sentinel_values = fields.iter().map(|f| {
+        if f.opts.skip {
+            quote!(::core::default::Default::default())
+        } else {
+            quote!(::secure_gate::SentinelValue::sentinel_value())
+        }
+    });
+    let sentinel = match &data.fields {
+        Fields::Named(_) => {
+            let members = fields.iter().map(|f| &f.member);
+            quote!(Self { #(#members: #sentinel_values),* })
+        }
+        Fields::Unnamed(_) => quote!(Self(#(#sentinel_values),*)),
+        Fields::Unit => quote!(Self),
+    };
+
+    let ct_eq = container.ct_eq.then(|| {
+        let compared = fields
+            .iter()
+            .filter(|f| !f.opts.skip || f.opts.ct_eq)
+            .map(|f| {
+                let m = &f.member;
+                quote!(& ::secure_gate::ConstantTimeEq::ct_eq_choice(&self.#m, &other.#m))
+            });
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::secure_gate::ConstantTimeEq for #name #ty_generics #where_clause {
+                #[inline]
+                fn ct_eq(&self, other: &Self) -> bool {
+                    ::secure_gate::ConstantTimeEq::ct_eq_choice(self, other).into()
+                }
+
+                #[inline]
+                fn ct_eq_choice(&self, other: &Self) -> ::secure_gate::Choice {
+                    ::secure_gate::Choice::from(1u8) #(#compared)*
+                }
+            }
+        }
+    });
+
+    let cloneable = container.cloneable.then(|| {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::secure_gate::CloneableSecret for #name #ty_generics #where_clause {}
+        }
+    });
+
+    let serializable = container.serializable.then(|| {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::secure_gate::SerializableSecret for #name #ty_generics #where_clause {}
+        }
+    });
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #debug_body
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #zeroize::Zeroize for #name #ty_generics #where_clause {
+            fn zeroize(&mut self) {
+                #(#zeroize_fields)*
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::ops::Drop for #name #ty_generics #where_clause {
+            fn drop(&mut self) {
+                #zeroize::Zeroize::zeroize(self);
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #zeroize::ZeroizeOnDrop for #name #ty_generics #where_clause {}
+
+        #[automatically_derived]
+        impl #impl_generics ::secure_gate::SentinelValue for #name #ty_generics #where_clause {
+            fn sentinel_value() -> Self {
+                #sentinel
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::secure_gate::OpaqueSecret for #name #ty_generics #where_clause {}
+
+        #ct_eq
+        #cloneable
+        #serializable
+    })
+}
+
+/// `Debug` body: secret fields print `[REDACTED]`, skipped fields print their value.
+fn debug_body(name: &syn::Ident, shape: &Fields, fields: &[Field<'_>]) -> TokenStream {
+    let name_str = name.to_string();
+    let value = |f: &Field<'_>| {
+        let m = &f.member;
+        if f.opts.skip {
+            quote!(&self.#m)
+        } else {
+            quote!(&::core::format_args!("[REDACTED]"))
+        }
+    };
+    match shape {
+        Fields::Named(_) => {
+            let entries = fields.iter().map(|f| {
+                let label = f.field.ident.as_ref().map(ToString::to_string);
+                let value = value(f);
+                quote!(.field(#label, #value))
+            });
+            quote!(f.debug_struct(#name_str) #(#entries)* .finish())
+        }
+        Fields::Unnamed(_) => {
+            let entries = fields.iter().map(|f| {
+                let value = value(f);
+                quote!(.field(#value))
+            });
+            quote!(f.debug_tuple(#name_str) #(#entries)* .finish())
+        }
+        Fields::Unit => quote!(f.write_str(#name_str)),
+    }
+}
@@ -0,0 +1,48 @@
+//! Derive macro for [`secure-gate`](https://docs.rs/secure-gate) composite secrets.
+//!
+//! Do not depend on this crate directly. Enable the `derive` feature of
+//! `secure-gate` and use the re-export, `secure_gate::SecretStruct`; the generated
+//! code refers to `::secure_gate` paths and only resolves through that crate.
+//!
+//! # What the derive generates
+//!
+//! | Impl | When | Notes |
+//! |---|---|---|
+//! | `Debug` | always | Secret fields print `[REDACTED]`; `#[secret(skip)]` fields print normally |
+//! | `Zeroize` | always | Zeroizes every field except `#[secret(skip)]` ones |
+//! | `Drop` + `ZeroizeOnDrop` | always | Drop calls `zeroize()` |
+//! | `SentinelValue` | always | Secret fields use their own `SentinelValue`; skipped fields use `Default` |
+//! | `OpaqueSecret` | always | Makes `Fixed<T>` / `Dynamic<T>` around the struct revealable |
+//! | `ConstantTimeEq` | `#[secret(ct_eq)]` on the struct | Needs `ct-eq`; compares every secret field in constant time |
+//! | `CloneableSecret` | `#[secret(cloneable)]` on the struct | Needs `cloneable`; you still derive `Clone` |
+//! | `SerializableSecret` | `#[secret(serializable)]` on the struct | Needs `serde-serialize`; you still derive `Serialize` |
+//!
+//! # Field attributes
+//!
+//! - `#[secret(skip)]` — public metadata (a key id, an algorithm tag). Not
+//!   zeroized, shown in `Debug`, left out of `ConstantTimeEq`. The field type
+//!   must implement `Debug` and `Default`.
+//! - `#[secret(skip, ct_eq)]` — also compare this metadata field in the derived
+//!   `ConstantTimeEq` (its type must implement `secure_gate::ConstantTimeEq`).
+//!
+//! Generic structs must bound their parameters on the struct itself (for
+//! example `struct Keys<K: Zeroize>`): the generated `Drop` impl cannot add bounds
+//! the struct definition does not have.
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, parse_macro_input};
+
+mod attr;
+mod expand;
+
+/// Derives the secret-handling boilerplate for a composite key struct.
+///
+/// See the [crate-level documentation](crate) for the generated impls and the
+/// `#[secret(...)]` attributes.
+#[proc_macro_derive(SecretStruct, attributes(secret))]
+pub fn derive_secret_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::secret_struct(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
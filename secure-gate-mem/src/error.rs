@@ -0,0 +1,36 @@
+//! Error types for secure-gate-mem.
+//!
+//! # Error types
+//!
+//! | Type | Produced by |
+//! |------|------------|
+//! | [`LockError`] | [`Lockable::lock`](crate::Lockable::lock), `Guarded::try_new*` (Unix) |
+//!
+//! Like the core crate's errors, these are heap-free, `Copy` and
+//! `#[non_exhaustive]`. They never carry addresses or secret-derived data — only
+//! a coarse category and, for unclassified failures, the raw OS error code.
+
+use thiserror::Error;
+
+/// Error returned when a secret's memory cannot be mapped, protected, locked, or
+/// excluded from core dumps.
+///
+/// On failure nothing stays locked: any partial protection is rolled back before
+/// the error is returned, and the secret is dropped (and zeroized) by the caller.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum LockError {
+    /// The per-process locked-memory limit would be exceeded (`RLIMIT_MEMLOCK` on
+    /// Unix, the working-set quota on Windows).
+    #[error("locked-memory limit exceeded")]
+    LimitExceeded,
+    /// The process lacks the privilege to lock memory.
+    #[error("insufficient privileges to lock memory")]
+    PermissionDenied,
+    /// Memory locking is not available on this target.
+    #[error("memory locking is not supported on this platform")]
+    Unsupported,
+    /// Any other OS failure, with the raw error code (`errno` / `GetLastError`).
+    #[error("OS error {0} while changing memory protection")]
+    Os(i32),
+}
@@ -0,0 +1,539 @@
+//! Guard-page protected secrets that stay inaccessible while not revealed.
+//!
+//! [`Guarded<T>`] is modelled on libsodium's `sodium_malloc`. Each secret gets its
+//! own anonymous mapping:
+//!
+//! ```text
+//! | guard page | canary · · · · · · · · · secret | guard page |
+//!   PROT_NONE  |<------ data pages ------------->|  PROT_NONE
+//! ```
+//!
+//! - The secret is placed at the **end** of the data pages, so reading or writing
+//!   one byte past it hits the trailing guard page and faults immediately.
+//! - A 16-byte per-process random **canary** sits directly in front of it. It is
+//!   checked on drop; a mismatch means something underflowed the buffer, and the
+//!   process aborts.
+//! - The data pages are `PROT_NONE` whenever no reveal is in progress.
+//!   `with_secret` switches them to read-only and `with_secret_mut` to
+//!   read-write for the duration of the closure only. A stray read of a dangling
+//!   pointer outside a reveal therefore segfaults instead of leaking.
+//! - The data pages are locked in RAM and excluded from core dumps where the
+//!   platform allows (best effort — failures are ignored, as in libsodium).
+//! - On drop the data pages are zeroized, then unlocked and unmapped.
+//!
+//! # Example
+//!
+//! ```rust
+//! use secure_gate::{RevealSecret, RevealSecretMut};
+//! use secure_gate_mem::Guarded;
+//!
+//! let mut key = Guarded::<[u8; 32]>::new_with(|k| k.fill(0x42));
+//! // Pages are PROT_NONE here; readable only inside the closure.
+//! assert_eq!(key.with_secret(|k| k[0]), 0x42);
+//! key.with_secret_mut(|k| k[0] = 0);
+//! assert_eq!(format!("{key:?}"), "[REDACTED]");
+//! ```
+//!
+//! # Same API as `Dynamic`
+//!
+//! `Guarded<[u8]>` and `Guarded<[u8; N]>` implement [`RevealSecret`] and
+//! [`RevealSecretMut`], so generic code written against those traits works
+//! unchanged after a type swap from `Dynamic<[u8]>` / `Dynamic<[u8; N]>`.
+//!
+//! # Direct access downgrades protection
+//!
+//! [`expose_secret`](secure_gate::RevealSecret::expose_secret) returns a
+//! reference whose lifetime the wrapper cannot observe, so the pages can no longer
+//! be closed afterwards. Calling it **permanently** lowers the idle protection
+//! to read-only (`expose_secret_mut`: read-write) for the rest of the value's
+//! life. The guard pages and the canary remain effective. Prefer the scoped
+//! `with_secret` / `with_secret_mut`.
+//!
+//! # Cost
+//!
+//! Every scoped reveal is two `mprotect` system calls, and every value occupies at
+//! least three pages of address space. Use `Guarded<T>` for a handful of
+//! long-lived keys, not for bulk data.
+//!
+//! # Platform
+//!
+//! Unix only (`mmap` / `mprotect`). `Guarded<T>` is `Send` but not `Sync`: the
+//! reader count that decides when to close the pages is not atomic.
+
+use core::cell::Cell;
+use core::marker::PhantomData;
+use std::sync::OnceLock;
+
+use secure_gate::{Fixed, InnerSecret, RevealSecret, RevealSecretMut, SentinelValue};
+use zeroize::Zeroize;
+
+use crate::LockError;
+use crate::sys::{self, PageRange, Protection};
+
+const CANARY_LEN: usize = 16;
+
+/// Random per-process canary, drawn on first use.
+fn canary() -> &'static [u8; CANARY_LEN] {
+    static CANARY: OnceLock<[u8; CANARY_LEN]> = OnceLock::new();
+    CANARY.get_or_init(|| {
+        let mut c = [0u8; CANARY_LEN];
+        getrandom::fill(&mut c).expect("OS random number generator unavailable");
+        c
+    })
+}
+
+/// Secret stored in its own guard-paged mapping, `PROT_NONE` while not revealed.
+///
+/// Implemented for `Guarded<[u8]>` and `Guarded<[u8; N]>`. See the
+/// [module docs](crate::guarded) for the memory layout and guarantees.
+/// `Debug` prints `[REDACTED]`.
+pub struct Guarded<T: ?Sized> {
+    /// Whole mapping, guard pages included.
+    base: *mut u8,
+    map_len: usize,
+    /// Pages between the two guard pages.
+    data_pages: PageRange,
+    /// First byte of the secret (`len` bytes, ending at the trailing guard page).
+    data: *mut u8,
+    len: usize,
+    /// Protection left in place when no reveal is running. Only ever raised.
+    idle: Cell<Protection>,
+    /// Nested `with_secret` calls in progress.
+    readers: Cell<usize>,
+    locked: bool,
+    dump_excluded: bool,
+    _marker: PhantomData<Box<T>>,
+}
+
+// SAFETY: `Guarded` exclusively owns its mapping, like a `Box<T>`. It is not
+// `Sync` (the `Cell` reader count is not atomic), so moving it between threads
+// cannot race on the protection state.
+unsafe impl<T: ?Sized> Send for Guarded<T> {}
+
+impl<T: ?Sized> Guarded<T> {
+    /// Maps a fresh guarded region for `len` secret bytes, zero-filled and left
+    /// read-write for initialization.
+    ///
+    /// A `len` whose region size overflows `usize` fails with `ENOMEM`, as
+    /// `mmap` itself would for a region that large.
+    fn map(len: usize) -> Result<Self, LockError> {
+        let page = sys::page_size();
+        let too_large = LockError::Os(libc::ENOMEM);
+        let data_len = len
+            .checked_add(CANARY_LEN)
+            .and_then(|n| n.div_ceil(page).checked_mul(page))
+            .ok_or(too_large)?;
+        let map_len = page
+            .checked_mul(2)
+            .and_then(|guards| data_len.checked_add(guards))
+            .ok_or(too_large)?;
+        let base = sys::map(map_len)?;
+        let start = base as usize + page;
+        let data_pages = PageRange {
+            start,
+            len: data_len,
+        };
+        let data = (start + data_len - len) as *mut u8;
+        // Written first: `Drop` checks it even if a later step fails.
+        // SAFETY: the canary lies inside the read-write data pages; nothing else
+        // references them yet.
+        unsafe {
+            core::ptr::copy_nonoverlapping(canary().as_ptr(), data.sub(CANARY_LEN), CANARY_LEN);
+        }
+        let mut this = Self {
+            base,
+            map_len,
+            data_pages,
+            data,
+            len,
+            idle: Cell::new(Protection::ReadWrite),
+            readers: Cell::new(0),
+            locked: false,
+            dump_excluded: false,
+            _marker: PhantomData,
+        };
+        let guards = [
+            PageRange {
+                start: base as usize,
+                len: page,
+            },
+            PageRange {
+                start: start + data_len,
+                len: page,
+            },
+        ];
+        for guard in guards {
+            // SAFETY: the guard pages lie inside our mapping and are never
+            // referenced.
+            unsafe { sys::protect(guard, Protection::NoAccess)? };
+        }
+        this.locked = sys::lock(data_pages).is_ok();
+        this.dump_excluded = sys::exclude_from_dump(data_pages).unwrap_or(false);
+        Ok(this)
+    }
+
+    /// Closes the data pages after initialization.
+    fn seal(self) -> Self {
+        self.idle.set(Protection::NoAccess);
+        self.set_protection(Protection::NoAccess);
+        self
+    }
+
+    fn set_protection(&self, prot: Protection) {
+        // SAFETY: `data_pages` lies inside our mapping. Protection is only
+        // lowered when no reveal is running (`readers == 0`, or `&mut self`)
+        // and the idle level never drops below what `expose_secret*` handed out,
+        // so no live reference is invalidated.
+        unsafe { sys::protect(self.data_pages, prot) }
+            .expect("mprotect failed on a Guarded secret region");
+    }
+
+    /// Opens the pages for reading until the returned token drops.
+    fn open_read(&self) -> ReadToken<'_, T> {
+        let n = self.readers.get();
+        if n == 0 && self.idle.get() < Protection::ReadOnly {
+            self.set_protection(Protection::ReadOnly);
+        }
+        self.readers.set(n + 1);
+        ReadToken(self)
+    }
+
+    /// Opens the pages for writing until the returned token drops.
+    fn open_write(&mut self) -> WriteToken<'_, T> {
+        if self.idle.get() < Protection::ReadWrite {
+            self.set_protection(Protection::ReadWrite);
+        }
+        WriteToken(self)
+    }
+
+    /// Permanently raises the idle protection (direct access escape hatches).
+    fn raise_idle(&self, prot: Protection) {
+        if self.idle.get() < prot {
+            self.idle.set(prot);
+            self.set_protection(prot);
+        }
+    }
+
+    fn data_slice(&self) -> &[u8] {
+        // SAFETY: `data` points at `len` initialized bytes inside our mapping;
+        // every caller holds the pages open for reading for the borrow's lifetime.
+        unsafe { core::slice::from_raw_parts(self.data, self.len) }
+    }
+
+    fn data_slice_mut(&mut self) -> &mut [u8] {
+        // SAFETY: as for `data_slice`, with exclusive access through `&mut self`
+        // and the pages open for writing.
+        unsafe { core::slice::from_raw_parts_mut(self.data, self.len) }
+    }
+
+    /// `true` if the data pages are locked in RAM (best effort at construction).
+    #[inline]
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// `true` if the data pages are excluded from core dumps. Always `false` on
+    /// platforms without per-range dump exclusion (macOS).
+    #[inline]
+    pub fn is_dump_excluded(&self) -> bool {
+        self.dump_excluded
+    }
+}
+
+/// Restores the idle protection when the last nested reader finishes, including
+/// on unwind.
+struct ReadToken<'a, T: ?Sized>(&'a Guarded<T>);
+
+impl<T: ?Sized> Drop for ReadToken<'_, T> {
+    fn drop(&mut self) {
+        let g = self.0;
+        let n = g.readers.get() - 1;
+        g.readers.set(n);
+        if n == 0 && g.idle.get() < Protection::ReadOnly {
+            g.set_protection(g.idle.get());
+        }
+    }
+}
+
+/// Restores the idle protection after a mutable reveal, including on unwind.
+struct WriteToken<'a, T: ?Sized>(&'a mut Guarded<T>);
+
+impl<T: ?Sized> Drop for WriteToken<'_, T> {
+    fn drop(&mut self) {
+        let g = &*self.0;
+        if g.idle.get() < Protection::ReadWrite {
+            g.set_protection(g.idle.get());
+        }
+    }
+}
+
+impl Guarded<[u8]> {
+    /// Copies `bytes` into a new guarded region.
+    ///
+    /// The source is not wiped — zeroize it yourself, or use
+    /// [`new_with`](Self::new_with) to write the secret in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region cannot be mapped; see [`try_new`](Self::try_new).
+    pub fn new(bytes: &[u8]) -> Self {
+        Self::try_new(bytes).expect("failed to map a Guarded secret region")
+    }
+
+    /// Fallible form of [`new`](Self::new).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LockError::Os`] if `mmap` or `mprotect` fails.
+    pub fn try_new(bytes: &[u8]) -> Result<Self, LockError> {
+        Self::try_new_with(bytes.len(), |buf| buf.copy_from_slice(bytes))
+    }
+
+    /// Maps a zero-filled region of `len` bytes and lets `f` write the secret
+    /// directly into it, so no copy ever exists outside the guarded pages.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region cannot be mapped. If `f` panics, the region is
+    /// zeroized and unmapped during unwinding.
+    pub fn new_with<F>(len: usize, f: F) -> Self
+    where
+        F: FnOnce(&mut [u8]),
+    {
+        Self::try_new_with(len, f).expect("failed to map a Guarded secret region")
+    }
+
+    /// Fallible form of [`new_with`](Self::new_with).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LockError::Os`] if `mmap` or `mprotect` fails, or with
+    /// `ENOMEM` if `len` is too large for the region size to fit in `usize`.
+    pub fn try_new_with<F>(len: usize, f: F) -> Result<Self, LockError>
+    where
+        F: FnOnce(&mut [u8]),
+    {
+        let mut this = Self::map(len)?;
+        f(this.data_slice_mut());
+        Ok(this.seal())
+    }
+}
+
+impl<const N: usize> Guarded<[u8; N]> {
+    /// Moves `bytes` into a new guarded region and zeroizes the argument.
+    ///
+    /// The array is passed by value, so a copy may remain in the caller's stack
+    /// frame; prefer [`new_with`](Self::new_with) for long-lived keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region cannot be mapped; see [`try_new`](Self::try_new).
+    pub fn new(bytes: [u8; N]) -> Self {
+        Self::try_new(bytes).expect("failed to map a Guarded secret region")
+    }
+
+    /// Fallible form of [`new`](Self::new). `bytes` is zeroized on success and
+    /// on failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LockError::Os`] if `mmap` or `mprotect` fails.
+    pub fn try_new(mut bytes: [u8; N]) -> Result<Self, LockError> {
+        let result = Self::try_new_with(|buf| buf.copy_from_slice(&bytes));
+        bytes.zeroize();
+        result
+    }
+
+    /// Maps a zero-filled region and lets `f` write the secret directly into it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region cannot be mapped. If `f` panics, the region is
+    /// zeroized and unmapped during unwinding.
+    pub fn new_with<F>(f: F) -> Self
+    where
+        F: FnOnce(&mut [u8; N]),
+    {
+        Self::try_new_with(f).expect("failed to map a Guarded secret region")
+    }
+
+    /// Fallible form of [`new_with`](Self::new_with).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LockError::Os`] if `mmap` or `mprotect` fails.
+    pub fn try_new_with<F>(f: F) -> Result<Self, LockError>
+    where
+        F: FnOnce(&mut [u8; N]),
+    {
+        let mut this = Self::map(N)?;
+        f(this
+            .data_slice_mut()
+            .try_into()
+            .expect("region length is N"));
+        Ok(this.seal())
+    }
+}
+
+/// Read-only access to a guarded byte slice.
+impl RevealSecret for Guarded<[u8]> {
+    type Inner = [u8];
+
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&[u8]) -> R,
+    {
+        let _open = self.open_read();
+        f(self.data_slice())
+    }
+
+    /// Permanently lowers the idle protection to read-only; see the
+    /// [module docs](crate::guarded#direct-access-downgrades-protection).
+    fn expose_secret(&self) -> &[u8] {
+        self.raise_idle(Protection::ReadOnly);
+        self.data_slice()
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        self.len
+    }
+
+    /// Never callable — `[u8]` is unsized, so the trait bounds cannot hold.
+    fn into_inner(self) -> InnerSecret<[u8]>
+    where
+        Self: Sized,
+        // `for<'a>` keeps the concrete (always-false) `[u8]: Sized` bound from
+        // being rejected as trivially unsatisfiable; it is still never met.
+        for<'a> Self::Inner: Sized + SentinelValue + Zeroize,
+    {
+        secure_gate::__private::unsized_into_inner()
+    }
+}
+
+/// Mutable access to a guarded byte slice.
+impl RevealSecretMut for Guarded<[u8]> {
+    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut [u8]) -> R,
+    {
+        let open = self.open_write();
+        f(open.0.data_slice_mut())
+    }
+
+    /// Permanently lowers the idle protection to read-write; see the
+    /// [module docs](crate::guarded#direct-access-downgrades-protection).
+    fn expose_secret_mut(&mut self) -> &mut [u8] {
+        self.raise_idle(Protection::ReadWrite);
+        self.data_slice_mut()
+    }
+}
+
+/// Read-only access to a guarded byte array.
+impl<const N: usize> RevealSecret for Guarded<[u8; N]> {
+    type Inner = [u8; N];
+
+    fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&[u8; N]) -> R,
+    {
+        let _open = self.open_read();
+        f(self.data_slice().try_into().expect("region length is N"))
+    }
+
+    /// Permanently lowers the idle protection to read-only; see the
+    /// [module docs](crate::guarded#direct-access-downgrades-protection).
+    fn expose_secret(&self) -> &[u8; N] {
+        self.raise_idle(Protection::ReadOnly);
+        self.data_slice().try_into().expect("region length is N")
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        N
+    }
+
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        N
+    }
+
+    /// Copies the secret out of the guarded region into a zeroize-on-drop
+    /// [`InnerSecret`], then zeroizes and unmaps the region.
+    fn into_inner(self) -> InnerSecret<[u8; N]>
+    where
+        Self: Sized,
+        Self::Inner: Sized + SentinelValue + Zeroize,
+    {
+        self.with_secret(|b| Fixed::new_with(|out| out.copy_from_slice(b)))
+            .into_inner()
+    }
+}
+
+/// Mutable access to a guarded byte array.
+impl<const N: usize> RevealSecretMut for Guarded<[u8; N]> {
+    fn with_secret_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut [u8; N]) -> R,
+    {
+        let open = self.open_write();
+        f(open
+            .0
+            .data_slice_mut()
+            .try_into()
+            .expect("region length is N"))
+    }
+
+    /// Permanently lowers the idle protection to read-write; see the
+    /// [module docs](crate::guarded#direct-access-downgrades-protection).
+    fn expose_secret_mut(&mut self) -> &mut [u8; N] {
+        self.raise_idle(Protection::ReadWrite);
+        self.data_slice_mut()
+            .try_into()
+            .expect("region length is N")
+    }
+}
+
+impl<T: ?Sized> Drop for Guarded<T> {
+    fn drop(&mut self) {
+        self.set_protection(Protection::ReadWrite);
+        // SAFETY: the pages are read-write, and the canary sits directly in front
+        // of the secret inside the data pages.
+        let canary_ok = unsafe {
+            core::slice::from_raw_parts(self.data.sub(CANARY_LEN), CANARY_LEN) == canary()
+        };
+        // SAFETY: the data pages are read-write and exclusively ours.
+        let pages = unsafe {
+            core::slice::from_raw_parts_mut(self.data_pages.start as *mut u8, self.data_pages.len)
+        };
+        pages.zeroize();
+        if !canary_ok {
+            // Something wrote in front of the secret. The heap state is no longer
+            // trustworthy; mirror libsodium and stop immediately.
+            std::process::abort();
+        }
+        if self.locked {
+            sys::unlock(self.data_pages);
+        }
+        if self.dump_excluded {
+            sys::include_in_dump(self.data_pages);
+        }
+        // SAFETY: `base`/`map_len` is exactly the region returned by `sys::map`,
+        // and `&mut self` proves no reference into it survives.
+        unsafe { sys::unmap(self.base, self.map_len) };
+    }
+}
+
+impl<T: ?Sized> zeroize::ZeroizeOnDrop for Guarded<T> {}
+
+/// Always prints `[REDACTED]`.
+impl<T: ?Sized> core::fmt::Debug for Guarded<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
@@ -0,0 +1,71 @@
+//! OS-level memory protection for [`secure-gate`](secure_gate) secrets.
+//!
+//! The core crate is `#![forbid(unsafe_code)]` and portable to `no_std`, so it
+//! cannot call `mlock` or `madvise`. This companion crate adds those protections
+//! for heap-backed [`Dynamic<T>`](secure_gate::Dynamic) secrets, plus a
+//! guard-paged `Guarded<T>` storage type:
+//!
+//! | Protection | Unix | Windows |
+//! |---|---|---|
+//! | Lock pages in RAM (no swap) | `mlock` | `VirtualLock` |
+//! | Exclude from core dumps | `MADV_DONTDUMP` (Linux, Android), `MADV_NOCORE` (FreeBSD, DragonFly) | — |
+//! | Guard pages + canary, `PROT_NONE` while not revealed | `Guarded<T>` (`mmap` / `mprotect`) | — |
+//!
+//! # Module tree
+//!
+//! ```text
+//! secure_gate_mem
+//! ├── Lockable      ← `.lock()` on Dynamic<Vec<T>>, Dynamic<String>, Dynamic<[T]>, …
+//! ├── LockGuard     ← keeps pages locked; zeroizes then unlocks on drop
+//! ├── Guarded<T>    ← sodium_malloc-style storage for [u8] / [u8; N] (Unix)
+//! └── error         ← LockError
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use secure_gate::Dynamic;
+//! use secure_gate_mem::{LockError, Lockable};
+//!
+//! let mut key: Dynamic<Vec<u8>> = Dynamic::new(vec![0x42u8; 64]);
+//! match key.lock() {
+//!     Ok(guard) => {
+//!         guard.with_secret(|k| assert_eq!(k[0], 0x42));
+//!         // guard drops here: zeroize, munlock, re-enable dumps
+//!     }
+//!     // e.g. RLIMIT_MEMLOCK too small in a container
+//!     Err(LockError::LimitExceeded | LockError::PermissionDenied) => {}
+//!     Err(e) => panic!("{e}"),
+//! }
+//! ```
+//!
+//! # Platform notes
+//!
+//! - Locking is page-granular; see [`lock`] for how neighbouring allocations
+//!   and shared pages behave.
+//! - Unprivileged Linux processes are limited by `RLIMIT_MEMLOCK` (often 64 KiB
+//!   or 8 MiB). Containers may set it lower.
+//! - Windows limits locked pages by the process working-set minimum; raise it with
+//!   `SetProcessWorkingSetSize` for large secrets.
+
+mod error;
+#[cfg(unix)]
+pub mod guarded;
+pub mod lock;
+mod sys;
+
+/// Heap-backed secret that can be locked in RAM — provides `.lock()`.
+pub use lock::Lockable;
+
+/// Guard returned by [`Lockable::lock`]: keeps the secret's pages locked and
+/// excluded from core dumps; zeroizes, unlocks and re-enables dumps on drop.
+pub use lock::LockGuard;
+
+/// Secret in its own guard-paged mapping, inaccessible (`PROT_NONE`) while not
+/// revealed (Unix only).
+#[cfg(unix)]
+pub use guarded::Guarded;
+
+/// Error returned when memory cannot be mapped, protected, locked, or excluded
+/// from core dumps.
+pub use error::LockError;
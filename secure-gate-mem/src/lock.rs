@@ -0,0 +1,181 @@
+//! Scoped memory locking for `Dynamic` secrets.
+//!
+//! [`Lockable::lock`] pins the heap buffer behind a [`Dynamic<T>`] in RAM and
+//! excludes it from core dumps for as long as the returned [`LockGuard`] lives.
+//! When the guard drops it, in order:
+//!
+//! 1. zeroizes the secret (the buffer is still locked, so no plaintext page can
+//!    be swapped out between use and wipe),
+//! 2. unlocks the pages,
+//! 3. re-enables core dumps for them, so memory later reused by the allocator
+//!    behaves normally.
+//!
+//! The guard holds `&mut Dynamic<T>` and only offers **read** access. A
+//! `Vec`/`String` therefore cannot reallocate out of the locked range while the
+//! guard exists.
+//!
+//! # Page granularity
+//!
+//! Locking works on whole pages, so the locked range is rounded out to page
+//! boundaries and may include neighbouring heap allocations. Unix does not
+//! reference-count page locks: if two guards share a page, the first to drop
+//! unlocks that page for both. Use a dedicated buffer per secret (or
+//! [`Guarded<T>`](crate::Guarded), which gives each secret its own mapping, on
+//! Unix) when strict isolation matters.
+
+use secure_gate::{Dynamic, RevealSecret};
+use zeroize::Zeroize;
+
+use crate::LockError;
+use crate::sys::{self, PageRange};
+
+/// Heap-backed secrets whose storage can be locked in RAM.
+///
+/// Implemented for `Dynamic<Vec<T>>`, `Dynamic<String>`, `Dynamic<[T]>`,
+/// `Dynamic<str>` and `Dynamic<[T; N]>`. Stack-allocated `Fixed<T>` is not
+/// lockable: its address changes on every move.
+pub trait Lockable: RevealSecret + Zeroize + Sized {
+    /// Start address and byte length of the heap buffer that holds the secret,
+    /// including any spare capacity.
+    ///
+    /// Returns only an address and a length, never the contents.
+    fn secret_region(&self) -> (*const u8, usize);
+
+    /// Locks the secret's heap buffer in RAM and excludes it from core dumps until
+    /// the returned guard drops. The secret is zeroized when the guard drops.
+    ///
+    /// An empty buffer (no allocation) succeeds without locking anything.
+    ///
+    /// # Errors
+    ///
+    /// - [`LockError::LimitExceeded`] — `RLIMIT_MEMLOCK` / working-set quota.
+    /// - [`LockError::PermissionDenied`] — missing privilege.
+    /// - [`LockError::Unsupported`] — no locking API on this target.
+    /// - [`LockError::Os`] — any other OS failure.
+    ///
+    /// On error nothing stays locked and the secret is left untouched.
+    fn lock(&mut self) -> Result<LockGuard<'_, Self>, LockError> {
+        LockGuard::new(self)
+    }
+}
+
+impl<T: Zeroize> Lockable for Dynamic<Vec<T>> {
+    fn secret_region(&self) -> (*const u8, usize) {
+        let v = self.expose_secret();
+        (v.as_ptr().cast(), v.capacity() * core::mem::size_of::<T>())
+    }
+}
+
+impl Lockable for Dynamic<String> {
+    fn secret_region(&self) -> (*const u8, usize) {
+        let s = self.expose_secret();
+        (s.as_ptr(), s.capacity())
+    }
+}
+
+impl<T> Lockable for Dynamic<[T]>
+where
+    [T]: Zeroize,
+{
+    fn secret_region(&self) -> (*const u8, usize) {
+        let s = self.expose_secret();
+        (s.as_ptr().cast(), core::mem::size_of_val(s))
+    }
+}
+
+impl Lockable for Dynamic<str> {
+    fn secret_region(&self) -> (*const u8, usize) {
+        let s = self.expose_secret();
+        (s.as_ptr(), s.len())
+    }
+}
+
+impl<T: Zeroize, const N: usize> Lockable for Dynamic<[T; N]> {
+    fn secret_region(&self) -> (*const u8, usize) {
+        let a = self.expose_secret();
+        (a.as_ptr().cast(), core::mem::size_of_val(a))
+    }
+}
+
+/// Keeps a secret's heap buffer locked in RAM; zeroizes and unlocks on drop.
+///
+/// Created by [`Lockable::lock`]. Read access only — see the
+/// [module docs](crate::lock) for why. `Debug` prints `[REDACTED]`.
+pub struct LockGuard<'a, S: Lockable> {
+    secret: &'a mut S,
+    range: Option<PageRange>,
+    dump_excluded: bool,
+}
+
+impl<'a, S: Lockable> LockGuard<'a, S> {
+    fn new(secret: &'a mut S) -> Result<Self, LockError> {
+        let (addr, len) = secret.secret_region();
+        let range = PageRange::covering(addr, len);
+        let mut dump_excluded = false;
+        if let Some(range) = range {
+            sys::lock(range)?;
+            dump_excluded = match sys::exclude_from_dump(range) {
+                Ok(excluded) => excluded,
+                Err(e) => {
+                    sys::unlock(range);
+                    return Err(e);
+                }
+            };
+        }
+        Ok(Self {
+            secret,
+            range,
+            dump_excluded,
+        })
+    }
+
+    /// Scoped read access to the locked secret.
+    #[inline(always)]
+    pub fn with_secret<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&S::Inner) -> R,
+    {
+        self.secret.with_secret(f)
+    }
+
+    /// Direct read access to the locked secret (escape hatch — prefer
+    /// [`with_secret`](Self::with_secret)).
+    #[inline(always)]
+    pub fn expose_secret(&self) -> &S::Inner {
+        self.secret.expose_secret()
+    }
+
+    /// Number of bytes locked, after rounding out to whole pages. `0` for an
+    /// empty buffer.
+    #[inline]
+    pub fn locked_bytes(&self) -> usize {
+        self.range.map_or(0, |r| r.len)
+    }
+
+    /// `true` if the locked pages are also excluded from core dumps. Always
+    /// `false` on platforms without per-range dump exclusion (macOS, Windows).
+    #[inline]
+    pub fn is_dump_excluded(&self) -> bool {
+        self.dump_excluded
+    }
+}
+
+impl<S: Lockable> Drop for LockGuard<'_, S> {
+    fn drop(&mut self) {
+        // Wipe while still locked, then release the pages.
+        self.secret.zeroize();
+        if let Some(range) = self.range {
+            sys::unlock(range);
+            if self.dump_excluded {
+                sys::include_in_dump(range);
+            }
+        }
+    }
+}
+
+/// Always prints `[REDACTED]`.
+impl<S: Lockable> core::fmt::Debug for LockGuard<'_, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
@@ -0,0 +1,71 @@
+//! Thin platform layer: page size, lock / unlock, core-dump exclusion, and (Unix
+//! only) anonymous mappings with page protection for `Guarded<T>`.
+//!
+//! The lock and dump functions take a page-aligned range produced by
+//! [`PageRange`] and are safe to call: the OS validates the range and reports
+//! failures as errors, so a wrong range can fail but cannot cause undefined
+//! behavior. `unmap` and `protect` can invalidate live references and are
+//! `unsafe`.
+
+use crate::LockError;
+
+#[cfg(unix)]
+mod unix;
+#[cfg(unix)]
+pub(crate) use unix::{exclude_from_dump, include_in_dump, lock, page_size, unlock};
+#[cfg(unix)]
+pub(crate) use unix::{map, protect, unmap};
+
+#[cfg(windows)]
+mod windows;
+#[cfg(windows)]
+pub(crate) use windows::{exclude_from_dump, include_in_dump, lock, page_size, unlock};
+
+#[cfg(not(any(unix, windows)))]
+mod unsupported;
+#[cfg(not(any(unix, windows)))]
+pub(crate) use unsupported::{exclude_from_dump, include_in_dump, lock, page_size, unlock};
+
+/// Page protection applied by `protect`, ordered from most to least restrictive.
+#[cfg(unix)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Protection {
+    NoAccess,
+    ReadOnly,
+    ReadWrite,
+}
+
+/// Page-aligned span covering a byte range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct PageRange {
+    pub(crate) start: usize,
+    pub(crate) len: usize,
+}
+
+impl PageRange {
+    /// Rounds `[addr, addr + len)` out to whole pages. `None` for an empty range.
+    pub(crate) fn covering(addr: *const u8, len: usize) -> Option<Self> {
+        if len == 0 {
+            return None;
+        }
+        let page = page_size();
+        let addr = addr as usize;
+        let start = addr & !(page - 1);
+        let end = (addr + len).div_ceil(page) * page;
+        Some(Self {
+            start,
+            len: end - start,
+        })
+    }
+}
+
+/// Maps the `errno` of a failed lock call to a [`LockError`].
+#[allow(dead_code)] // unused on targets without a lock syscall
+pub(crate) fn last_error(limit_codes: &[i32], permission_codes: &[i32]) -> LockError {
+    match std::io::Error::last_os_error().raw_os_error() {
+        Some(code) if limit_codes.contains(&code) => LockError::LimitExceeded,
+        Some(code) if permission_codes.contains(&code) => LockError::PermissionDenied,
+        Some(code) => LockError::Os(code),
+        None => LockError::Os(0),
+    }
+}
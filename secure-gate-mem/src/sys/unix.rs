@@ -0,0 +1,132 @@
+//! Unix backend: `mlock` / `munlock` and `madvise` dump exclusion.
+//!
+//! Dump exclusion uses `MADV_DONTDUMP` on Linux / Android and `MADV_NOCORE` on
+//! FreeBSD / DragonFly. Other Unix targets lock memory but cannot exclude it from
+//! core dumps; [`exclude_from_dump`] reports that by returning `Ok(false)`.
+//!
+//! [`map`], [`protect`] and [`unmap`] back `Guarded<T>`.
+
+use super::{PageRange, Protection, last_error};
+use crate::LockError;
+
+pub(crate) fn page_size() -> usize {
+    // SAFETY: sysconf has no memory-safety preconditions.
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    if size > 0 { size as usize } else { 4096 }
+}
+
+pub(crate) fn lock(range: PageRange) -> Result<(), LockError> {
+    // SAFETY: mlock only changes the residency of pages; the kernel validates the
+    // range and fails with ENOMEM if any part of it is unmapped.
+    let rc = unsafe { libc::mlock(range.start as *const libc::c_void, range.len) };
+    if rc == 0 {
+        Ok(())
+    } else {
+        Err(last_error(&[libc::ENOMEM, libc::EAGAIN], &[libc::EPERM]))
+    }
+}
+
+pub(crate) fn unlock(range: PageRange) {
+    // SAFETY: as for mlock. A failure here leaves pages locked, which is harmless;
+    // the lock is released when the process exits.
+    unsafe {
+        libc::munlock(range.start as *const libc::c_void, range.len);
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const DUMP_ADVICE: Option<(libc::c_int, libc::c_int)> =
+    Some((libc::MADV_DONTDUMP, libc::MADV_DODUMP));
+
+#[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+const DUMP_ADVICE: Option<(libc::c_int, libc::c_int)> = Some((libc::MADV_NOCORE, libc::MADV_CORE));
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "dragonfly"
+)))]
+const DUMP_ADVICE: Option<(libc::c_int, libc::c_int)> = None;
+
+/// Returns `Ok(true)` if the range is now excluded from core dumps, `Ok(false)` if
+/// the platform has no such advice.
+pub(crate) fn exclude_from_dump(range: PageRange) -> Result<bool, LockError> {
+    let Some((exclude, _)) = DUMP_ADVICE else {
+        return Ok(false);
+    };
+    // SAFETY: dump-exclusion advice only changes core-dump bookkeeping; it never
+    // frees, remaps or changes the contents of the pages.
+    let rc = unsafe { libc::madvise(range.start as *mut libc::c_void, range.len, exclude) };
+    if rc == 0 {
+        Ok(true)
+    } else {
+        Err(last_error(&[], &[libc::EPERM]))
+    }
+}
+
+pub(crate) fn include_in_dump(range: PageRange) {
+    if let Some((_, include)) = DUMP_ADVICE {
+        // SAFETY: as for exclude_from_dump.
+        unsafe {
+            libc::madvise(range.start as *mut libc::c_void, range.len, include);
+        }
+    }
+}
+
+/// Maps `len` bytes (a multiple of the page size) of private, zero-filled,
+/// read-write anonymous memory.
+pub(crate) fn map(len: usize) -> Result<*mut u8, LockError> {
+    // SAFETY: a fresh anonymous mapping at a kernel-chosen address cannot alias
+    // any existing Rust allocation.
+    let ptr = unsafe {
+        libc::mmap(
+            core::ptr::null_mut(),
+            len,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANON,
+            -1,
+            0,
+        )
+    };
+    if ptr == libc::MAP_FAILED {
+        Err(last_error(&[], &[libc::EPERM]))
+    } else {
+        Ok(ptr.cast())
+    }
+}
+
+/// Unmaps a region returned by [`map`].
+///
+/// # Safety
+///
+/// `ptr`/`len` must be exactly a region returned by [`map`], and no reference
+/// into it may be used afterwards.
+pub(crate) unsafe fn unmap(ptr: *mut u8, len: usize) {
+    // SAFETY: guaranteed by the caller.
+    unsafe {
+        libc::munmap(ptr.cast(), len);
+    }
+}
+
+/// Changes the access rights of `range`.
+///
+/// # Safety
+///
+/// `range` must lie inside a region returned by [`map`]. Lowering the protection
+/// makes any outstanding reference into the range fault on use; the caller must
+/// ensure none is used while the lower protection is in effect.
+pub(crate) unsafe fn protect(range: PageRange, prot: Protection) -> Result<(), LockError> {
+    let flags = match prot {
+        Protection::NoAccess => libc::PROT_NONE,
+        Protection::ReadOnly => libc::PROT_READ,
+        Protection::ReadWrite => libc::PROT_READ | libc::PROT_WRITE,
+    };
+    // SAFETY: guaranteed by the caller.
+    let rc = unsafe { libc::mprotect(range.start as *mut libc::c_void, range.len, flags) };
+    if rc == 0 {
+        Ok(())
+    } else {
+        Err(last_error(&[libc::ENOMEM], &[libc::EACCES]))
+    }
+}
@@ -0,0 +1,21 @@
+//! Fallback for targets without a memory-locking API: every lock fails with
+//! [`LockError::Unsupported`].
+
+use super::PageRange;
+use crate::LockError;
+
+pub(crate) fn page_size() -> usize {
+    4096
+}
+
+pub(crate) fn lock(_range: PageRange) -> Result<(), LockError> {
+    Err(LockError::Unsupported)
+}
+
+pub(crate) fn unlock(_range: PageRange) {}
+
+pub(crate) fn exclude_from_dump(_range: PageRange) -> Result<bool, LockError> {
+    Ok(false)
+}
+
+pub(crate) fn include_in_dump(_range: PageRange) {}
@@ -0,0 +1,53 @@
+//! Windows backend: `VirtualLock` / `VirtualUnlock`.
+//!
+//! Windows has no per-range core-dump exclusion comparable to `MADV_DONTDUMP`;
+//! [`exclude_from_dump`] returns `Ok(false)`.
+
+use core::ffi::c_void;
+
+use windows_sys::Win32::System::Memory::{VirtualLock, VirtualUnlock};
+use windows_sys::Win32::System::SystemInformation::{GetSystemInfo, SYSTEM_INFO};
+
+use super::{PageRange, last_error};
+use crate::LockError;
+
+/// `ERROR_WORKING_SET_QUOTA`: the working-set minimum is too small to lock more pages.
+const ERROR_WORKING_SET_QUOTA: i32 = 1453;
+/// `ERROR_PRIVILEGE_NOT_HELD`.
+const ERROR_PRIVILEGE_NOT_HELD: i32 = 1314;
+
+pub(crate) fn page_size() -> usize {
+    // SAFETY: GetSystemInfo fully initializes the struct it is given.
+    let info: SYSTEM_INFO = unsafe {
+        let mut info = core::mem::zeroed();
+        GetSystemInfo(&mut info);
+        info
+    };
+    info.dwPageSize as usize
+}
+
+pub(crate) fn lock(range: PageRange) -> Result<(), LockError> {
+    // SAFETY: VirtualLock only changes page residency and validates the range.
+    let ok = unsafe { VirtualLock(range.start as *const c_void, range.len) };
+    if ok != 0 {
+        Ok(())
+    } else {
+        Err(last_error(
+            &[ERROR_WORKING_SET_QUOTA],
+            &[ERROR_PRIVILEGE_NOT_HELD],
+        ))
+    }
+}
+
+pub(crate) fn unlock(range: PageRange) {
+    // SAFETY: as for VirtualLock; failure leaves the pages locked, which is harmless.
+    unsafe {
+        VirtualUnlock(range.start as *const c_void, range.len);
+    }
+}
+
+pub(crate) fn exclude_from_dump(_range: PageRange) -> Result<bool, LockError> {
+    Ok(false)
+}
+
+pub(crate) fn include_in_dump(_range: PageRange) {}
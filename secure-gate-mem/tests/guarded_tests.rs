@@ -0,0 +1,146 @@
+// secure-gate-mem/tests/guarded_tests.rs
+// Guard-page storage tests. Fault cases re-run this test binary as a child
+// process and assert that it dies from a signal instead of reading memory.
+#![cfg(unix)]
+#![cfg(not(miri))] // mmap/mprotect and child processes are beyond Miri
+
+use secure_gate::{RevealSecret, RevealSecretMut};
+use secure_gate_mem::{Guarded, LockError};
+use std::os::unix::process::ExitStatusExt;
+use std::process::Command;
+
+/// Env var telling a child process which fault to trigger.
+const FAULT_ENV: &str = "SECURE_GATE_MEM_FAULT";
+
+/// Re-runs `test_name` in a child with `FAULT_ENV` set and asserts that it was
+/// killed by a signal (SIGSEGV on Linux, SIGBUS on some BSDs / macOS).
+fn assert_child_faults(test_name: &str) {
+    let status = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", test_name, "--nocapture", "--test-threads=1"])
+        .env(FAULT_ENV, "1")
+        .status()
+        .expect("spawn child test process");
+    assert!(
+        matches!(status.signal(), Some(libc::SIGSEGV | libc::SIGBUS)),
+        "child should die from SIGSEGV/SIGBUS, got {status:?}"
+    );
+}
+
+fn in_child() -> bool {
+    std::env::var_os(FAULT_ENV).is_some()
+}
+
+#[test]
+fn guarded_array_roundtrip() {
+    let mut key = Guarded::<[u8; 32]>::new([0x42u8; 32]);
+    assert_eq!(key.len(), 32);
+    assert_eq!(key.byte_len(), 32);
+    assert_eq!(key.with_secret(|k| k[0]), 0x42);
+
+    key.with_secret_mut(|k| k[31] = 0x07);
+    assert_eq!(key.with_secret(|k| k[31]), 0x07);
+
+    let inner = key.into_inner();
+    assert_eq!(inner[0], 0x42);
+    assert_eq!(inner[31], 0x07);
+}
+
+#[test]
+fn guarded_slice_roundtrip() {
+    let mut pw = Guarded::<[u8]>::new(b"hunter2");
+    assert_eq!(pw.len(), 7);
+    pw.with_secret(|p| assert_eq!(p, b"hunter2"));
+    pw.with_secret_mut(|p| p[0] = b'H');
+    assert_eq!(pw.with_secret(|p| p.to_vec()), b"Hunter2");
+
+    let empty = Guarded::<[u8]>::new(&[]);
+    assert!(empty.is_empty());
+    empty.with_secret(|p| assert!(p.is_empty()));
+}
+
+#[test]
+fn guarded_new_with_writes_in_place() {
+    let key = Guarded::<[u8; 16]>::new_with(|k| k.fill(0xAA));
+    key.with_secret(|k| assert_eq!(k, &[0xAAu8; 16]));
+
+    let buf = Guarded::<[u8]>::new_with(5000, |b| b[4999] = 1);
+    assert_eq!(buf.len(), 5000);
+    buf.with_secret(|b| assert_eq!(b[4999], 1));
+}
+
+#[test]
+fn guarded_nested_reveals_keep_pages_open() {
+    let key = Guarded::<[u8; 8]>::new([1u8; 8]);
+    let sum = key.with_secret(|outer| {
+        let inner_sum: u32 = key.with_secret(|inner| inner.iter().map(|&b| u32::from(b)).sum());
+        // Still readable after the nested reveal finished.
+        inner_sum + u32::from(outer[0])
+    });
+    assert_eq!(sum, 9);
+}
+
+#[test]
+fn guarded_expose_secret_stays_readable() {
+    let mut key = Guarded::<[u8; 4]>::new([9u8; 4]);
+    let direct = key.expose_secret();
+    assert_eq!(direct, &[9u8; 4]);
+    // A scoped reveal afterwards must not close the pages under `direct`.
+    key.with_secret(|_| ());
+    assert_eq!(key.expose_secret()[0], 9);
+
+    key.expose_secret_mut()[0] = 1;
+    key.with_secret_mut(|_| ());
+    assert_eq!(key.expose_secret_mut()[0], 1);
+}
+
+#[test]
+fn guarded_panic_in_closure_restores_protection() {
+    let key = Guarded::<[u8; 4]>::new([3u8; 4]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        key.with_secret(|_| panic!("boom"));
+    }));
+    assert!(result.is_err());
+    assert_eq!(key.with_secret(|k| k[0]), 3);
+}
+
+#[test]
+fn guarded_oversized_len_is_rejected() {
+    for len in [usize::MAX, usize::MAX - 3, usize::MAX - 16] {
+        let result = Guarded::<[u8]>::try_new_with(len, |_| {
+            unreachable!("closure must not run for an unmappable length")
+        });
+        assert_eq!(result.unwrap_err(), LockError::Os(libc::ENOMEM));
+    }
+}
+
+#[test]
+fn guarded_debug_is_redacted() {
+    let key = Guarded::<[u8; 4]>::new([1u8; 4]);
+    assert_eq!(format!("{key:?}"), "[REDACTED]");
+}
+
+#[test]
+fn guarded_read_outside_reveal_faults() {
+    if in_child() {
+        let key = Guarded::<[u8; 32]>::new([0x42u8; 32]);
+        let ptr = key.with_secret(|k| k.as_ptr());
+        // SAFETY: deliberately reads a PROT_NONE page to prove it faults.
+        let _ = unsafe { ptr.read_volatile() };
+        unreachable!("read of a closed Guarded region did not fault");
+    }
+    assert_child_faults("guarded_read_outside_reveal_faults");
+}
+
+#[test]
+fn guarded_overflow_hits_guard_page() {
+    if in_child() {
+        let key = Guarded::<[u8; 32]>::new([0x42u8; 32]);
+        key.with_secret(|k| {
+            // SAFETY: deliberately reads one byte past the end to prove the
+            // trailing guard page faults.
+            let _ = unsafe { k.as_ptr().add(32).read_volatile() };
+        });
+        unreachable!("overflow into the guard page did not fault");
+    }
+    assert_child_faults("guarded_overflow_hits_guard_page");
+}
@@ -0,0 +1,125 @@
+// secure-gate-mem/tests/lock_tests.rs
+// Memory-locking tests. Locking can legitimately fail in restricted sandboxes
+// (RLIMIT_MEMLOCK = 0, seccomp), so those errors are tolerated.
+#![cfg(not(miri))] // mlock/VirtualLock are foreign calls Miri cannot run
+
+use secure_gate::{Dynamic, RevealSecret};
+use secure_gate_mem::{LockError, LockGuard, Lockable};
+
+/// Accepts the errors a restricted environment may produce; anything else fails.
+fn tolerate(err: LockError) -> bool {
+    assert!(
+        matches!(err, LockError::LimitExceeded | LockError::PermissionDenied),
+        "unexpected lock error: {err}"
+    );
+    false
+}
+
+/// Locks `secret`, or returns `None` if the environment forbids locking.
+fn try_lock<S: Lockable>(secret: &mut S) -> Option<LockGuard<'_, S>> {
+    secret.lock().map_err(tolerate).ok()
+}
+
+#[test]
+fn lock_vec_reads_and_zeroizes_on_drop() {
+    let mut key: Dynamic<Vec<u8>> = Dynamic::new(vec![0x42u8; 64]);
+    let locked = match key.lock() {
+        Ok(guard) => {
+            assert!(guard.locked_bytes() >= 64);
+            assert_eq!(guard.locked_bytes() % 4096, 0);
+            guard.with_secret(|k| assert_eq!(k, &[0x42u8; 64]));
+            assert_eq!(guard.expose_secret().len(), 64);
+            true
+        }
+        Err(e) => tolerate(e),
+    };
+    if locked {
+        // Dropping the guard zeroized the secret.
+        assert!(key.expose_secret().is_empty());
+    } else {
+        // A failed lock leaves the secret untouched.
+        assert_eq!(key.expose_secret(), &[0x42u8; 64]);
+    }
+}
+
+#[test]
+fn lock_string_and_unsized() {
+    let mut pw: Dynamic<String> = Dynamic::new(String::from("hunter2"));
+    if let Some(guard) = try_lock(&mut pw) {
+        assert_eq!(guard.expose_secret(), "hunter2");
+    }
+
+    let mut s: Dynamic<str> = Dynamic::new("hunter2");
+    if let Some(guard) = try_lock(&mut s) {
+        assert_eq!(guard.expose_secret(), "hunter2");
+    }
+
+    let mut b: Dynamic<[u8]> = Dynamic::new(&[1u8, 2, 3][..]);
+    if let Some(guard) = try_lock(&mut b) {
+        assert_eq!(guard.expose_secret(), &[1, 2, 3]);
+    }
+
+    let mut a: Dynamic<[u8; 32]> = Dynamic::new([7u8; 32]);
+    if let Some(guard) = try_lock(&mut a) {
+        assert_eq!(guard.expose_secret(), &[7u8; 32]);
+    }
+}
+
+#[test]
+fn lock_empty_is_noop() {
+    let mut empty: Dynamic<Vec<u8>> = Dynamic::new(Vec::new());
+    let guard = empty.lock().expect("empty buffer locks nothing");
+    assert_eq!(guard.locked_bytes(), 0);
+    assert!(!guard.is_dump_excluded());
+}
+
+#[test]
+fn guard_debug_is_redacted() {
+    let mut key: Dynamic<Vec<u8>> = Dynamic::new(vec![1u8; 16]);
+    if let Some(guard) = try_lock(&mut key) {
+        assert_eq!(format!("{guard:?}"), "[REDACTED]");
+    }
+}
+
+#[test]
+fn lock_error_messages() {
+    assert_eq!(
+        LockError::LimitExceeded.to_string(),
+        "locked-memory limit exceeded"
+    );
+    assert_eq!(
+        LockError::Unsupported.to_string(),
+        "memory locking is not supported on this platform"
+    );
+    assert_eq!(
+        LockError::Os(5).to_string(),
+        "OS error 5 while changing memory protection"
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn linux_lock_shows_in_vmlck_and_dump_excluded() {
+    fn vm_lck_kib() -> u64 {
+        let status = std::fs::read_to_string("/proc/self/status").unwrap();
+        status
+            .lines()
+            .find_map(|l| l.strip_prefix("VmLck:"))
+            .and_then(|v| v.trim().trim_end_matches("kB").trim().parse().ok())
+            .unwrap_or(0)
+    }
+
+    // A large allocation gets its own pages, so the lock is visible in VmLck.
+    // Other tests in this binary may lock concurrently, so only a lower bound
+    // is checked.
+    let mut big: Dynamic<Vec<u8>> = Dynamic::new(vec![0xAAu8; 64 * 1024]);
+    match big.lock() {
+        Ok(guard) => {
+            assert!(guard.is_dump_excluded());
+            assert!(vm_lck_kib() >= 64);
+        }
+        Err(e) => {
+            tolerate(e);
+        }
+    }
+}